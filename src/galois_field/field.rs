@@ -0,0 +1,60 @@
+use std::fmt::Debug;
+
+/**
+Arithmetic shared by all finite fields, so that matrices and polynomials can be computed on any of them.
+
+A field calculator owns whatever it needs to compute (lookup tables, modulus, irreducible polynomial),
+elements are plain values which only have meaning together with the calculator they come from.
+*/
+pub trait GaloisField {
+    /// Element type of current field.
+    type Element: Clone + PartialEq + Debug;
+
+    /// Characteristic $p$ of $GF(p^n)$.
+    fn characteristic(&self) -> u64;
+
+    /// Degree $n$ of $GF(p^n)$ over its prime field.
+    fn degree(&self) -> u32;
+
+    /// Additive identity.
+    fn zero(&self) -> Self::Element;
+
+    /// Multiplicative identity.
+    fn one(&self) -> Self::Element;
+
+    fn add(&self, x: Self::Element, y: Self::Element) -> Self::Element;
+
+    fn sub(&self, x: Self::Element, y: Self::Element) -> Self::Element;
+
+    /// Additive inverse, $x + (-x) = 0$.
+    fn neg(&self, x: Self::Element) -> Self::Element;
+
+    fn mul(&self, x: Self::Element, y: Self::Element) -> Self::Element;
+
+    /// Multiplicative inverse, $x \cdot x^{-1} = 1$, panics if x is zero.
+    fn inv(&self, x: Self::Element) -> Self::Element;
+
+    /// Division, panics if y is zero.
+    fn div(&self, x: Self::Element, y: Self::Element) -> Self::Element {
+        self.mul(x, self.inv(y))
+    }
+
+    /// $x^e$ by square-and-multiply.
+    fn pow(&self, x: Self::Element, e: u64) -> Self::Element {
+        let mut res = self.one();
+        let mut base = x;
+        let mut e = e;
+        while e > 0 {
+            if e & 1 == 1 {
+                res = self.mul(res, base.clone());
+            }
+            base = self.mul(base.clone(), base);
+            e >>= 1;
+        }
+        res
+    }
+
+    fn is_zero(&self, x: &Self::Element) -> bool {
+        *x == self.zero()
+    }
+}
@@ -0,0 +1,355 @@
+/*!
+Galois field arithmetic on $GF(2^{128})$ with the GCM irreducible polynomial
+
+$$
+P(x) = x^{128} + x^{7} + x^{2} + x + 1
+$$
+
+Elements use the GCM bit order: a 16 bytes block is read as big endian u128, and the most significant bit
+is the coefficient of $x^0$. So `Gf2p128::one()` is `1 << 127`, not `1`.
+
+Multiplication is a 128 bits carry-less multiplication followed by reduction modulo $P(x)$.
+On x86_64 CPUs with `pclmulqdq` the carry-less multiplication is done by hardware, which is detected at runtime,
+otherwise a portable constant-time implementation is used.
+*/
+use crate::galois_field::field::GaloisField;
+
+// x^0 is the most significant bit
+const GF2P128_ONE: u128 = 1 << 127;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Gf2p128;
+
+impl Gf2p128 {
+    pub fn new() -> Gf2p128 {
+        Gf2p128
+    }
+
+    /// Read 16 bytes block as field element.
+    pub fn from_block(block: &[u8; 16]) -> u128 {
+        u128::from_be_bytes(*block)
+    }
+
+    /// Write field element as 16 bytes block.
+    pub fn to_block(x: u128) -> [u8; 16] {
+        x.to_be_bytes()
+    }
+
+    /**
+    add on $GF(2^{128})$
+    x + y is x xor y
+    */
+    #[inline(always)]
+    pub fn add(&self, x: u128, y: u128) -> u128 {
+        x ^ y
+    }
+
+    /**
+    Multiplication on $GF(2^{128})$, uses `pclmulqdq` when CPU supports it.
+    */
+    #[inline]
+    pub fn mul(&self, x: u128, y: u128) -> u128 {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("pclmulqdq") {
+                // safety: pclmulqdq is available, checked above
+                return unsafe { mul_pclmulqdq(x, y) };
+            }
+        }
+        mul_portable(x, y)
+    }
+
+    /**
+    Multiplication on $GF(2^{128})$ without any CPU extension, it takes the same time for all inputs.
+    */
+    #[inline]
+    pub fn mul_portable(&self, x: u128, y: u128) -> u128 {
+        mul_portable(x, y)
+    }
+
+    /**
+    Multiplicative inverse, panics if x is zero.
+    $$
+    x^{-1} = x^{2^{128} - 2} = x^{2} x^{4} \cdots x^{2^{127}}
+    $$
+    */
+    pub fn inv(&self, x: u128) -> u128 {
+        assert_ne!(x, 0); // 0 has no inverse
+        let mut res = GF2P128_ONE;
+        let mut square = x;
+        for _ in 1..128 {
+            square = self.mul(square, square);
+            res = self.mul(res, square);
+        }
+        res
+    }
+}
+
+impl GaloisField for Gf2p128 {
+    type Element = u128;
+
+    fn characteristic(&self) -> u64 {
+        2
+    }
+
+    fn degree(&self) -> u32 {
+        128
+    }
+
+    fn zero(&self) -> u128 {
+        0
+    }
+
+    fn one(&self) -> u128 {
+        GF2P128_ONE
+    }
+
+    fn add(&self, x: u128, y: u128) -> u128 {
+        x ^ y
+    }
+
+    fn sub(&self, x: u128, y: u128) -> u128 {
+        x ^ y
+    }
+
+    // on $GF(2^w)$ every element is its own additive inverse
+    fn neg(&self, x: u128) -> u128 {
+        x
+    }
+
+    fn mul(&self, x: u128, y: u128) -> u128 {
+        Gf2p128::mul(self, x, y)
+    }
+
+    fn inv(&self, x: u128) -> u128 {
+        Gf2p128::inv(self, x)
+    }
+}
+
+/**
+Carry-less 32 x 32 bits multiplication with integer multiplication.
+
+Operand bits are split into 4 groups by position mod 4, so every integer product only has 1 bit every 4 bits,
+and the 3 bits holes between them take the carries: at most 8 bits are summed at one position, which fits in 4 bits.
+*/
+#[inline(always)]
+fn clmul32(x: u32, y: u32) -> u64 {
+    const M0: u32 = 0x1111_1111;
+    const M1: u32 = 0x2222_2222;
+    const M2: u32 = 0x4444_4444;
+    const M3: u32 = 0x8888_8888;
+    let (x0, x1, x2, x3) = (
+        (x & M0) as u64,
+        (x & M1) as u64,
+        (x & M2) as u64,
+        (x & M3) as u64,
+    );
+    let (y0, y1, y2, y3) = (
+        (y & M0) as u64,
+        (y & M1) as u64,
+        (y & M2) as u64,
+        (y & M3) as u64,
+    );
+    let z0 = (x0 * y0) ^ (x1 * y3) ^ (x2 * y2) ^ (x3 * y1);
+    let z1 = (x0 * y1) ^ (x1 * y0) ^ (x2 * y3) ^ (x3 * y2);
+    let z2 = (x0 * y2) ^ (x1 * y1) ^ (x2 * y0) ^ (x3 * y3);
+    let z3 = (x0 * y3) ^ (x1 * y2) ^ (x2 * y1) ^ (x3 * y0);
+    (z0 & 0x1111_1111_1111_1111)
+        | (z1 & 0x2222_2222_2222_2222)
+        | (z2 & 0x4444_4444_4444_4444)
+        | (z3 & 0x8888_8888_8888_8888)
+}
+
+#[inline(always)]
+fn clmul64(x: u64, y: u64) -> u128 {
+    let (xl, xh) = (x as u32, (x >> 32) as u32);
+    let (yl, yh) = (y as u32, (y >> 32) as u32);
+    let lo = clmul32(xl, yl) as u128;
+    let hi = clmul32(xh, yh) as u128;
+    let mid = (clmul32(xl, yh) ^ clmul32(xh, yl)) as u128;
+    lo ^ (mid << 32) ^ (hi << 64)
+}
+
+/// Reduce 256 bits carry-less product hi * x^128 + lo modulo P(x), both in normal bit order.
+#[inline(always)]
+fn reduce(hi: u128, lo: u128) -> u128 {
+    // x^128 = x^7 + x^2 + x + 1 mod P(x), so hi * x^128 = hi * (x^7 + x^2 + x + 1),
+    // and the bits shifted out are above x^128 again
+    let folded = hi ^ (hi << 1) ^ (hi << 2) ^ (hi << 7);
+    let overflow = (hi >> 127) ^ (hi >> 126) ^ (hi >> 121);
+    let overflow = overflow ^ (overflow << 1) ^ (overflow << 2) ^ (overflow << 7);
+    lo ^ folded ^ overflow
+}
+
+fn mul_portable(x: u128, y: u128) -> u128 {
+    // GCM bit order is reversed normal polynomial bit order
+    let (a, b) = (x.reverse_bits(), y.reverse_bits());
+    let (a0, a1) = (a as u64, (a >> 64) as u64);
+    let (b0, b1) = (b as u64, (b >> 64) as u64);
+    let lo = clmul64(a0, b0);
+    let hi = clmul64(a1, b1);
+    let mid = clmul64(a0, b1) ^ clmul64(a1, b0);
+    reduce(hi ^ (mid >> 64), lo ^ (mid << 64)).reverse_bits()
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "pclmulqdq,sse2")]
+unsafe fn mul_pclmulqdq(x: u128, y: u128) -> u128 {
+    use std::arch::x86_64::*;
+
+    let (a, b) = (x.reverse_bits(), y.reverse_bits());
+    let a = _mm_set_epi64x((a >> 64) as i64, a as i64);
+    let b = _mm_set_epi64x((b >> 64) as i64, b as i64);
+    // x86_64 is little endian, lane 0 is the low half
+    let to_u128 = |v: __m128i| -> u128 { std::mem::transmute(v) };
+    let lo = to_u128(_mm_clmulepi64_si128(a, b, 0x00));
+    let hi = to_u128(_mm_clmulepi64_si128(a, b, 0x11));
+    let mid = to_u128(_mm_clmulepi64_si128(a, b, 0x01)) ^ to_u128(_mm_clmulepi64_si128(a, b, 0x10));
+    reduce(hi ^ (mid >> 64), lo ^ (mid << 64)).reverse_bits()
+}
+
+/**
+GHASH from GCM, the polynomial hash over $GF(2^{128})$ with hash key H.
+
+$$
+Y_{i} = (Y_{i-1} \oplus X_{i}) \cdot H
+$$
+*/
+#[derive(Debug, Clone)]
+pub struct Ghash {
+    gf: Gf2p128,
+    h: u128,
+    y: u128,
+}
+
+impl Ghash {
+    pub fn new(h: &[u8; 16]) -> Ghash {
+        Ghash {
+            gf: Gf2p128,
+            h: Gf2p128::from_block(h),
+            y: 0,
+        }
+    }
+
+    /// Hash data, the last block is padded with zeros if data length is not a multiple of 16.
+    pub fn update_padded(&mut self, data: &[u8]) {
+        for chunk in data.chunks(16) {
+            let mut block = [0u8; 16];
+            block[..chunk.len()].copy_from_slice(chunk);
+            self.update_block(&block);
+        }
+    }
+
+    pub fn update_block(&mut self, block: &[u8; 16]) {
+        self.y = self.gf.mul(self.y ^ Gf2p128::from_block(block), self.h);
+    }
+
+    pub fn finalize(self) -> [u8; 16] {
+        Gf2p128::to_block(self.y)
+    }
+}
+
+/**
+GHASH of additional authenticated data and ciphertext as GCM defines it:
+both are padded to blocks, followed by one block of their bit lengths.
+*/
+pub fn ghash(h: &[u8; 16], aad: &[u8], ciphertext: &[u8]) -> [u8; 16] {
+    let mut hasher = Ghash::new(h);
+    hasher.update_padded(aad);
+    hasher.update_padded(ciphertext);
+    let lengths = ((aad.len() as u128 * 8) << 64) | (ciphertext.len() as u128 * 8);
+    hasher.update_block(&Gf2p128::to_block(lengths));
+    hasher.finalize()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn bytes(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn block(s: &str) -> [u8; 16] {
+        Gf2p128::to_block(u128::from_str_radix(s, 16).unwrap())
+    }
+
+    // bit by bit multiplication from NIST SP 800-38D algorithm 1
+    fn mul_reference(x: u128, y: u128) -> u128 {
+        let mut z = 0u128;
+        let mut v = y;
+        for i in 0..128 {
+            if (x >> (127 - i)) & 1 == 1 {
+                z ^= v;
+            }
+            v = if v & 1 == 1 {
+                (v >> 1) ^ (0xE1 << 120)
+            } else {
+                v >> 1
+            };
+        }
+        z
+    }
+
+    #[test]
+    fn gf2p128_mul_test() {
+        let gf = Gf2p128::new();
+        let x = 0x993955be58886f39137c56af8c5187c1u128;
+        let y = 0x1634106f49e1859f9b11bf0cd848292du128;
+        assert_eq!(gf.mul(x, y), 0x26c08cab20c7294a1c0b552aa366edf9);
+        assert_eq!(gf.mul_portable(x, y), 0x26c08cab20c7294a1c0b552aa366edf9);
+
+        let mut rng = StdRng::seed_from_u64(29);
+        for _ in 0..1000 {
+            let (x, y): (u128, u128) = (rng.gen(), rng.gen());
+            let expected = mul_reference(x, y);
+            assert_eq!(gf.mul_portable(x, y), expected);
+            assert_eq!(gf.mul(x, y), expected);
+        }
+
+        // carries inside clmul32 are largest when all bits are set
+        assert_eq!(
+            gf.mul_portable(u128::MAX, u128::MAX),
+            mul_reference(u128::MAX, u128::MAX)
+        );
+        assert_eq!(gf.mul(x, gf.one()), x);
+        assert_eq!(gf.mul(x, 0), 0);
+    }
+
+    #[test]
+    fn gf2p128_inv_test() {
+        let gf = Gf2p128::new();
+        let mut rng = StdRng::seed_from_u64(128);
+        for _ in 0..20 {
+            let x: u128 = rng.gen::<u128>() | 1;
+            assert_eq!(gf.mul(x, gf.inv(x)), gf.one());
+            assert_eq!(gf.div(gf.mul(x, 12345), x), 12345);
+        }
+        assert_eq!(gf.inv(gf.one()), gf.one());
+    }
+
+    #[test]
+    fn ghash_test() {
+        // GCM specification test case 2: K = 0, P = 0, IV = 0
+        let h = block("66e94bd4ef8a2c3b884cfa59ca342b2e");
+        let c = bytes("0388dace60b6a392f328c2b971b2fe78");
+        assert_eq!(
+            ghash(&h, &[], &c),
+            block("f38cbb1ad69223dcc3457ae5b6b0f885")
+        );
+
+        // GCM specification test case 4, with additional authenticated data and a partial block
+        let h = block("b83b533708bf535d0aa6e52980d53b78");
+        let a = bytes("feedfacedeadbeeffeedfacedeadbeefabaddad2");
+        let c = bytes(
+            "42831ec2217774244b7221b784d0d49ce3aa212f2c02a4e035c17e2329aca12e\
+             21d514b25466931c7d8f6a5aac84aa051ba30b396a0aac973d58e091",
+        );
+        assert_eq!(ghash(&h, &a, &c), block("698e57f70e6ecc7fd9463b7260a9ae5f"));
+    }
+}
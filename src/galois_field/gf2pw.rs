@@ -1,81 +1,110 @@
-// Galois field arithmetic.
+// Galois field arithmetic on any unsigned element type.
 
 /*
 another multiply algorithm on GF(2^8)： https://blog.csdn.net/codebreakers/article/details/41456149?locationNum=7&fps=1
 */
 
-use std::collections::HashMap;
-use crate::number::number::XNum;
-
-// TODO
-// 不可约多项式改用u128
-// 支持GF(2)
-
-const GF2PW_DEFAULT_IRREDUCIBLE_POLYNOMIAL_DICT: [u8; 9] = [
-    0x00, // GF(2^0) not exist
-    0x03, // 0b11: x + 1
-    0x07, // 0b111: x^2 + x + 1
-    0x0B, // 0b001011: x^3 + x + 1
-    0x13, // 0b010011: x^4 + x + 1
-    0x25, // 0b100101: x^5 + x^2 + 1
-    0x43, // 0b001000011: x^6 + x + 1
-    0x83, // 0b010000011: x^7 + x + 1
-    0x1D, // 0b100011101 % 0xFF = 0x1D : x^8 + x^4 + x^3 + x^2 + 1
+use crate::galois_field::field::GaloisField;
+use num_traits::{PrimInt, Unsigned};
+use std::fmt::Debug;
+
+/// Lookup tables have $2^w$ elements, so w is limited.
+pub const GF2PW_MAX_W: u8 = 16;
+
+/// Primitive polynomials with the $x^w$ term, index is w.
+pub const GF2PW_DEFAULT_IRREDUCIBLE_POLYNOMIAL_DICT: [u32; 17] = [
+    0x00,    // GF(2^0) not exist
+    0x03,    // x + 1
+    0x07,    // x^2 + x + 1
+    0x0B,    // x^3 + x + 1
+    0x13,    // x^4 + x + 1
+    0x25,    // x^5 + x^2 + 1
+    0x43,    // x^6 + x + 1
+    0x83,    // x^7 + x + 1
+    0x11D,   // x^8 + x^4 + x^3 + x^2 + 1
+    0x211,   // x^9 + x^4 + 1
+    0x409,   // x^10 + x^3 + 1
+    0x805,   // x^11 + x^2 + 1
+    0x1053,  // x^12 + x^6 + x^4 + x + 1
+    0x201B,  // x^13 + x^4 + x^3 + x + 1
+    0x4443,  // x^14 + x^10 + x^6 + x + 1
+    0x8003,  // x^15 + x + 1
+    0x1100B, // x^16 + x^12 + x^3 + x + 1
 ];
 
 /**
-in AES(Rijndael), the irreducible polynomial is x⁸ + x⁴ + x³ + x + 1，this polynomial = 0x11B mod 0xFF = 0x1B when x = 2
-*/
-const GF2PW8_IRREDUCIBLE_POLYNOMIAL_AES: u8 = 0x1B;
-
-/**
-in Erasure Code, the irreducible polynomial is x⁸ + x⁴ + x³ + x² + 1，this polynomial = 0x1D when x = 2
-*/
-const GF2PW8_IRREDUCIBLE_POLYNOMIAL_ERASURE_CODE: u8 = 0x1D;
-
-/**
-Galois field arithmetic on $GF(2^w)$
+Galois field arithmetic on $GF(2^w)$ with element type T, it is the same algorithm as `Gf2p`,
+but T can be u8, u16 or u32, so w can be up to `GF2PW_MAX_W`.
 */
+#[derive(Debug, Clone)]
 pub struct XGf2pw<T> {
     // notice: this is how many elements exist on current finite field,
     // but this number is less than power table length, element_count == power_length - 1.
     element_count: usize,
-    overflow_flag: u8,
+    overflow_flag: T,
+    // 2^w - 1, clears bits above x^(w-1) after shift
+    mask: T,
     w: u8,
-    irreducible_polynomial: u8,
+    irreducible_polynomial: T,
     power: Vec<T>, // ilog table
     log: Vec<T>,   // log table
 }
 
 impl<T> XGf2pw<T>
-where T: XNum + Copy + Clone {
+where
+    T: PrimInt + Unsigned + Debug,
+{
     /**
     Create new galois field calculator.
-    w: power.
-    irreducible_polynomial: irreducible polynomial selected, and sometimes it is primitive polynomial, sometimes it is not.
+    w: power, 1 <= w <= min(bits of T, GF2PW_MAX_W).
+    irreducible_polynomial: irreducible polynomial selected, the $x^w$ term can be omitted,
+    so both 0x1D and 0x11D mean $x^8 + x^4 + x^3 + x^2 + 1$ on $GF(2^8)$.
     */
-    pub fn new(w: u8, irreducible_polynomial: u8, type_def_val: T) -> Result<XGf2pw<T>, anyhow::Error> {
-        if w <= 0 || w >= 9 {
+    pub fn new(w: u8, irreducible_polynomial: T) -> anyhow::Result<XGf2pw<T>> {
+        let bits = T::zero().count_zeros() as u8;
+        if w == 0 || w > bits || w > GF2PW_MAX_W {
             return Err(anyhow::anyhow!(
-                "w cannot be ".to_string() + w.to_string().as_str()
+                "w cannot be {} for {} bits element",
+                w,
+                bits
             ));
         }
 
         let element_count: usize = 1 << (w as usize);
+        let mask = if w == bits {
+            T::max_value()
+        } else {
+            (T::one() << w as usize) - T::one()
+        };
         let mut gf = XGf2pw {
-            element_count: 1 << w as usize,
-            overflow_flag: 1 << (w - 1), // on GF(2^8) it is 0x80
-            w: w,
-            irreducible_polynomial: irreducible_polynomial,
-            power: vec![type_def_val; element_count],
-            log: vec![type_def_val; element_count],
+            element_count,
+            overflow_flag: T::one() << (w as usize - 1), // on GF(2^8) it is 0x80
+            mask,
+            w,
+            irreducible_polynomial: irreducible_polynomial & mask,
+            power: vec![T::zero(); element_count],
+            log: vec![T::zero(); element_count],
         };
 
-        let res = gf.generate_power_log_table();
-        if res.is_err() {
-            return Err(res.unwrap_err());
-        }
-        return Ok(gf);
+        gf.generate_power_log_table()?;
+        Ok(gf)
+    }
+
+    /// Create new galois field calculator with `GF2PW_DEFAULT_IRREDUCIBLE_POLYNOMIAL_DICT`.
+    pub fn with_default_polynomial(w: u8) -> anyhow::Result<XGf2pw<T>> {
+        let polynomial = GF2PW_DEFAULT_IRREDUCIBLE_POLYNOMIAL_DICT
+            .get(w as usize)
+            .copied()
+            .unwrap_or(0);
+        // bits of the x^w term may not fit in T, they are dropped by mask anyway
+        let low_bits = polynomial & ((1u32 << (w.min(31) as u32)) - 1);
+        let polynomial = T::from(low_bits).ok_or_else(|| {
+            anyhow::anyhow!(
+                "default polynomial {:#x} overflows element type",
+                polynomial
+            )
+        })?;
+        XGf2pw::new(w, polynomial)
     }
 
     /**
@@ -84,52 +113,63 @@ where T: XNum + Copy + Clone {
     power table: table to store g⁰, g¹, g² ... g^(2^w), element pᵢ = g^i.
     log table: table to store log_g{0}, log_g{1}, log_g{2} ... log_g{2^w}, element lᵢ = log_g{i}
     */
-    fn generate_power_log_table(&mut self) -> Result<(), anyhow::Error> {
-        let mut n = 1u8;
-        let g = self.min_generator();
-        if g.is_none() {
-            return Err(anyhow::anyhow!(
-                "generator not found for w ".to_string() + self.w.to_string().as_str()
-            ));
-        }
+    fn generate_power_log_table(&mut self) -> anyhow::Result<()> {
+        let g = self
+            .min_generator()
+            .ok_or_else(|| anyhow::anyhow!("generator not found for w {}", self.w))?;
 
-        self.power[0] = 1u8.from(1u8); // g(0) = 1, first element is 1, it is used to generate power table
+        let mut n = T::one();
+        self.power[0] = T::one(); // g(0) = 1, first element is 1, it is used to generate power table
 
-        for i in 1u8..=((self.element_count - 1) as u8) {
+        for i in 1..self.element_count {
             // g(i) = g(i - 1) * g
             // notice, power table is generated by multiply generator, generator is 2 sometimes, but NOT 2 all times. So, mul generator, NOT 2!
-            n = self.mul(n, g.unwrap());
+            n = self.mul(n, g);
 
-            self.power[i as usize] = n; //self.power.get_mut(i as usize).map(|item| *item = n);
-            self.log[n as usize] = i; //self.log.get_mut(n as usize).map(|item| *item = i);
+            self.power[i] = n;
+            self.log[Self::index(n)] = Self::element(i);
         }
 
         // power[0] = 1, so log[1] = 0, this operation should be put after for cycle, because is could be input with log[0xFF] = 0 in for cycle.
         // power table start with 1 but not 0, so power table has two 0x01 and lack of 0x00.
         // log table is the inverse operation of power table, so log table has two 0x00 and lack of 0xFF.
-        self.log[1] = 0;
-        return Ok(());
+        self.log[1] = T::zero();
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn index(x: T) -> usize {
+        x.to_usize().unwrap()
+    }
+
+    #[inline(always)]
+    fn element(i: usize) -> T {
+        T::from(i).unwrap()
+    }
+
+    pub fn w(&self) -> u8 {
+        self.w
     }
 
     pub fn min_element(&self) -> T {
-        return 0;
+        T::zero()
     }
 
     pub fn max_element(&self) -> T {
-        return ((1u16 << (self.w as u16)) as u8) - 1;
+        self.mask
     }
 
     // How many element exist on current field.
     pub fn get_element_count(&self) -> usize {
-        return self.element_count;
+        self.element_count
     }
 
-    pub fn power_table(self) -> Vec<u8> {
-        return self.power;
+    pub fn power_table(self) -> Vec<T> {
+        self.power
     }
 
-    pub fn log_table(self) -> Vec<u8> {
-        return self.log;
+    pub fn log_table(self) -> Vec<T> {
+        self.log
     }
 
     /**
@@ -137,17 +177,16 @@ where T: XNum + Copy + Clone {
     on $GF(2^w)$, x + y is x xor y
     */
     #[inline(always)]
-    pub fn add(&self, x: u8, y: u8) -> u8 {
+    pub fn add(&self, x: T, y: T) -> T {
         x ^ y
     }
 
     /**
-    FIXME 有些文章说减法就是加上加法逆元，和加法并不相同！
     sub on $GF(2^w)$
     on $GF(2^w)$, x - y is x xor y, same as add
     */
     #[inline(always)]
-    pub fn sub(&self, x: u8, y: u8) -> u8 {
+    pub fn sub(&self, x: T, y: T) -> T {
         x ^ y
     }
 
@@ -155,309 +194,299 @@ where T: XNum + Copy + Clone {
     x multiply 2 on $GF(2^w)$
     */
     #[inline(always)]
-    fn mul_2(&self, x: u8) -> u8 {
-        // overflow_flag is 0x80 on $GF(2^8)$
-        // x << 1 means multiply 2
-        //
+    fn mul_2(&self, x: T) -> T {
         // if x & self.overflow_flag != 0, means highest bit is 1, number will overflow after multiply 2 (left shift 1)
         // if overflows, then mod irreducible polynomial, in GF(2^w), mod irreducible polynomial == XOR
-        if (x & self.overflow_flag) != 0 {
-            (x << 1) ^ self.irreducible_polynomial
+        if (x & self.overflow_flag) != T::zero() {
+            ((x << 1) ^ self.irreducible_polynomial) & self.mask
         } else {
-            x << 1
+            (x << 1) & self.mask
         }
     }
 
     /**
-    Multiplication direcly on $GF(2^w)$, this algorithm comes from my mathematics notes.
-    x, y: input numbers
+    Multiplication directly on $GF(2^w)$, x is doubled once per bit of y.
     */
     #[inline(always)]
     pub fn mul(&self, x: T, y: T) -> T {
-        // temp table which stores x mul 0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80
-        let mut x_mul_0x01_0x80: [u8; 8] = [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
-        x_mul_0x01_0x80[0] = x;
-        for i in 1..8 {
-            x_mul_0x01_0x80[i] = self.mul_2(x_mul_0x01_0x80[i - 1]);
-        }
-
-        let mut res: T = 0;
-        for i in 0..8 {
-            // if ((y >> i) & 0x01) == 0, nothing changed in res
-            res ^= ((y >> i) & 0x01) /*1: yes, 0: no*/ * x_mul_0x01_0x80[i];
+        let mut res = T::zero();
+        let mut x_mul_2_pow_i = x;
+        for i in 0..self.w as usize {
+            if (y >> i) & T::one() == T::one() {
+                res = res ^ x_mul_2_pow_i;
+            }
+            x_mul_2_pow_i = self.mul_2(x_mul_2_pow_i);
         }
-        return res;
+        res
     }
 
     /**
     Multiplication on $GF(2^w)$ through looking up power table and log table.
 
-    if x =0 or y = 0
-    $$
-    x * y = 0
-    $$
-
-    if x != 0 and y != 0
-
     $$
     x * y = g^{(log_g{x}+log_g{y}) \mod (2^w - 1)}
     $$
-
-    In this formula, "+" is add like usually, not "^". If sum overflows, mod $(2^w - 1)$, it is 0xFF(255) on $GF(2^8)$
     */
     #[inline(always)]
-    pub fn mul_by_power_log_table(&self, x: u8, y: u8) -> u8 {
+    pub fn mul_by_power_log_table(&self, x: T, y: T) -> T {
         // in power table there is no 0, if input number has 0, just return correct result -> 0.
-        if x == 0 || y == 0 {
-            return 0;
+        if x == T::zero() || y == T::zero() {
+            return T::zero();
         }
 
-        // if (log_table[x] + log_table[y]) overflows, mod (element - 1), it is 0xFF(255) on $GF(2^8)$
-        let mut sum: u16 = ((self.log[x as usize] as u16) + (self.log[y as usize] as u16))
-            % (self.element_count - 1) as u16;
-        return self.power[sum as usize];
+        let sum = (Self::index(self.log[Self::index(x)]) + Self::index(self.log[Self::index(y)]))
+            % (self.element_count - 1);
+        self.power[sum]
     }
 
     /**
-    Division on $GF(2^w)$ through looking up power table and log table.
+    Division on $GF(2^w)$ through looking up power table and log table, panics if y is 0.
         $$
         x / y = g^{(log_g{x}-log_g{y}) \mod (2^w - 1)}
         $$
     */
     #[inline(always)]
-    pub fn div_by_power_log_table(&self, x: u8, y: u8) -> u8 {
-        if x == 0 {
-            return 0u8;
-        } // 0 mul any number equals 0
-        assert_ne!(y, 0); // can't divide 0
-
-        let mut difference_i64 = (self.log[x as usize] as i64) - (self.log[y as usize] as i64);
-        if difference_i64 < 0 {
-            difference_i64 += (self.element_count - 1) as i64;
+    pub fn div_by_power_log_table(&self, x: T, y: T) -> T {
+        assert_ne!(y, T::zero()); // can't divide 0
+        if x == T::zero() {
+            return T::zero();
         }
-        let mut difference: usize = (difference_i64 as usize) % (self.element_count - 1) as usize;
-        return self.power[difference];
-    }
 
-    /**
-    Multiplication on $GF(2^w)$ utilising SIMD through looking up half table.
-    */
-    #[inline(always)]
-    pub fn mul_by_simd() -> u8 {
-        0
+        let order = self.element_count - 1;
+        let difference = (Self::index(self.log[Self::index(x)]) + order
+            - Self::index(self.log[Self::index(y)]))
+            % order;
+        self.power[difference]
     }
 
     /**
-    Check input number is generator or not.
+    Check input number is generator or not, a generator's powers $g^1 ... g^{2^w - 1}$ are all distinct.
     */
-    fn is_generator(&self, generator: u8) -> bool {
-        let mut products: HashMap<u8, bool> = HashMap::new();
-        let mut n = generator;
-        for i in 1u8..=(self.element_count - 1) as u8 {
-            // found duplicated product, it means this is not a product
-            if products.contains_key(&n) {
-                return false;
-            }
-
-            // save product to map cache
-            products.insert(n, true);
-
-            // calculate product
-            n = self.mul(n, generator);
-        }
-
-        // check products are complete
-        if products.len() != (self.element_count as usize - 1) {
+    pub fn is_generator(&self, generator: T) -> bool {
+        if generator == T::zero() || generator > self.mask {
             return false;
         }
-
-        // in power table, there are 2 duplicated element '1', so check from 2
-        for n in 2u8..=(self.element_count - 1) as u8 {
-            if !products.contains_key(&n) {
+        let mut seen = vec![false; self.element_count];
+        let mut n = generator;
+        for _ in 1..self.element_count {
+            // found duplicated product, or the product is 0 because irreducible polynomial is not irreducible
+            if n == T::zero() || seen[Self::index(n)] {
                 return false;
             }
+            seen[Self::index(n)] = true;
+            n = self.mul(n, generator);
         }
-
-        return true;
+        true
     }
 
     /**
-    Find all generators of $GF(2^w)$
+    Find the minimum generator of $GF(2^w)$
     */
-    pub fn min_generator(&self) -> Option<u8> {
-        for g in 2u8..=((self.element_count - 1) as u8) {
-            if self.is_generator(g) {
-                return Some(g);
-            }
-        }
-        return None;
+    pub fn min_generator(&self) -> Option<T> {
+        (1..self.element_count)
+            .map(Self::element)
+            .find(|g| self.is_generator(*g))
     }
 
     /**
     Find all generators of $GF(2^w)$
     */
-    pub fn all_generators(&self) -> Vec<u8> {
-        let mut res = vec![];
-        for g in 2u8..=((self.element_count - 1) as u8) {
-            if self.is_generator(g) {
-                res.push(g);
-            }
-        }
-        return res;
+    pub fn all_generators(&self) -> Vec<T> {
+        (1..self.element_count)
+            .map(Self::element)
+            .filter(|g| self.is_generator(*g))
+            .collect()
     }
 }
 
-pub fn print_matrix_u8(data: Vec<u8>) {
-    let side_len = (data.len() as f64).sqrt() as usize;
-    for (k, v) in data.iter().enumerate() {
-        if (k + 1) % side_len == 0 {
-            // last element
-            println!("{:02X}", v);
-        } else {
-            // non-last element
-            print!("{:02X},", v);
-        }
+impl<T> GaloisField for XGf2pw<T>
+where
+    T: PrimInt + Unsigned + Debug,
+{
+    type Element = T;
+
+    fn characteristic(&self) -> u64 {
+        2
     }
-    println!();
-}
 
-pub fn print_matrix_u16(data: Vec<u16>) {
-    let side_len = (data.len() as f64).sqrt() as usize;
-    for (k, v) in data.iter().enumerate() {
-        if (k + 1) % side_len == 0 {
-            // last element
-            println!("{:02X}", v);
-        } else {
-            // non-last element
-            print!("{:02X},", v);
-        }
+    fn degree(&self) -> u32 {
+        self.w as u32
+    }
+
+    fn zero(&self) -> T {
+        T::zero()
+    }
+
+    fn one(&self) -> T {
+        T::one()
+    }
+
+    fn add(&self, x: T, y: T) -> T {
+        XGf2pw::add(self, x, y)
+    }
+
+    fn sub(&self, x: T, y: T) -> T {
+        XGf2pw::sub(self, x, y)
+    }
+
+    // on $GF(2^w)$ every element is its own additive inverse
+    fn neg(&self, x: T) -> T {
+        x
+    }
+
+    fn mul(&self, x: T, y: T) -> T {
+        self.mul_by_power_log_table(x, y)
+    }
+
+    fn inv(&self, x: T) -> T {
+        self.div_by_power_log_table(T::one(), x)
+    }
+
+    fn div(&self, x: T, y: T) -> T {
+        self.div_by_power_log_table(x, y)
     }
-    println!();
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    #[test]
-    fn XGf2pw_generate_power_log_table_test() {
-        let res = XGf2pw::new(3, 0x0B, 0u8);
-        if res.is_err() {
-            println!("{}", res.err().unwrap());
-            return;
-            //assert!(res.err().unwrap().to_string());
+    use crate::galois_field::gf_u8::Gf2p;
+
+    // compare every operation with Gf2p on all element pairs
+    fn check_against_gf2p<T>(w: u8)
+    where
+        T: PrimInt + Unsigned + Debug,
+    {
+        let polynomial = GF2PW_DEFAULT_IRREDUCIBLE_POLYNOMIAL_DICT[w as usize];
+        let gf2p = Gf2p::new(w, polynomial as u8).unwrap();
+        let gf = XGf2pw::<T>::with_default_polynomial(w).unwrap();
+        let e = |x: u8| T::from(x).unwrap();
+        let max = gf2p.max_element();
+        assert_eq!(gf.max_element(), e(max));
+        assert_eq!(gf.min_generator(), gf2p.min_generator().map(e));
+
+        for x in 0..=max {
+            if x != 0 {
+                let inv = gf.inv(e(x));
+                assert_eq!(gf.mul(e(x), inv), T::one());
+                assert_eq!(inv, e(gf2p.div_by_power_log_table(1, x)));
+            }
+            for y in 0..=max {
+                assert_eq!(gf.add(e(x), e(y)), e(gf2p.add(x, y)));
+                assert_eq!(gf.mul(e(x), e(y)), e(gf2p.mul(x, y)));
+                assert_eq!(
+                    gf.mul_by_power_log_table(e(x), e(y)),
+                    e(gf2p.mul_by_power_log_table(x, y))
+                );
+                if y != 0 {
+                    assert_eq!(
+                        gf.div_by_power_log_table(e(x), e(y)),
+                        e(gf2p.div_by_power_log_table(x, y))
+                    );
+                }
+            }
         }
-        //assert_eq!(res.is_ok(), true);
-        let gf = res.unwrap();
-
-        //println!("{}", gf.power.to_string());
-
-        print_matrix_u8(gf.power);
+    }
 
-        print_matrix_u8(gf.log);
+    // associativity and distributivity on all element triples
+    fn check_field_axioms<T>(w: u8)
+    where
+        T: PrimInt + Unsigned + Debug,
+    {
+        let gf = XGf2pw::<T>::with_default_polynomial(w).unwrap();
+        let elements: Vec<T> = (0..gf.get_element_count())
+            .map(|i| T::from(i).unwrap())
+            .collect();
+        for x in elements.iter().copied() {
+            for y in elements.iter().copied() {
+                let xy = gf.mul_by_power_log_table(x, y);
+                for z in elements.iter().copied() {
+                    // (x * y) * z = x * (y * z)
+                    assert_eq!(
+                        gf.mul_by_power_log_table(xy, z),
+                        gf.mul_by_power_log_table(x, gf.mul_by_power_log_table(y, z))
+                    );
+                    // x * (y + z) = x * y + x * z
+                    assert_eq!(
+                        gf.mul_by_power_log_table(x, gf.add(y, z)),
+                        gf.add(xy, gf.mul_by_power_log_table(x, z))
+                    );
+                }
+            }
+        }
     }
 
     #[test]
-    fn XGf2pw_mul_test() {
-        let res = XGf2pw::new(8, 0x1B, 0u8);
-        if res.is_err() {
-            println!("{}", res.err().unwrap());
-            return;
-            //assert!(res.err().unwrap().to_string());
+    fn xgf2pw_u8_against_gf2p_test() {
+        for w in 2..=8 {
+            check_against_gf2p::<u8>(w);
+            check_field_axioms::<u8>(w);
         }
-        //assert_eq!(res.is_ok(), true);
-        let dt = res.unwrap();
     }
 
     #[test]
-    fn XGf2pw_add_test() {
-        let mut res = XGf2pw::new(3, 0x0B, 0u8);
-        if res.is_err() {
-            println!("{}", res.err().unwrap());
-            return;
-            //assert!(res.err().unwrap().to_string());
+    fn xgf2pw_u16_against_gf2p_test() {
+        for w in 2..=8 {
+            check_against_gf2p::<u16>(w);
         }
-        let gf = res.unwrap();
-
-        for x in 0x00u8..=7 {
-            for y in 0x00u8..=7 {
-                let quotient = gf.add(x, y);
-                println!("{} + {} = {}", x, y, quotient);
-            }
+        // u16 and u32 share every line of code with u8, larger w only costs time
+        for w in 2..=6 {
+            check_field_axioms::<u16>(w);
         }
     }
 
     #[test]
-    fn XGf2pw_div_by_power_log_table_test() {
-        let mut res = XGf2pw::new(3, 0x0B, 0u8);
-        if res.is_err() {
-            println!("{}", res.err().unwrap());
-            return;
-            //assert!(res.err().unwrap().to_string());
+    fn xgf2pw_u32_against_gf2p_test() {
+        for w in 2..=8 {
+            check_against_gf2p::<u32>(w);
         }
-        let gf = res.unwrap();
-
-        for x in 0x00u8..=7 {
-            for y in 0x01u8..=7 {
-                let quotient = gf.div_by_power_log_table(x, y);
-                println!("{} / {} = {}", x, y, quotient);
-            }
+        for w in 2..=6 {
+            check_field_axioms::<u32>(w);
         }
     }
 
     #[test]
-    fn XGf2pw_diff_mul_cmp_test() {
-        for w in 1u8..=8 {
-            let mut res = XGf2pw::new(w, GF2PW_DEFAULT_IRREDUCIBLE_POLYNOMIAL_DICT[w as usize], 0u8);
-            if res.is_err() {
-                println!("{}", res.err().unwrap());
-                return;
-                //assert!(res.err().unwrap().to_string());
-            }
-            let gf = res.unwrap();
-
-            let maxN = ((1 << w as u16) - 1) as u8; // if w = 8, maxN = 0xFF
-
-            for x in 0x00u8..=maxN {
-                for y in 0x00u8..=maxN {
-                    let sum_direct = gf.mul(x, y);
-                    let sum_table = gf.mul_by_power_log_table(x, y);
-                    if sum_direct != sum_table {
-                        println!(
-                            "x: {}, y: {}: sum_direct: {} vs sum_table: {}",
-                            x, y, sum_direct, sum_table
-                        );
-                    }
-                    assert_eq!(sum_direct, sum_table);
-                }
-            }
-        }
+    fn xgf2pw_new_test() {
+        assert!(XGf2pw::<u8>::new(0, 0x03).is_err());
+        assert!(XGf2pw::<u8>::new(9, 0x11).is_err());
+        assert!(XGf2pw::<u32>::new(GF2PW_MAX_W + 1, 0x03).is_err());
+        // x^8 + 1 = (x + 1)^8 is reducible
+        assert!(XGf2pw::<u16>::new(8, 0x101).is_err());
+        // x^term is optional
+        let with_term = XGf2pw::<u16>::new(8, 0x11D).unwrap();
+        let without_term = XGf2pw::<u16>::new(8, 0x1D).unwrap();
+        assert_eq!(with_term.power_table(), without_term.power_table());
+        // GF(2) has only 0 and 1, 1 is its generator
+        let gf = XGf2pw::<u8>::with_default_polynomial(1).unwrap();
+        assert_eq!(gf.mul(1, 1), 1);
+        assert_eq!(gf.all_generators(), vec![1]);
     }
 
     #[test]
-    fn XGf2pw_all_generators_test() {
-        let mut res = XGf2pw::new(8, 0x1B, 0u8);
-        if res.is_err() {
-            println!("{}", res.err().unwrap());
-            return;
-            //assert!(res.err().unwrap().to_string());
-        }
-        let gf = res.unwrap();
-        let allGeneratos = gf.all_generators();
-        let mut allGeneratosString = "[".to_string();
-        for (k, v) in allGeneratos.iter().enumerate() {
-            allGeneratosString += v.to_string().as_str();
-            if k != allGeneratos.len() - 1 {
-                allGeneratosString += ",";
+    fn xgf2pw_all_generators_test() {
+        let gf = XGf2pw::<u16>::new(8, 0x1B).unwrap();
+        let gf2p = Gf2p::new(8, 0x1B).unwrap();
+        let expected: Vec<u16> = gf2p.all_generators().iter().map(|g| *g as u16).collect();
+        assert_eq!(gf.all_generators(), expected);
+    }
+
+    #[test]
+    fn xgf2pw_wide_field_test() {
+        for w in 9..=GF2PW_MAX_W {
+            let gf = XGf2pw::<u32>::with_default_polynomial(w).unwrap();
+            let max = gf.max_element();
+            for x in (1..=max).step_by(997) {
+                let inv = gf.inv(x);
+                assert_eq!(gf.mul(x, inv), 1);
+                assert_eq!(gf.mul_by_power_log_table(x, inv), 1);
+                for y in (0..=max).step_by(1009) {
+                    assert_eq!(gf.mul(x, y), gf.mul_by_power_log_table(x, y));
+                }
             }
         }
-        allGeneratosString += "]";
-
-        // This generators list is from GF(2^8) in AES algorithm, irreducible polynomial is 0x1B (x⁸ + x⁴ + x³ + x + 1).
-        assert_eq!(allGeneratosString, "[3,5,6,9,11,14,17,18,19,20,23,24,25,26,28,30,31,33,34,35,39,\
-        40,42,44,48,49,60,62,63,65,69,70,71,72,73,75,76,78,79,82,84,86,87,88,89,90,91,95,100,101,104,\
-        105,109,110,112,113,118,119,121,122,123,126,129,132,134,135,136,138,142,143,144,147,149,150,152,\
-        153,155,157,160,164,165,166,167,169,170,172,173,178,180,183,184,185,186,190,191,192,193,196,200,201,\
-        206,207,208,214,215,218,220,221,222,226,227,229,230,231,233,234,235,238,240,241,244,245,246,248,251,253,254,255]");
+        // w = 16 fits in u16 exactly, the x^16 term is dropped
+        let gf = XGf2pw::<u16>::with_default_polynomial(16).unwrap();
+        assert_eq!(gf.max_element(), 0xFFFF);
+        assert_eq!(gf.mul(0xFFFF, gf.inv(0xFFFF)), 1);
     }
 }
@@ -4,6 +4,7 @@
 another multiply algorithm on GF(2^8)： https://blog.csdn.net/codebreakers/article/details/41456149?locationNum=7&fps=1
 */
 
+use crate::galois_field::field::GaloisField;
 use std::collections::HashMap;
 
 // TODO
@@ -25,12 +26,12 @@ const DEFAULT_IRREDUCIBLE_POLYNOMIAL_DICT: [u8; 9] = [
 /**
 in AES(Rijndael), the irreducible polynomial is x⁸ + x⁴ + x³ + x + 1，this polynomial = 0x11B mod 0xFF = 0x1B when x = 2
 */
-const GF2P8_IRREDUCIBLE_POLYNOMIAL_AES: u8 = 0x1B;
+pub const GF2P8_IRREDUCIBLE_POLYNOMIAL_AES: u8 = 0x1B;
 
 /**
 in Erasure Code, the irreducible polynomial is x⁸ + x⁴ + x³ + x² + 1，this polynomial = 0x1D when x = 2
 */
-const GF2P8_IRREDUCIBLE_POLYNOMIAL_ERASURE_CODE: u8 = 0x1D;
+pub const GF2P8_IRREDUCIBLE_POLYNOMIAL_ERASURE_CODE: u8 = 0x1D;
 
 /**
 Galois field arithmetic on $GF(2^w)$
@@ -53,7 +54,7 @@ impl Gf2p {
     irreducible_polynomial: irreducible polynomial selected, and sometimes it is primitive polynomial, sometimes it is not.
     */
     pub fn new(w: u8, irreducible_polynomial: u8) -> anyhow::Result<Gf2p> {
-        if w <= 0 || w >= 9 {
+        if w == 0 || w >= 9 {
             return Err(anyhow::anyhow!(
                 "w cannot be ".to_string() + w.to_string().as_str()
             ));
@@ -114,7 +115,7 @@ impl Gf2p {
     }
 
     pub fn max_element(&self) -> u8 {
-        return ((1u16 << (self.w as u16)) as u8) - 1;
+        ((1u16 << (self.w as u16)) - 1) as u8
     }
 
     // How many element exist on current field.
@@ -305,6 +306,51 @@ impl Gf2p {
     }
 }
 
+impl GaloisField for Gf2p {
+    type Element = u8;
+
+    fn characteristic(&self) -> u64 {
+        2
+    }
+
+    fn degree(&self) -> u32 {
+        self.w as u32
+    }
+
+    fn zero(&self) -> u8 {
+        0
+    }
+
+    fn one(&self) -> u8 {
+        1
+    }
+
+    fn add(&self, x: u8, y: u8) -> u8 {
+        Gf2p::add(self, x, y)
+    }
+
+    fn sub(&self, x: u8, y: u8) -> u8 {
+        Gf2p::sub(self, x, y)
+    }
+
+    // on $GF(2^w)$ every element is its own additive inverse
+    fn neg(&self, x: u8) -> u8 {
+        x
+    }
+
+    fn mul(&self, x: u8, y: u8) -> u8 {
+        self.mul_by_power_log_table(x, y)
+    }
+
+    fn inv(&self, x: u8) -> u8 {
+        self.div_by_power_log_table(1, x)
+    }
+
+    fn div(&self, x: u8, y: u8) -> u8 {
+        self.div_by_power_log_table(x, y)
+    }
+}
+
 pub fn print_matrix_u8(data: Vec<u8>) {
     let side_len = (data.len() as f64).sqrt() as usize;
     for (k, v) in data.iter().enumerate() {
@@ -0,0 +1,274 @@
+/*!
+Galois field arithmetic on prime field $GF(p)$, $p$ is any prime which fits in u64.
+
+Elements are canonical residues $0 \le x < p$. For odd $p$ multiplication uses Montgomery reduction with
+$R = 2^{64}$, so no 128-bit division is needed:
+
+$$
+REDC(T) = T R^{-1} \mod p
+$$
+
+$$
+x \cdot y = REDC(REDC(x y) \cdot (R^{2} \mod p))
+$$
+*/
+use crate::galois_field::field::GaloisField;
+use crate::number::modular::{is_prime_u64, mod_inv_u64};
+
+#[derive(Debug, Clone)]
+pub struct Gfp {
+    p: u64,
+    // p^{-1} mod 2^64, only valid when p is odd
+    p_inv: u64,
+    // R^2 mod p, only valid when p is odd
+    r2: u64,
+}
+
+impl Gfp {
+    /**
+    Create new prime field calculator.
+    p: field characteristic, must be prime.
+    */
+    pub fn new(p: u64) -> anyhow::Result<Gfp> {
+        if !is_prime_u64(p) {
+            return Err(anyhow::anyhow!("{} is not prime", p));
+        }
+
+        let mut p_inv = 1u64;
+        let mut r2 = 0u64;
+        if p % 2 == 1 {
+            // Newton iteration, p * p = 1 mod 8 for odd p, every round doubles correct low bits: 3 -> 6 ... -> 96
+            p_inv = p;
+            for _ in 0..6 {
+                p_inv = p_inv.wrapping_mul(2u64.wrapping_sub(p.wrapping_mul(p_inv)));
+            }
+            let r = (1u128 << 64) % p as u128;
+            r2 = ((r * r) % p as u128) as u64;
+        }
+        Ok(Gfp { p, p_inv, r2 })
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.p
+    }
+
+    /// Map any integer into current field.
+    pub fn element(&self, n: u64) -> u64 {
+        n % self.p
+    }
+
+    /// Map any signed integer into current field.
+    pub fn element_i64(&self, n: i64) -> u64 {
+        (n as i128).rem_euclid(self.p as i128) as u64
+    }
+
+    /**
+    add on $GF(p)$
+    */
+    #[inline(always)]
+    pub fn add(&self, x: u64, y: u64) -> u64 {
+        let (sum, overflow) = x.overflowing_add(y);
+        if overflow || sum >= self.p {
+            sum.wrapping_sub(self.p)
+        } else {
+            sum
+        }
+    }
+
+    /**
+    sub on $GF(p)$
+    */
+    #[inline(always)]
+    pub fn sub(&self, x: u64, y: u64) -> u64 {
+        if x >= y {
+            x - y
+        } else {
+            x.wrapping_sub(y).wrapping_add(self.p)
+        }
+    }
+
+    /**
+    additive inverse on $GF(p)$
+    */
+    #[inline(always)]
+    pub fn neg(&self, x: u64) -> u64 {
+        if x == 0 {
+            0
+        } else {
+            self.p - x
+        }
+    }
+
+    /// Montgomery reduction, input must < p * 2^64, output is t * 2^-64 mod p.
+    #[inline(always)]
+    fn redc(&self, t: u128) -> u64 {
+        let m = (t as u64).wrapping_mul(self.p_inv);
+        let mp_hi = ((m as u128 * self.p as u128) >> 64) as u64;
+        let t_hi = (t >> 64) as u64;
+        // low 64 bits of t and m * p are equal, so (t - m * p) / 2^64 = t_hi - mp_hi
+        if t_hi >= mp_hi {
+            t_hi - mp_hi
+        } else {
+            t_hi.wrapping_sub(mp_hi).wrapping_add(self.p)
+        }
+    }
+
+    #[inline(always)]
+    fn to_montgomery(&self, x: u64) -> u64 {
+        self.redc(x as u128 * self.r2 as u128)
+    }
+
+    /**
+    Multiplication on $GF(p)$.
+    */
+    #[inline(always)]
+    pub fn mul(&self, x: u64, y: u64) -> u64 {
+        if self.p == 2 {
+            return x & y;
+        }
+        let xy_r_inv = self.redc(x as u128 * y as u128);
+        self.redc(xy_r_inv as u128 * self.r2 as u128)
+    }
+
+    /**
+    $x^e$ on $GF(p)$, intermediate products stay in Montgomery form.
+    */
+    pub fn pow(&self, x: u64, e: u64) -> u64 {
+        if self.p == 2 {
+            return if e == 0 { 1 } else { x };
+        }
+        let mut res = self.to_montgomery(1);
+        let mut base = self.to_montgomery(x);
+        let mut e = e;
+        while e > 0 {
+            if e & 1 == 1 {
+                res = self.redc(res as u128 * base as u128);
+            }
+            base = self.redc(base as u128 * base as u128);
+            e >>= 1;
+        }
+        self.redc(res as u128)
+    }
+
+    /**
+    Multiplicative inverse by extended Euclidean algorithm, panics if x is zero.
+    */
+    pub fn inv(&self, x: u64) -> u64 {
+        assert_ne!(x, 0); // 0 has no inverse
+        mod_inv_u64(x, self.p).unwrap()
+    }
+}
+
+impl GaloisField for Gfp {
+    type Element = u64;
+
+    fn characteristic(&self) -> u64 {
+        self.p
+    }
+
+    fn degree(&self) -> u32 {
+        1
+    }
+
+    fn zero(&self) -> u64 {
+        0
+    }
+
+    fn one(&self) -> u64 {
+        1
+    }
+
+    fn add(&self, x: u64, y: u64) -> u64 {
+        Gfp::add(self, x, y)
+    }
+
+    fn sub(&self, x: u64, y: u64) -> u64 {
+        Gfp::sub(self, x, y)
+    }
+
+    fn neg(&self, x: u64) -> u64 {
+        Gfp::neg(self, x)
+    }
+
+    fn mul(&self, x: u64, y: u64) -> u64 {
+        Gfp::mul(self, x, y)
+    }
+
+    fn inv(&self, x: u64) -> u64 {
+        Gfp::inv(self, x)
+    }
+
+    fn pow(&self, x: u64, e: u64) -> u64 {
+        Gfp::pow(self, x, e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gfp_new_test() {
+        assert!(Gfp::new(0).is_err());
+        assert!(Gfp::new(1).is_err());
+        assert!(Gfp::new(2).is_ok());
+        assert!(Gfp::new(3).is_ok());
+        assert!(Gfp::new(91).is_err()); // 7 * 13
+        assert!(Gfp::new(3_215_031_751).is_err()); // strong pseudoprime to bases 2, 3, 5, 7
+        assert!(Gfp::new(18_446_744_073_709_551_557).is_ok()); // largest u64 prime
+    }
+
+    #[test]
+    fn gfp_small_field_exhaustive_test() {
+        for p in [2u64, 3, 5, 7, 11, 13, 251].iter() {
+            let gf = Gfp::new(*p).unwrap();
+            for x in 0..*p {
+                assert_eq!(gf.add(x, gf.neg(x)), 0);
+                for y in 0..*p {
+                    assert_eq!(gf.add(x, y), (x + y) % p);
+                    assert_eq!(gf.sub(x, y), (x + p - y) % p);
+                    assert_eq!(gf.mul(x, y), (x * y) % p);
+                }
+                if x != 0 {
+                    assert_eq!(gf.mul(x, gf.inv(x)), 1);
+                    // Fermat's little theorem
+                    assert_eq!(gf.pow(x, p - 1), 1);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn gfp_large_modulus_test() {
+        let p = 18_446_744_073_709_551_557u64;
+        let gf = Gfp::new(p).unwrap();
+        let xs = [
+            1u64,
+            2,
+            3,
+            p - 1,
+            p - 2,
+            0x1234_5678_9abc_def0,
+            p / 2,
+            p / 3 + 7,
+        ];
+        for x in xs.iter() {
+            for y in xs.iter() {
+                let expected = ((*x as u128 * *y as u128) % p as u128) as u64;
+                assert_eq!(gf.mul(*x, *y), expected);
+                let expected = ((*x as u128 + *y as u128) % p as u128) as u64;
+                assert_eq!(gf.add(*x, *y), expected);
+            }
+            assert_eq!(gf.mul(*x, gf.inv(*x)), 1);
+            assert_eq!(gf.pow(*x, p - 1), 1);
+        }
+        assert_eq!(gf.element_i64(-1), p - 1);
+    }
+
+    #[test]
+    fn gfp_div_test() {
+        let gf = Gfp::new(7).unwrap();
+        assert_eq!(gf.div(3, 5), 2); // 2 * 5 = 10 = 3 mod 7
+        assert_eq!(GaloisField::pow(&gf, 3, 0), 1);
+    }
+}
@@ -0,0 +1,280 @@
+/*!
+Galois field arithmetic on extension field $GF(p^n)$.
+
+$GF(p^n)$ is built as $GF(p)[x] / f(x)$, $f$ is a monic irreducible polynomial of degree $n$ over $GF(p)$.
+An element is a polynomial of degree $< n$, stored as its $n$ coefficients from low degree to high degree,
+so $[1, 2]$ on $GF(3^2)$ means $2x + 1$. Shorter inputs such as a trimmed polynomial are padded with zeros,
+results always have n coefficients.
+*/
+use crate::galois_field::field::GaloisField;
+use crate::galois_field::gfp::Gfp;
+use crate::polynomial::*;
+
+#[derive(Debug, Clone)]
+pub struct Gfpn {
+    base: Gfp,
+    n: usize,
+    irreducible_polynomial: Vec<u64>,
+}
+
+impl Gfpn {
+    /**
+    Create new extension field calculator.
+    p: characteristic, must be prime.
+    irreducible_polynomial: monic irreducible polynomial over $GF(p)$ from low degree to high degree,
+    $x^2 + 1$ over $GF(3)$ is [1, 0, 1].
+    */
+    pub fn new(p: u64, irreducible_polynomial: &[u64]) -> anyhow::Result<Gfpn> {
+        let base = Gfp::new(p)?;
+        let mut f: Vec<u64> = irreducible_polynomial
+            .iter()
+            .map(|c| base.element(*c))
+            .collect();
+        poly_trim(&base, &mut f);
+        let n = match poly_degree(&base, &f) {
+            Some(d) if d >= 1 => d,
+            _ => return Err(anyhow::anyhow!("irreducible polynomial degree must >= 1")),
+        };
+        if f[n] != 1 {
+            return Err(anyhow::anyhow!("irreducible polynomial must be monic"));
+        }
+        if !is_irreducible(&base, &f) {
+            return Err(anyhow::anyhow!(
+                "{:?} is not irreducible over GF({})",
+                irreducible_polynomial,
+                p
+            ));
+        }
+        Ok(Gfpn {
+            base,
+            n,
+            irreducible_polynomial: f,
+        })
+    }
+
+    /// The prime field current field extends.
+    pub fn base_field(&self) -> &Gfp {
+        &self.base
+    }
+
+    pub fn irreducible_polynomial(&self) -> &[u64] {
+        &self.irreducible_polynomial
+    }
+
+    /// Map any polynomial over $GF(p)$ into current field.
+    pub fn element(&self, coefficients: &[u64]) -> Vec<u64> {
+        let a: Vec<u64> = coefficients.iter().map(|c| self.base.element(*c)).collect();
+        let (_, r) = poly_div_rem(&self.base, &a, &self.irreducible_polynomial);
+        self.pad(r)
+    }
+
+    // trimmed polynomial -> fixed length element
+    fn pad(&self, mut a: Vec<u64>) -> Vec<u64> {
+        a.resize(self.n, 0);
+        a
+    }
+}
+
+impl GaloisField for Gfpn {
+    type Element = Vec<u64>;
+
+    fn characteristic(&self) -> u64 {
+        self.base.modulus()
+    }
+
+    fn degree(&self) -> u32 {
+        self.n as u32
+    }
+
+    fn zero(&self) -> Vec<u64> {
+        vec![0; self.n]
+    }
+
+    fn one(&self) -> Vec<u64> {
+        self.pad(vec![1])
+    }
+
+    fn add(&self, x: Vec<u64>, y: Vec<u64>) -> Vec<u64> {
+        self.pad(x)
+            .iter()
+            .zip(self.pad(y).iter())
+            .map(|(a, b)| self.base.add(*a, *b))
+            .collect()
+    }
+
+    fn sub(&self, x: Vec<u64>, y: Vec<u64>) -> Vec<u64> {
+        self.pad(x)
+            .iter()
+            .zip(self.pad(y).iter())
+            .map(|(a, b)| self.base.sub(*a, *b))
+            .collect()
+    }
+
+    fn neg(&self, x: Vec<u64>) -> Vec<u64> {
+        self.pad(x).iter().map(|a| self.base.neg(*a)).collect()
+    }
+
+    fn mul(&self, x: Vec<u64>, y: Vec<u64>) -> Vec<u64> {
+        let product = poly_mul(&self.base, &x, &y);
+        let (_, r) = poly_div_rem(&self.base, &product, &self.irreducible_polynomial);
+        self.pad(r)
+    }
+
+    /// Extended Euclidean algorithm on $GF(p)[x]$: $x s + f t = 1$, so $x^{-1} = s$.
+    fn inv(&self, x: Vec<u64>) -> Vec<u64> {
+        assert!(!self.is_zero(&x)); // 0 has no inverse
+        let (_, s, _) = poly_ext_gcd(&self.base, &x, &self.irreducible_polynomial);
+        self.pad(s)
+    }
+
+    fn is_zero(&self, x: &Vec<u64>) -> bool {
+        x.iter().all(|c| *c == 0)
+    }
+}
+
+/**
+Rabin's irreducibility test, monic f of degree n is irreducible over $GF(p)$ if and only if
+
+$$
+x^{p^{n}} \equiv x \mod f
+$$
+
+and for every prime factor q of n
+
+$$
+\gcd(x^{p^{n/q}} - x \mod f, f) = 1
+$$
+*/
+fn is_irreducible(gf: &Gfp, f: &[u64]) -> bool {
+    let n = f.len() - 1;
+    let p = gf.modulus();
+    let x = vec![0u64, 1];
+
+    // x^(p^k) mod f for k = 0..=n, each one is the previous one raised to p
+    let mut x_powers = vec![poly_div_rem(gf, &x, f).1];
+    for k in 1..=n {
+        let next = poly_pow_mod(gf, &x_powers[k - 1], p, f);
+        x_powers.push(next);
+    }
+
+    if !poly_sub(gf, &x_powers[n], &x_powers[0]).is_empty() {
+        return false;
+    }
+    prime_factors(n).iter().all(|q| {
+        let h = poly_sub(gf, &x_powers[n / q], &x_powers[0]);
+        poly_gcd(gf, &h, f) == vec![1]
+    })
+}
+
+fn prime_factors(mut n: usize) -> Vec<usize> {
+    let mut res = vec![];
+    let mut q = 2;
+    while q * q <= n {
+        if n.is_multiple_of(q) {
+            res.push(q);
+            while n.is_multiple_of(q) {
+                n /= q;
+            }
+        }
+        q += 1;
+    }
+    if n > 1 {
+        res.push(n);
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::galois_field::gf_u8::Gf2p;
+
+    #[test]
+    fn gfpn_new_test() {
+        // x^2 + 1 is irreducible over GF(3), reducible over GF(5): (x + 2)(x + 3)
+        assert!(Gfpn::new(3, &[1, 0, 1]).is_ok());
+        assert!(Gfpn::new(5, &[1, 0, 1]).is_err());
+        // (x^2 + x + 1)^2 has no root over GF(2) but is reducible
+        assert!(Gfpn::new(2, &[1, 0, 1, 0, 1]).is_err());
+        assert!(Gfpn::new(2, &[1, 1, 0, 0, 1]).is_ok());
+        // not monic
+        assert!(Gfpn::new(3, &[1, 0, 2]).is_err());
+        assert!(Gfpn::new(4, &[1, 0, 1]).is_err());
+        assert!(Gfpn::new(3, &[1]).is_err());
+    }
+
+    #[test]
+    fn gfpn_field_axioms_test() {
+        // GF(3^2) and GF(5^3), every non-zero element has an inverse and x^(p^n - 1) = 1
+        for (p, f) in [(3u64, vec![1u64, 0, 1]), (5, vec![2, 0, 1, 1])].iter() {
+            let gf = Gfpn::new(*p, f).unwrap();
+            let n = gf.degree();
+            let order = p.pow(n);
+            let elements: Vec<Vec<u64>> = (0..order)
+                .map(|mut i| {
+                    (0..n)
+                        .map(|_| {
+                            let c = i % p;
+                            i /= p;
+                            c
+                        })
+                        .collect()
+                })
+                .collect();
+
+            for x in elements.iter() {
+                assert_eq!(gf.add(x.clone(), gf.neg(x.clone())), gf.zero());
+                if gf.is_zero(x) {
+                    continue;
+                }
+                assert_eq!(gf.mul(x.clone(), gf.inv(x.clone())), gf.one());
+                assert_eq!(gf.pow(x.clone(), order - 1), gf.one());
+                for y in elements.iter().step_by(7) {
+                    let xy = gf.mul(x.clone(), y.clone());
+                    assert_eq!(xy, gf.mul(y.clone(), x.clone()));
+                    assert_eq!(gf.div(xy, x.clone()), *y);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn gfpn_matches_gf2p_test() {
+        // GF(2^8) with x^8 + x^4 + x^3 + x^2 + 1, same as Gf2p with 0x1D
+        let gf = Gfpn::new(2, &[1, 0, 1, 1, 1, 0, 0, 0, 1]).unwrap();
+        let gf2p = Gf2p::new(8, 0x1D).unwrap();
+        let to_poly = |v: u8| -> Vec<u64> { (0..8).map(|i| ((v >> i) & 1) as u64).collect() };
+        for x in (0u8..=255).step_by(3) {
+            for y in (0u8..=255).step_by(5) {
+                assert_eq!(
+                    gf.mul(to_poly(x), to_poly(y)),
+                    to_poly(gf2p.mul_by_power_log_table(x, y))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn gfpn_element_test() {
+        let gf = Gfpn::new(3, &[1, 0, 1]).unwrap();
+        // x^2 = -1 = 2, and 4 = 1
+        assert_eq!(gf.element(&[4, 0, 1]), vec![0, 0]);
+        assert_eq!(gf.element(&[0, 0, 1]), vec![2, 0]);
+    }
+
+    #[test]
+    fn gfpn_trimmed_element_test() {
+        let gf = Gfpn::new(5, &[2, 0, 1, 1]).unwrap();
+        // [1] is the padded [1, 0, 0]
+        assert_eq!(gf.add(vec![1], gf.zero()), vec![1, 0, 0]);
+        assert_eq!(gf.add(gf.zero(), vec![1]), vec![1, 0, 0]);
+        assert_eq!(gf.sub(vec![], vec![0, 3]), vec![0, 2, 0]);
+        assert_eq!(gf.neg(vec![1]), vec![4, 0, 0]);
+        assert_eq!(gf.mul(vec![3], vec![0, 1]), vec![0, 3, 0]);
+        assert!(gf.is_zero(&vec![]));
+        assert!(gf.is_zero(&vec![0]));
+        assert!(!gf.is_zero(&vec![0, 1]));
+        assert_eq!(gf.mul(vec![0, 1], gf.inv(vec![0, 1])), gf.one());
+        assert_eq!(gf.inv(vec![1]), gf.one());
+    }
+}
@@ -1,2 +1,6 @@
+pub mod field;
+pub mod gf2p128;
+pub mod gf2pw;
 pub mod gf_u8;
-//pub mod gf2pw;
\ No newline at end of file
+pub mod gfp;
+pub mod gfpn;
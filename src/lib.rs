@@ -7,7 +7,9 @@ pub mod ornstein_uhlenbeck_process;
 pub mod polynomial;
 pub mod price_distance;
 pub mod probability_distribution;
+pub mod secret_sharing;
 pub mod single_linked_list;
+pub mod special_function;
 pub mod stat;
 pub mod vandermonde_matrix;
 
@@ -5,8 +5,10 @@ extern crate rand;
 
 use rand::Rng;
 use std::fmt::{Display, Formatter, Result};
-use std::ops::{Add, Mul, Sub};
+use std::ops::{Add, Div, Mul, Sub};
+use num::{One, Zero};
 use self::rand::distributions::uniform::SampleUniform;
+use crate::galois_field::field::GaloisField;
 use crate::galois_field::gf_u8::Gf2p;
 
 #[derive(Debug, Clone)]
@@ -23,11 +25,16 @@ impl<T> Matrix<T>
 where
     T: Copy + Default + SampleUniform,
 {
-    pub fn new(row_size: usize, col_size: usize, val: T) -> Self {
+    pub fn new_random(row_size: usize, col_size: usize, low: T, high: T) -> Matrix<T> {
         let mut data: Vec<Vec<T>> = Vec::new();
 
         for _ in 0..row_size {
-            let row = vec![val; col_size];
+            let mut row: Vec<T> = Vec::new();
+
+            for _ in 0..col_size {
+                row.push(rand::thread_rng().gen_range(low, high));
+            }
+
             data.push(row);
         }
 
@@ -37,17 +44,17 @@ where
             data: data,
         }
     }
+}
 
-    fn new_random(row_size: usize, col_size: usize, low: T, high: T) -> Matrix<T> {
+impl<T> Matrix<T>
+where
+    T: Copy + Default,
+{
+    pub fn new(row_size: usize, col_size: usize, val: T) -> Self {
         let mut data: Vec<Vec<T>> = Vec::new();
 
         for _ in 0..row_size {
-            let mut row: Vec<T> = Vec::new();
-
-            for _ in 0..col_size {
-                row.push(rand::thread_rng().gen_range(low, high));
-            }
-
+            let row = vec![val; col_size];
             data.push(row);
         }
 
@@ -136,6 +143,223 @@ where
 
         new_matrix
     }
+
+    pub fn row_size(&self) -> usize {
+        self.row_size
+    }
+
+    pub fn col_size(&self) -> usize {
+        self.col_size
+    }
+
+    /// Element at row and col, panics if out of range.
+    pub fn get(&self, row: usize, col: usize) -> T {
+        self.data[row][col]
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.data[row]
+    }
+
+    pub fn column(&self, col: usize) -> Vec<T> {
+        self.data.iter().map(|row| row[col]).collect()
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Clone,
+{
+    /**
+    Matrix multiplication on any galois field.
+    $$
+    (A B)_{i j}=\sum_{k=1}^{p} a_{i k} b_{k j}
+    $$
+    */
+    pub fn mul_over_field<F>(&self, multiplier: &Matrix<T>, gf: &F) -> anyhow::Result<Matrix<T>>
+    where
+        F: GaloisField<Element = T>,
+    {
+        if self.col_size != multiplier.row_size {
+            return Err(anyhow::anyhow!("A col size must equal to B row size"));
+        }
+
+        let mut data: Vec<Vec<T>> = Vec::with_capacity(self.row_size);
+        for i in 0..self.row_size {
+            let mut row: Vec<T> = Vec::with_capacity(multiplier.col_size);
+            for j in 0..multiplier.col_size {
+                let mut sum = gf.zero();
+                for k in 0..self.col_size {
+                    let product = gf.mul(self.data[i][k].clone(), multiplier.data[k][j].clone());
+                    sum = gf.add(sum, product);
+                }
+                row.push(sum);
+            }
+            data.push(row);
+        }
+
+        Ok(Matrix {
+            row_size: self.row_size,
+            col_size: multiplier.col_size,
+            data,
+        })
+    }
+
+    /**
+    Inverse matrix on any galois field by Gauss-Jordan elimination.
+    */
+    pub fn inverse_over_field<F>(&self, gf: &F) -> anyhow::Result<Matrix<T>>
+    where
+        F: GaloisField<Element = T>,
+    {
+        if self.row_size != self.col_size || self.row_size == 0 {
+            return Err(anyhow::anyhow!("only non-empty square matrix has inverse"));
+        }
+        let size = self.row_size;
+
+        // [self | I] -> [I | self^-1]
+        let mut work = self.data.clone();
+        let mut inverse: Vec<Vec<T>> = (0..size)
+            .map(|i| {
+                (0..size)
+                    .map(|j| if i == j { gf.one() } else { gf.zero() })
+                    .collect()
+            })
+            .collect();
+
+        for col in 0..size {
+            let pivot = (col..size).find(|row| !gf.is_zero(&work[*row][col]));
+            let pivot = match pivot {
+                Some(row) => row,
+                None => return Err(anyhow::anyhow!("matrix is singular")),
+            };
+            work.swap(col, pivot);
+            inverse.swap(col, pivot);
+
+            let pivot_inv = gf.inv(work[col][col].clone());
+            for j in 0..size {
+                work[col][j] = gf.mul(work[col][j].clone(), pivot_inv.clone());
+                inverse[col][j] = gf.mul(inverse[col][j].clone(), pivot_inv.clone());
+            }
+
+            for row in 0..size {
+                if row == col || gf.is_zero(&work[row][col]) {
+                    continue;
+                }
+                let factor = work[row][col].clone();
+                for j in 0..size {
+                    let w = gf.mul(factor.clone(), work[col][j].clone());
+                    work[row][j] = gf.sub(work[row][j].clone(), w);
+                    let v = gf.mul(factor.clone(), inverse[col][j].clone());
+                    inverse[row][j] = gf.sub(inverse[row][j].clone(), v);
+                }
+            }
+        }
+
+        Ok(Matrix {
+            row_size: size,
+            col_size: size,
+            data: inverse,
+        })
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Clone + Zero + One + PartialEq + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /**
+    Determinant by Gaussian elimination, exact for number types without rounding error such as `Rational`.
+    $$
+    \det(A)=\sum_{\sigma \in S_{n}} \operatorname{sgn}(\sigma) \prod_{i=1}^{n} a_{i, \sigma(i)}
+    $$
+    */
+    pub fn determinant(&self) -> anyhow::Result<T> {
+        if self.row_size != self.col_size {
+            return Err(anyhow::anyhow!("only square matrix has determinant"));
+        }
+        let size = self.row_size;
+        let mut work = self.data.clone();
+        let mut det = T::one();
+
+        for col in 0..size {
+            let pivot = match (col..size).find(|row| !work[*row][col].is_zero()) {
+                Some(row) => row,
+                None => return Ok(T::zero()),
+            };
+            if pivot != col {
+                work.swap(col, pivot);
+                det = T::zero() - det;
+            }
+            det = det * work[col][col].clone();
+
+            for row in col + 1..size {
+                if work[row][col].is_zero() {
+                    continue;
+                }
+                let factor = work[row][col].clone() / work[col][col].clone();
+                let (upper, lower) = work.split_at_mut(row);
+                for (x, y) in lower[0][col..].iter_mut().zip(upper[col][col..].iter()) {
+                    *x = x.clone() - factor.clone() * y.clone();
+                }
+            }
+        }
+        Ok(det)
+    }
+
+    /**
+    Inverse matrix by Gauss-Jordan elimination, exact for number types without rounding error such as `Rational`.
+    */
+    pub fn inverse(&self) -> anyhow::Result<Matrix<T>> {
+        if self.row_size != self.col_size || self.row_size == 0 {
+            return Err(anyhow::anyhow!("only non-empty square matrix has inverse"));
+        }
+        let size = self.row_size;
+
+        // [self | I] -> [I | self^-1]
+        let mut work = self.data.clone();
+        let mut inverse: Vec<Vec<T>> = (0..size)
+            .map(|i| {
+                (0..size)
+                    .map(|j| if i == j { T::one() } else { T::zero() })
+                    .collect()
+            })
+            .collect();
+
+        for col in 0..size {
+            let pivot = match (col..size).find(|row| !work[*row][col].is_zero()) {
+                Some(row) => row,
+                None => return Err(anyhow::anyhow!("matrix is singular")),
+            };
+            work.swap(col, pivot);
+            inverse.swap(col, pivot);
+
+            let pivot_value = work[col][col].clone();
+            for j in 0..size {
+                work[col][j] = work[col][j].clone() / pivot_value.clone();
+                inverse[col][j] = inverse[col][j].clone() / pivot_value.clone();
+            }
+
+            for row in 0..size {
+                if row == col || work[row][col].is_zero() {
+                    continue;
+                }
+                let factor = work[row][col].clone();
+                for j in 0..size {
+                    let w = factor.clone() * work[col][j].clone();
+                    work[row][j] = work[row][j].clone() - w;
+                    let v = factor.clone() * inverse[col][j].clone();
+                    inverse[row][j] = inverse[row][j].clone() - v;
+                }
+            }
+        }
+
+        Ok(Matrix {
+            row_size: size,
+            col_size: size,
+            data: inverse,
+        })
+    }
 }
 
 // Make sure generic type T implements Add (so you can add them together)
@@ -144,7 +368,7 @@ where
 // <Output=T> ensures the T implementation returns a T
 impl<T> Add for Matrix<T>
 where
-    T: Add<Output = T> + Copy + Default + SampleUniform,
+    T: Add<Output = T> + Copy + Default,
 {
     type Output = Matrix<T>;
 
@@ -163,7 +387,7 @@ where
 
 impl<T> Sub for Matrix<T>
 where
-    T: Sub<Output = T> + Copy + Default + SampleUniform,
+    T: Sub<Output = T> + Copy + Default,
 {
     type Output = Matrix<T>;
 
@@ -183,7 +407,7 @@ where
 
 impl<T> Mul for Matrix<T>
 where
-    T: Mul<Output = T> + Copy + Default + Add<Output = T> + SampleUniform,
+    T: Mul<Output = T> + Copy + Default + Add<Output = T>,
 {
     type Output = Matrix<T>;
 
@@ -346,7 +570,7 @@ impl MatrixU8 {
             for nkey in 0..n {
                 let mut sum = 0u8;
                 for pkey in 0..p {
-                    sum = gf.add(sum, gf.mul_by_power_log_table(self.data[mkey][pkey], multiplier.data[nkey][pkey]));
+                    sum = gf.add(sum, gf.mul_by_power_log_table(self.data[mkey][pkey], multiplier.data[pkey][nkey]));
                 }
                 new_matrix.data[mkey][nkey] = sum;
             }
@@ -359,6 +583,8 @@ impl MatrixU8 {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::galois_field::gfp::Gfp;
+    use crate::number::rational::Rational64;
 
     #[test]
     fn Matrix_create_test() {
@@ -508,4 +734,54 @@ mod test {
         println!("{}", format!("{}", c1.unwrap()));
     }
 
+    #[test]
+    fn matrix_over_field_test() {
+        let gf = Gfp::new(7).unwrap();
+        let m = Matrix::new_from_vec(vec![vec![1u64, 2], vec![3, 4]]);
+        let inv = m.inverse_over_field(&gf).unwrap();
+        let identity = m.mul_over_field(&inv, &gf).unwrap();
+        assert_eq!(identity.data, vec![vec![1, 0], vec![0, 1]]);
+
+        let singular = Matrix::new_from_vec(vec![vec![1u64, 2], vec![2, 4]]);
+        assert!(singular.inverse_over_field(&gf).is_err());
+
+        let gf = Gf2p::new(8, 0x1D).unwrap();
+        let cauchy = MatrixU8::new_cauchy_matrix(&gf, 3, 3).unwrap();
+        let inv = cauchy.inverse_over_field(&gf).unwrap();
+        let identity = inv.mul_over_field(&cauchy, &gf).unwrap();
+        assert_eq!(identity.data, MatrixU8::new_identity_matrix(3).unwrap().data);
+    }
+
+    #[test]
+    fn matrix_exact_inverse_test() {
+        let r = |n: i64, d: i64| Rational64::new(n, d).unwrap();
+        // Hilbert matrix is badly conditioned, exact inverse of H_4 has integer entries
+        let h = Matrix::new_from_vec(
+            (1..=4)
+                .map(|i| (1..=4).map(|j| r(1, i + j - 1)).collect())
+                .collect(),
+        );
+        assert_eq!(h.determinant().unwrap(), r(1, 6048000));
+        let inv = h.inverse().unwrap();
+        assert_eq!(
+            inv.data[0],
+            vec![r(16, 1), r(-120, 1), r(240, 1), r(-140, 1)]
+        );
+        let product = h.clone() * inv;
+        for i in 0..4 {
+            for j in 0..4 {
+                let want = if i == j { 1 } else { 0 };
+                assert_eq!(product.data[i][j], r(want, 1));
+            }
+        }
+
+        // needs row swap, and singular matrix
+        let m = Matrix::new_from_vec(vec![vec![r(0, 1), r(2, 1)], vec![r(3, 1), r(4, 1)]]);
+        assert_eq!(m.determinant().unwrap(), r(-6, 1));
+        assert_eq!(m.inverse().unwrap().data[0], vec![r(-2, 3), r(1, 3)]);
+        let singular = Matrix::new_from_vec(vec![vec![r(1, 2), r(1, 3)], vec![r(3, 2), r(1, 1)]]);
+        assert_eq!(singular.determinant().unwrap(), r(0, 1));
+        assert!(singular.inverse().is_err());
+        assert!(Matrix::new(2, 3, r(1, 1)).determinant().is_err());
+    }
 }
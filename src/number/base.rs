@@ -0,0 +1,136 @@
+/*!
+Common parts of Base64, Base32 and Base58 codecs.
+
+Base64 and Base32 are both bit packing codecs: every symbol carries 6 or 5 bits,
+the last symbol is filled with zero bits, and padding `=` makes symbol count a multiple of 4 or 8.
+*/
+use core::fmt;
+
+/// Base64, Base32 and Base58 codec error type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BaseErr {
+    /// Character is not in the alphabet, or the last symbol has non-zero fill bits.
+    InvalidChar { c: char, index: usize },
+
+    /// Count of symbols can't be decoded into whole bytes.
+    InvalidLen,
+
+    /// Padding is missing, redundant or not at the end.
+    InvalidPadding,
+
+    /// Base58Check checksum mismatch.
+    InvalidChecksum,
+}
+
+impl std::error::Error for BaseErr {}
+
+impl fmt::Display for BaseErr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BaseErr::InvalidChar { c, index } => {
+                write!(f, "Invalid char {:?} at index {}", c, index)
+            }
+            BaseErr::InvalidLen => write!(f, "Invalid length of string to decode"),
+            BaseErr::InvalidPadding => write!(f, "Invalid padding"),
+            BaseErr::InvalidChecksum => write!(f, "Invalid checksum"),
+        }
+    }
+}
+
+/// Encode src by `bits` bits per symbol, pad with `=` to a multiple of group_len symbols if group_len > 0.
+pub(crate) fn encode_bits(src: &[u8], bits: u32, table: &[u8], group_len: usize) -> String {
+    let mask = (1u32 << bits) - 1;
+    let mut dst = Vec::with_capacity((src.len() * 8).div_ceil(bits as usize) + group_len);
+    let mut buf = 0u32;
+    let mut buf_bits = 0u32;
+    for b in src.iter() {
+        buf = (buf << 8) | *b as u32;
+        buf_bits += 8;
+        while buf_bits >= bits {
+            buf_bits -= bits;
+            dst.push(table[((buf >> buf_bits) & mask) as usize]);
+        }
+    }
+    if buf_bits > 0 {
+        dst.push(table[((buf << (bits - buf_bits)) & mask) as usize]);
+    }
+    if group_len > 0 {
+        while !dst.len().is_multiple_of(group_len) {
+            dst.push(b'=');
+        }
+    }
+    String::from_utf8(dst).unwrap()
+}
+
+/**
+Strip padding of src and check it, src must be a multiple of group_len symbols,
+padding count must be the smallest one making that true.
+*/
+pub(crate) fn strip_padding(src: &[u8], group_len: usize) -> Result<&[u8], BaseErr> {
+    if !src.len().is_multiple_of(group_len) {
+        return Err(BaseErr::InvalidLen);
+    }
+    let data_len = src.len() - src.iter().rev().take_while(|c| **c == b'=').count();
+    if src.len() - data_len >= group_len {
+        return Err(BaseErr::InvalidPadding);
+    }
+    Ok(&src[..data_len])
+}
+
+/**
+Decode src by `bits` bits per symbol, decode maps a character to its value, None for invalid ones,
+characters for which skip returns true are ignored.
+*/
+pub(crate) fn decode_bits<D, S>(
+    src: &[u8],
+    bits: u32,
+    decode: D,
+    skip: S,
+) -> Result<Vec<u8>, BaseErr>
+where
+    D: Fn(u8) -> Option<u8>,
+    S: Fn(u8) -> bool,
+{
+    let mut dst = Vec::with_capacity(src.len() * bits as usize / 8);
+    let mut buf = 0u32;
+    let mut buf_bits = 0u32;
+    let mut last_index = 0;
+    for (i, c) in src.iter().enumerate() {
+        if skip(*c) {
+            continue;
+        }
+        let v = decode(*c).ok_or(BaseErr::InvalidChar {
+            c: *c as char,
+            index: i,
+        })?;
+        buf = (buf << bits) | v as u32;
+        buf_bits += bits;
+        if buf_bits >= 8 {
+            buf_bits -= 8;
+            dst.push((buf >> buf_bits) as u8);
+        }
+        buf &= (1 << buf_bits) - 1;
+        last_index = i;
+    }
+
+    // left bits are fill bits of last symbol, a whole symbol of fill bits is never produced by encoder
+    if buf_bits >= bits {
+        return Err(BaseErr::InvalidLen);
+    }
+    if buf != 0 {
+        return Err(BaseErr::InvalidChar {
+            c: src[last_index] as char,
+            index: last_index,
+        });
+    }
+    Ok(dst)
+}
+
+/// Reverse lookup table of alphabet, 0xFF for characters not in alphabet.
+pub(crate) fn reverse_table(table: &[u8]) -> [u8; 256] {
+    let mut res = [0xFFu8; 256];
+    for (i, c) in table.iter().enumerate() {
+        res[*c as usize] = i as u8;
+    }
+    res
+}
@@ -0,0 +1,180 @@
+use crate::number::base::*;
+
+const BASE32_TABLE_RFC4648: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const BASE32_TABLE_CROCKFORD: &[u8] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Base32 alphabets and padding rules.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Base32Variant {
+    /// RFC 4648 upper case alphabet `A-Z 2-7`, padded with `=`.
+    Rfc4648,
+    /// RFC 4648 alphabet, no padding.
+    Rfc4648NoPad,
+    /**
+    Crockford's alphabet `0-9 A-Z` without `I L O U`, no padding.
+    Decoding is case insensitive, maps `O` to 0 and `I L` to 1, and ignores `-`.
+    */
+    Crockford,
+}
+
+/// Base32 encoder trait
+pub trait Base32Enc {
+    /// Encode bytes to base32 string
+    /// Example: encode u8 slice [0x66, 0x6f] to str "MZXQ====" with `Base32Variant::Rfc4648`
+    fn u_base32_encode(&self, variant: Base32Variant) -> String;
+}
+
+/// Base32 decoder trait
+pub trait Base32Dec {
+    /// Decode base32 string to bytes
+    /// Example: decode str "MZXQ====" to u8 slice [0x66, 0x6f] with `Base32Variant::Rfc4648`
+    fn u_base32_decode(&self, variant: Base32Variant) -> Result<Vec<u8>, BaseErr>;
+}
+
+impl Base32Enc for &[u8] {
+    fn u_base32_encode(&self, variant: Base32Variant) -> String {
+        base32_encode(self, variant)
+    }
+}
+
+impl Base32Dec for str {
+    fn u_base32_decode(&self, variant: Base32Variant) -> Result<Vec<u8>, BaseErr> {
+        base32_decode(self.as_bytes(), variant)
+    }
+}
+
+pub fn base32_encode(src: &[u8], variant: Base32Variant) -> String {
+    match variant {
+        Base32Variant::Rfc4648 => encode_bits(src, 5, BASE32_TABLE_RFC4648, 8),
+        Base32Variant::Rfc4648NoPad => encode_bits(src, 5, BASE32_TABLE_RFC4648, 0),
+        Base32Variant::Crockford => encode_bits(src, 5, BASE32_TABLE_CROCKFORD, 0),
+    }
+}
+
+/// Decode base32 string, padding is required by `Rfc4648` and rejected by the others.
+pub fn base32_decode(src: &[u8], variant: Base32Variant) -> Result<Vec<u8>, BaseErr> {
+    match variant {
+        Base32Variant::Rfc4648 | Base32Variant::Rfc4648NoPad => {
+            let data = if variant == Base32Variant::Rfc4648 {
+                strip_padding(src, 8)?
+            } else {
+                src
+            };
+            let reverse = reverse_table(BASE32_TABLE_RFC4648);
+            decode_bits(
+                data,
+                5,
+                |c| Some(reverse[c as usize]).filter(|v| *v != 0xFF),
+                |_| false,
+            )
+        }
+        Base32Variant::Crockford => {
+            let reverse = reverse_table(BASE32_TABLE_CROCKFORD);
+            decode_bits(
+                src,
+                5,
+                |c| {
+                    let c = match c.to_ascii_uppercase() {
+                        b'O' => b'0',
+                        b'I' | b'L' => b'1',
+                        c => c,
+                    };
+                    Some(reverse[c as usize]).filter(|v| *v != 0xFF)
+                },
+                |c| c == b'-',
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base32_rfc4648_test() {
+        let cases = [
+            ("", ""),
+            ("f", "MY======"),
+            ("fo", "MZXQ===="),
+            ("foo", "MZXW6==="),
+            ("foob", "MZXW6YQ="),
+            ("fooba", "MZXW6YTB"),
+            ("foobar", "MZXW6YTBOI======"),
+        ];
+        for (plain, encoded) in cases.iter() {
+            let bytes = plain.as_bytes();
+            assert_eq!(bytes.u_base32_encode(Base32Variant::Rfc4648), *encoded);
+            assert_eq!(
+                encoded.u_base32_decode(Base32Variant::Rfc4648),
+                Ok(bytes.to_vec())
+            );
+            let no_pad = encoded.trim_end_matches('=');
+            assert_eq!(base32_encode(bytes, Base32Variant::Rfc4648NoPad), no_pad);
+            assert_eq!(
+                no_pad.u_base32_decode(Base32Variant::Rfc4648NoPad),
+                Ok(bytes.to_vec())
+            );
+        }
+    }
+
+    #[test]
+    fn base32_crockford_test() {
+        let src = b"Hello";
+        let encoded = base32_encode(src, Base32Variant::Crockford);
+        assert_eq!(encoded, "91JPRV3F");
+        assert_eq!(
+            "91jp-rv3f".u_base32_decode(Base32Variant::Crockford),
+            Ok(src.to_vec())
+        );
+        // O -> 0, I and L -> 1
+        assert_eq!(
+            "0Z".u_base32_decode(Base32Variant::Crockford),
+            "OZ".u_base32_decode(Base32Variant::Crockford)
+        );
+        assert_eq!(
+            "1Z".u_base32_decode(Base32Variant::Crockford),
+            "lZ".u_base32_decode(Base32Variant::Crockford)
+        );
+        assert_eq!(
+            "9U".u_base32_decode(Base32Variant::Crockford),
+            Err(BaseErr::InvalidChar { c: 'U', index: 1 })
+        );
+    }
+
+    #[test]
+    fn base32_decode_err_test() {
+        let rfc = Base32Variant::Rfc4648;
+        assert_eq!("MZXQ===".u_base32_decode(rfc), Err(BaseErr::InvalidLen));
+        assert_eq!("MZX=====".u_base32_decode(rfc), Err(BaseErr::InvalidLen));
+        assert_eq!(
+            "========".u_base32_decode(rfc),
+            Err(BaseErr::InvalidPadding)
+        );
+        assert_eq!(
+            "MZ======".u_base32_decode(rfc),
+            Err(BaseErr::InvalidChar { c: 'Z', index: 1 })
+        );
+        assert_eq!(
+            "mzxq====".u_base32_decode(rfc),
+            Err(BaseErr::InvalidChar { c: 'm', index: 0 })
+        );
+    }
+
+    #[test]
+    fn base32_round_trip_test() {
+        let data: Vec<u8> = (0..=255).rev().collect();
+        for variant in [
+            Base32Variant::Rfc4648,
+            Base32Variant::Rfc4648NoPad,
+            Base32Variant::Crockford,
+        ]
+        .iter()
+        {
+            for len in 0..40 {
+                let encoded = base32_encode(&data[..len], *variant);
+                assert_eq!(encoded.u_base32_decode(*variant), Ok(data[..len].to_vec()));
+            }
+        }
+    }
+}
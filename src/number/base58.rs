@@ -0,0 +1,277 @@
+/*!
+Base58 with Bitcoin alphabet, which removes `0 O I l + /` from base64 to avoid visual ambiguity.
+
+Unlike base64 it treats input as one big-endian number, so encoding is $O(n^2)$,
+every leading zero byte is encoded as a leading `1`.
+*/
+use crate::number::base::*;
+
+const BASE58_TABLE_BITCOIN: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Base58 variants.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Base58Variant {
+    Bitcoin,
+    /// Bitcoin alphabet with a 4 byte checksum appended, the first 4 bytes of $SHA256(SHA256(payload))$.
+    BitcoinCheck,
+}
+
+/// Base58 encoder trait
+pub trait Base58Enc {
+    /// Encode bytes to base58 string
+    /// Example: encode u8 slice [0x00, 0x01] to str "12" with `Base58Variant::Bitcoin`
+    fn u_base58_encode(&self, variant: Base58Variant) -> String;
+}
+
+/// Base58 decoder trait
+pub trait Base58Dec {
+    /// Decode base58 string to bytes, checksum is verified and removed with `Base58Variant::BitcoinCheck`
+    /// Example: decode str "12" to u8 slice [0x00, 0x01] with `Base58Variant::Bitcoin`
+    fn u_base58_decode(&self, variant: Base58Variant) -> Result<Vec<u8>, BaseErr>;
+}
+
+impl Base58Enc for &[u8] {
+    fn u_base58_encode(&self, variant: Base58Variant) -> String {
+        base58_encode(self, variant)
+    }
+}
+
+impl Base58Dec for str {
+    fn u_base58_decode(&self, variant: Base58Variant) -> Result<Vec<u8>, BaseErr> {
+        base58_decode(self.as_bytes(), variant)
+    }
+}
+
+pub fn base58_encode(src: &[u8], variant: Base58Variant) -> String {
+    match variant {
+        Base58Variant::Bitcoin => _base58_encode(src),
+        Base58Variant::BitcoinCheck => {
+            let mut payload = src.to_vec();
+            payload.extend_from_slice(&sha256(&sha256(src))[..4]);
+            _base58_encode(&payload)
+        }
+    }
+}
+
+pub fn base58_decode(src: &[u8], variant: Base58Variant) -> Result<Vec<u8>, BaseErr> {
+    let mut data = _base58_decode(src)?;
+    if variant == Base58Variant::BitcoinCheck {
+        if data.len() < 4 {
+            return Err(BaseErr::InvalidLen);
+        }
+        let checksum = data.split_off(data.len() - 4);
+        if checksum[..] != sha256(&sha256(&data))[..4] {
+            return Err(BaseErr::InvalidChecksum);
+        }
+    }
+    Ok(data)
+}
+
+fn _base58_encode(src: &[u8]) -> String {
+    let zeros = src.iter().take_while(|b| **b == 0).count();
+    // little-endian base58 digits of the number
+    let mut digits: Vec<u8> = Vec::with_capacity(src.len() * 138 / 100 + 1);
+    for b in src[zeros..].iter() {
+        let mut carry = *b as u32;
+        for d in digits.iter_mut() {
+            carry += (*d as u32) << 8;
+            *d = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let mut dst = vec![BASE58_TABLE_BITCOIN[0]; zeros];
+    dst.extend(
+        digits
+            .iter()
+            .rev()
+            .map(|d| BASE58_TABLE_BITCOIN[*d as usize]),
+    );
+    String::from_utf8(dst).unwrap()
+}
+
+fn _base58_decode(src: &[u8]) -> Result<Vec<u8>, BaseErr> {
+    let reverse = reverse_table(BASE58_TABLE_BITCOIN);
+    let zeros = src
+        .iter()
+        .take_while(|c| **c == BASE58_TABLE_BITCOIN[0])
+        .count();
+    // little-endian bytes of the number
+    let mut bytes: Vec<u8> = Vec::with_capacity(src.len() * 733 / 1000 + 1);
+    for (i, c) in src.iter().enumerate().skip(zeros) {
+        let v = reverse[*c as usize];
+        if v == 0xFF {
+            return Err(BaseErr::InvalidChar {
+                c: *c as char,
+                index: i,
+            });
+        }
+        let mut carry = v as u32;
+        for b in bytes.iter_mut() {
+            carry += *b as u32 * 58;
+            *b = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+
+    let mut dst = vec![0u8; zeros];
+    dst.extend(bytes.iter().rev());
+    Ok(dst)
+}
+
+const SHA256_K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// SHA-256 of FIPS 180-4, only used by Base58Check checksum.
+fn sha256(data: &[u8]) -> [u8; 32] {
+    let mut h: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+        0x5be0cd19,
+    ];
+
+    // padding: 0x80, zeros, then bit length as u64 big-endian, to a multiple of 64 bytes
+    let mut msg = data.to_vec();
+    msg.push(0x80);
+    while msg.len() % 64 != 56 {
+        msg.push(0);
+    }
+    msg.extend_from_slice(&((data.len() as u64) * 8).to_be_bytes());
+
+    for block in msg.chunks(64) {
+        let mut w = [0u32; 64];
+        for (i, word) in block.chunks(4).enumerate() {
+            w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for i in 16..64 {
+            let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+            let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16]
+                .wrapping_add(s0)
+                .wrapping_add(w[i - 7])
+                .wrapping_add(s1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut hh] = h;
+        for i in 0..64 {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ (!e & g);
+            let t1 = hh
+                .wrapping_add(s1)
+                .wrapping_add(ch)
+                .wrapping_add(SHA256_K[i])
+                .wrapping_add(w[i]);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let t2 = s0.wrapping_add(maj);
+            hh = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(t1);
+            d = c;
+            c = b;
+            b = a;
+            a = t1.wrapping_add(t2);
+        }
+        for (x, y) in h.iter_mut().zip([a, b, c, d, e, f, g, hh].iter()) {
+            *x = x.wrapping_add(*y);
+        }
+    }
+
+    let mut res = [0u8; 32];
+    for (i, x) in h.iter().enumerate() {
+        res[i * 4..i * 4 + 4].copy_from_slice(&x.to_be_bytes());
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::hex::{HexDec, HexEnc};
+
+    #[test]
+    fn sha256_test() {
+        assert_eq!(
+            sha256(b"").as_ref().u_hex_encode(false),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            sha256(b"abc").as_ref().u_hex_encode(false),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        // two blocks
+        assert_eq!(
+            sha256(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq")
+                .as_ref()
+                .u_hex_encode(false),
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+        );
+    }
+
+    #[test]
+    fn base58_test() {
+        let cases = [
+            ("", ""),
+            ("61", "2g"),
+            ("626262", "a3gV"),
+            ("636363", "aPEr"),
+            ("00000000000000000000", "1111111111"),
+            ("000111d38e5fc9071ffcd20b4a763cc9ae4f252bb4e48fd66a835e252ada93ff480d6dd43dc62a641155a5", "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz"),
+            ("516b6fcd0f", "ABnLTmg"),
+            ("00eb15231dfceb60925886b67d065299925915aeb172c06647", "1NS17iag9jJgTHD1VXjvLCEnZuQ3rJDE9L"),
+        ];
+        for (hex, encoded) in cases.iter() {
+            let bytes = hex.u_hex_decode().unwrap();
+            assert_eq!(
+                bytes.as_slice().u_base58_encode(Base58Variant::Bitcoin),
+                *encoded
+            );
+            assert_eq!(encoded.u_base58_decode(Base58Variant::Bitcoin), Ok(bytes));
+        }
+        assert_eq!(
+            "3mJr0".u_base58_decode(Base58Variant::Bitcoin),
+            Err(BaseErr::InvalidChar { c: '0', index: 4 })
+        );
+    }
+
+    #[test]
+    fn base58check_test() {
+        // bitcoin address of version 0x00 and hash160 of a public key
+        let payload = "00f54a5851e9372b87810a8e60cdd2e7cfd80b6e31"
+            .u_hex_decode()
+            .unwrap();
+        let address = "1PMycacnJaSqwwJqjawXBErnLsZ7RkXUAs";
+        assert_eq!(
+            base58_encode(&payload, Base58Variant::BitcoinCheck),
+            address
+        );
+        assert_eq!(
+            address.u_base58_decode(Base58Variant::BitcoinCheck),
+            Ok(payload)
+        );
+        assert_eq!(
+            "1PMycacnJaSqwwJqjawXBErnLsZ7RkXUAt".u_base58_decode(Base58Variant::BitcoinCheck),
+            Err(BaseErr::InvalidChecksum)
+        );
+        assert_eq!(
+            "2g".u_base58_decode(Base58Variant::BitcoinCheck),
+            Err(BaseErr::InvalidLen)
+        );
+    }
+}
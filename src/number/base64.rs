@@ -0,0 +1,169 @@
+use crate::number::base::*;
+
+const BASE64_TABLE_STANDARD: &[u8] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const BASE64_TABLE_URL_SAFE: &[u8] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Base64 alphabets and padding rules of RFC 4648.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Base64Variant {
+    /// `+` and `/`, padded with `=`.
+    Standard,
+    /// `+` and `/`, no padding.
+    StandardNoPad,
+    /// `-` and `_`, padded with `=`, safe in URLs and file names.
+    UrlSafe,
+    /// `-` and `_`, no padding.
+    UrlSafeNoPad,
+}
+
+impl Base64Variant {
+    fn table(self) -> &'static [u8] {
+        match self {
+            Base64Variant::Standard | Base64Variant::StandardNoPad => BASE64_TABLE_STANDARD,
+            Base64Variant::UrlSafe | Base64Variant::UrlSafeNoPad => BASE64_TABLE_URL_SAFE,
+        }
+    }
+
+    fn padded(self) -> bool {
+        self == Base64Variant::Standard || self == Base64Variant::UrlSafe
+    }
+}
+
+/// Base64 encoder trait
+pub trait Base64Enc {
+    /// Encode bytes to base64 string
+    /// Example: encode u8 slice [0x66, 0x6f] to str "Zm8=" with `Base64Variant::Standard`
+    fn u_base64_encode(&self, variant: Base64Variant) -> String;
+}
+
+/// Base64 decoder trait
+pub trait Base64Dec {
+    /// Decode base64 string to bytes
+    /// Example: decode str "Zm8=" to u8 slice [0x66, 0x6f] with `Base64Variant::Standard`
+    fn u_base64_decode(&self, variant: Base64Variant) -> Result<Vec<u8>, BaseErr>;
+}
+
+impl Base64Enc for &[u8] {
+    fn u_base64_encode(&self, variant: Base64Variant) -> String {
+        base64_encode(self, variant)
+    }
+}
+
+impl Base64Dec for str {
+    fn u_base64_decode(&self, variant: Base64Variant) -> Result<Vec<u8>, BaseErr> {
+        base64_decode(self.as_bytes(), variant)
+    }
+}
+
+pub fn base64_encode(src: &[u8], variant: Base64Variant) -> String {
+    let group_len = if variant.padded() { 4 } else { 0 };
+    encode_bits(src, 6, variant.table(), group_len)
+}
+
+/// Decode base64 string, padding is required by padded variants and rejected by the others.
+pub fn base64_decode(src: &[u8], variant: Base64Variant) -> Result<Vec<u8>, BaseErr> {
+    let data = if variant.padded() {
+        strip_padding(src, 4)?
+    } else {
+        src
+    };
+    let reverse = reverse_table(variant.table());
+    decode_bits(
+        data,
+        6,
+        |c| Some(reverse[c as usize]).filter(|v| *v != 0xFF),
+        |_| false,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64_rfc4648_test() {
+        let cases = [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ];
+        for (plain, encoded) in cases.iter() {
+            let bytes = plain.as_bytes();
+            assert_eq!(bytes.u_base64_encode(Base64Variant::Standard), *encoded);
+            assert_eq!(
+                encoded.u_base64_decode(Base64Variant::Standard),
+                Ok(bytes.to_vec())
+            );
+            let no_pad = encoded.trim_end_matches('=');
+            assert_eq!(base64_encode(bytes, Base64Variant::StandardNoPad), no_pad);
+            assert_eq!(
+                base64_decode(no_pad.as_bytes(), Base64Variant::StandardNoPad),
+                Ok(bytes.to_vec())
+            );
+        }
+    }
+
+    #[test]
+    fn base64_url_safe_test() {
+        let src = [0xfb, 0xff, 0xbf];
+        assert_eq!(base64_encode(&src, Base64Variant::Standard), "+/+/");
+        assert_eq!(base64_encode(&src, Base64Variant::UrlSafe), "-_-_");
+        assert_eq!(base64_encode(&src[..1], Base64Variant::UrlSafeNoPad), "-w");
+        assert_eq!(
+            "-_-_".u_base64_decode(Base64Variant::UrlSafeNoPad),
+            Ok(src.to_vec())
+        );
+        assert_eq!(
+            "+/+/".u_base64_decode(Base64Variant::UrlSafe),
+            Err(BaseErr::InvalidChar { c: '+', index: 0 })
+        );
+    }
+
+    #[test]
+    fn base64_decode_err_test() {
+        let std = Base64Variant::Standard;
+        assert_eq!("Zm8".u_base64_decode(std), Err(BaseErr::InvalidLen));
+        assert_eq!(
+            "Zm==Zm8=".u_base64_decode(std),
+            Err(BaseErr::InvalidChar { c: '=', index: 2 })
+        );
+        assert_eq!("Z===".u_base64_decode(std), Err(BaseErr::InvalidLen));
+        assert_eq!("====".u_base64_decode(std), Err(BaseErr::InvalidPadding));
+        assert_eq!(
+            "Zm9=".u_base64_decode(std),
+            Err(BaseErr::InvalidChar { c: '9', index: 2 })
+        );
+        assert_eq!(
+            "Zm8=".u_base64_decode(Base64Variant::StandardNoPad),
+            Err(BaseErr::InvalidChar { c: '=', index: 3 })
+        );
+        assert_eq!(
+            "Zm.v".u_base64_decode(std),
+            Err(BaseErr::InvalidChar { c: '.', index: 2 })
+        );
+    }
+
+    #[test]
+    fn base64_round_trip_test() {
+        let data: Vec<u8> = (0..=255).collect();
+        for variant in [
+            Base64Variant::Standard,
+            Base64Variant::StandardNoPad,
+            Base64Variant::UrlSafe,
+            Base64Variant::UrlSafeNoPad,
+        ]
+        .iter()
+        {
+            for len in 0..data.len() {
+                let encoded = base64_encode(&data[..len], *variant);
+                assert_eq!(encoded.u_base64_decode(*variant), Ok(data[..len].to_vec()));
+            }
+        }
+    }
+}
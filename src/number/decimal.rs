@@ -1,4 +1,5 @@
 use crate::number::decimal::XdecimalErr::NoneResult;
+use crate::number::number::{XFloat, XNum};
 use core::fmt;
 use rust_decimal::prelude::*;
 use std::any::TypeId;
@@ -13,15 +14,55 @@ pub enum XdecimalErr {
         from_type: TypeId,
     },
     NoneResult,
+    /// result is out of decimal range.
+    Overflow,
+    DivideByZero,
+    /// operand is out of function domain, such as sqrt of negative number.
+    InvalidOperand,
 }
 
+/**
+Decimal number with 96 bit mantissa and scale 0 ~ 28, no precision is lost on base 10 calculations,
+so it suits money calculations.
+
+Operators `+ - * / %` panic on overflow or division by zero like primitive integers,
+use `checked_*` methods to get errors instead.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Xdecimal {
     ir: Decimal,
 }
 
+/// Rounding modes of `Xdecimal::round_dp`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RoundingMode {
+    /// Round to nearest, ties to even, also known as banker's rounding: 2.5 -> 2, 3.5 -> 4.
+    HalfEven,
+    /// Round to nearest, ties away from zero: 2.5 -> 3, -2.5 -> -3.
+    HalfUp,
+    /// Round toward negative infinity: -2.1 -> -3.
+    Floor,
+    /// Round toward positive infinity: 2.1 -> 3.
+    Ceil,
+    /// Round toward zero: -2.9 -> -2.
+    Truncate,
+}
+
+impl RoundingMode {
+    fn strategy(self) -> RoundingStrategy {
+        match self {
+            RoundingMode::HalfEven => RoundingStrategy::MidpointNearestEven,
+            RoundingMode::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+            RoundingMode::Floor => RoundingStrategy::ToNegativeInfinity,
+            RoundingMode::Ceil => RoundingStrategy::ToPositiveInfinity,
+            RoundingMode::Truncate => RoundingStrategy::ToZero,
+        }
+    }
+}
+
 impl std::error::Error for XdecimalErr {}
 impl std::convert::From<&str> for XdecimalErr {
-    fn from(value: &str) -> XdecimalErr {
+    fn from(_value: &str) -> XdecimalErr {
         NoneResult
     }
 }
@@ -33,6 +74,9 @@ impl fmt::Display for XdecimalErr {
                 write!(f, "Unsupported decimal from type {:?}", from_type)
             }
             XdecimalErr::NoneResult => write!(f, "none result"),
+            XdecimalErr::Overflow => write!(f, "decimal overflow"),
+            XdecimalErr::DivideByZero => write!(f, "decimal divide by zero"),
+            XdecimalErr::InvalidOperand => write!(f, "invalid decimal operand"),
         }
     }
 }
@@ -64,20 +108,134 @@ impl TryFrom<&str> for Xdecimal {
     }
 }
 
-impl Xdecimal {
+impl FromStr for Xdecimal {
+    type Err = rust_decimal::Error;
     fn from_str(value: &str) -> Result<Xdecimal, rust_decimal::Error> {
-        let res = Xdecimal {
+        Ok(Xdecimal {
             ir: Decimal::from_str(value)?,
-        };
-        return Ok(res);
+        })
+    }
+}
+
+impl fmt::Display for Xdecimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.ir, f)
+    }
+}
+
+impl Xdecimal {
+    /// num * 10^(-scale), `Xdecimal::new(12345, 2)` is 123.45, panics if scale > 28.
+    pub fn new(num: i64, scale: u32) -> Xdecimal {
+        Xdecimal {
+            ir: Decimal::new(num, scale),
+        }
     }
 
-    fn to_f64(&self) -> f64 {
-        return self.ir.to_f64().unwrap();
+    /// num * 10^(-scale) without i64 range limit, error if num overflows 96 bit or scale > 28.
+    pub fn from_i128_with_scale(num: i128, scale: u32) -> Result<Xdecimal, XdecimalErr> {
+        Ok(Xdecimal {
+            ir: Decimal::try_from_i128_with_scale(num, scale).map_err(|_| XdecimalErr::Overflow)?,
+        })
+    }
+
+    /// Count of digits after decimal point.
+    pub fn scale(&self) -> u32 {
+        self.ir.scale()
     }
 
-    fn to_string(&self) -> String {
-        return self.ir.to_string();
+    /// Integer part of the representation, self = mantissa * 10^(-scale).
+    pub fn mantissa(&self) -> i128 {
+        self.ir.mantissa()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.ir.is_sign_negative() && !self.ir.is_zero()
+    }
+
+    pub fn abs(self) -> Xdecimal {
+        Xdecimal { ir: self.ir.abs() }
+    }
+
+    /// Round to scale digits after decimal point.
+    pub fn round_dp(self, scale: u32, mode: RoundingMode) -> Xdecimal {
+        Xdecimal {
+            ir: self.ir.round_dp_with_strategy(scale, mode.strategy()),
+        }
+    }
+
+    /// Same value with exactly scale digits after decimal point, 1.5 rescaled to 3 is 1.500.
+    /// Rounds half away from zero when scale is reduced,
+    /// the scale is capped where the mantissa would overflow 96 bit.
+    pub fn rescale(self, scale: u32) -> Xdecimal {
+        let mut ir = self.ir;
+        ir.rescale(scale);
+        Xdecimal { ir }
+    }
+
+    pub fn checked_add(self, rhs: Xdecimal) -> Result<Xdecimal, XdecimalErr> {
+        Xdecimal::wrap(self.ir.checked_add(rhs.ir), XdecimalErr::Overflow)
+    }
+
+    pub fn checked_sub(self, rhs: Xdecimal) -> Result<Xdecimal, XdecimalErr> {
+        Xdecimal::wrap(self.ir.checked_sub(rhs.ir), XdecimalErr::Overflow)
+    }
+
+    pub fn checked_mul(self, rhs: Xdecimal) -> Result<Xdecimal, XdecimalErr> {
+        Xdecimal::wrap(self.ir.checked_mul(rhs.ir), XdecimalErr::Overflow)
+    }
+
+    pub fn checked_div(self, rhs: Xdecimal) -> Result<Xdecimal, XdecimalErr> {
+        if rhs.ir.is_zero() {
+            return Err(XdecimalErr::DivideByZero);
+        }
+        Xdecimal::wrap(self.ir.checked_div(rhs.ir), XdecimalErr::Overflow)
+    }
+
+    pub fn checked_rem(self, rhs: Xdecimal) -> Result<Xdecimal, XdecimalErr> {
+        if rhs.ir.is_zero() {
+            return Err(XdecimalErr::DivideByZero);
+        }
+        Xdecimal::wrap(self.ir.checked_rem(rhs.ir), XdecimalErr::Overflow)
+    }
+
+    /// Square root, error if self < 0.
+    pub fn sqrt(self) -> Result<Xdecimal, XdecimalErr> {
+        Xdecimal::wrap(self.ir.sqrt(), XdecimalErr::InvalidOperand)
+    }
+
+    /// $self^{exp}$, 0 to a negative power is an error.
+    pub fn powi(self, exp: i64) -> Result<Xdecimal, XdecimalErr> {
+        if self.ir.is_zero() && exp < 0 {
+            return Err(XdecimalErr::DivideByZero);
+        }
+        Xdecimal::wrap(self.ir.checked_powi(exp), XdecimalErr::Overflow)
+    }
+
+    /// $self^{exp}$ with decimal exponent, result is approximate unless exp is an integer.
+    pub fn pow(self, exp: Xdecimal) -> Result<Xdecimal, XdecimalErr> {
+        if exp.ir.fract().is_zero() {
+            if let Some(e) = exp.ir.to_i64() {
+                return self.powi(e);
+            }
+        }
+        if self.is_negative() {
+            return Err(XdecimalErr::InvalidOperand);
+        }
+        Xdecimal::wrap(self.ir.checked_powd(exp.ir), XdecimalErr::Overflow)
+    }
+
+    /// Natural logarithm, error if self <= 0.
+    pub fn ln(self) -> Result<Xdecimal, XdecimalErr> {
+        Xdecimal::wrap(self.ir.checked_ln(), XdecimalErr::InvalidOperand)
+    }
+
+    /// $e^{self}$.
+    pub fn exp(self) -> Result<Xdecimal, XdecimalErr> {
+        Xdecimal::wrap(self.ir.checked_exp(), XdecimalErr::Overflow)
+    }
+
+    fn wrap(ir: Option<Decimal>, err: XdecimalErr) -> Result<Xdecimal, XdecimalErr> {
+        ir.map(|ir| Xdecimal { ir }).ok_or(err)
     }
 }
 
@@ -117,12 +275,108 @@ impl Div for Xdecimal {
     }
 }
 
-impl PartialEq for Xdecimal {
-    fn eq(&self, other: &Xdecimal) -> bool {
-        self.ir == other.ir
+impl Rem for Xdecimal {
+    type Output = Xdecimal;
+    fn rem(self, other: Xdecimal) -> Xdecimal {
+        Xdecimal {
+            ir: self.ir % other.ir,
+        }
     }
-    fn ne(&self, other: &Xdecimal) -> bool {
-        self.ir != other.ir
+}
+
+impl Neg for Xdecimal {
+    type Output = Xdecimal;
+    fn neg(self) -> Xdecimal {
+        Xdecimal { ir: -self.ir }
+    }
+}
+
+impl XNum for Xdecimal {
+    fn num_type() -> String {
+        "Xdecimal".to_string()
+    }
+
+    fn n_0() -> Xdecimal {
+        Xdecimal {
+            ir: Decimal::zero(),
+        }
+    }
+
+    fn n_1() -> Xdecimal {
+        Xdecimal { ir: Decimal::one() }
+    }
+
+    fn n_u8(n: u8) -> Xdecimal {
+        Xdecimal {
+            ir: Decimal::from(n),
+        }
+    }
+
+    fn checked_add(self, rhs: Xdecimal) -> Option<Xdecimal> {
+        Xdecimal::checked_add(self, rhs).ok()
+    }
+
+    fn checked_sub(self, rhs: Xdecimal) -> Option<Xdecimal> {
+        Xdecimal::checked_sub(self, rhs).ok()
+    }
+
+    fn checked_mul(self, rhs: Xdecimal) -> Option<Xdecimal> {
+        Xdecimal::checked_mul(self, rhs).ok()
+    }
+
+    fn checked_div(self, rhs: Xdecimal) -> Option<Xdecimal> {
+        Xdecimal::checked_div(self, rhs).ok()
+    }
+
+    fn to_f64(self) -> f64 {
+        self.ir.to_f64().unwrap()
+    }
+
+    fn from_f64(n: f64) -> Option<Xdecimal> {
+        Some(Xdecimal {
+            ir: Decimal::from_f64(n)?,
+        })
+    }
+}
+
+/// Panics where the `Result` returning inherent methods return an error, such as sqrt of negative number.
+impl XFloat for Xdecimal {
+    fn abs(self) -> Xdecimal {
+        Xdecimal::abs(self)
+    }
+
+    fn sqrt(self) -> Xdecimal {
+        Xdecimal::sqrt(self).unwrap()
+    }
+
+    fn ln(self) -> Xdecimal {
+        Xdecimal::ln(self).unwrap()
+    }
+
+    fn exp(self) -> Xdecimal {
+        Xdecimal::exp(self).unwrap()
+    }
+
+    fn powi(self, n: i32) -> Xdecimal {
+        Xdecimal::powi(self, n as i64).unwrap()
+    }
+
+    fn powf(self, n: Xdecimal) -> Xdecimal {
+        Xdecimal::pow(self, n).unwrap()
+    }
+
+    fn floor(self) -> Xdecimal {
+        Xdecimal {
+            ir: self.ir.floor(),
+        }
+    }
+
+    fn ceil(self) -> Xdecimal {
+        Xdecimal { ir: self.ir.ceil() }
+    }
+
+    fn round(self) -> Xdecimal {
+        self.round_dp(0, RoundingMode::HalfUp)
     }
 }
 
@@ -153,4 +407,119 @@ mod tests {
         let sum = left + right;
         assert_eq!(sum.to_string(), "3.57");
     }
+
+    #[test]
+    fn xdecimal_xnum_test() {
+        let v: Vec<Xdecimal> = ["0.1", "0.2", "0.3"]
+            .iter()
+            .map(|s| Xdecimal::try_from(*s).unwrap())
+            .collect();
+        let sum = v.iter().fold(Xdecimal::n_0(), |acc, x| acc + *x);
+        assert_eq!(sum.to_string(), "0.6");
+        assert!(v[0] < v[1]);
+        assert_eq!(XNum::checked_div(Xdecimal::n_u8(3), Xdecimal::n_0()), None);
+        assert_eq!(Xdecimal::from_f64(f64::NAN), None);
+        assert_eq!(Xdecimal::num_type(), "Xdecimal");
+    }
+
+    #[test]
+    fn xdecimal_ord_neg_rem_test() {
+        let a = Xdecimal::new(-125, 2);
+        let b = Xdecimal::from_str("1.00").unwrap();
+        assert!(a < b);
+        assert_eq!(a.max(b), b);
+        assert_eq!((-a).to_string(), "1.25");
+        assert_eq!(a.abs(), -a);
+        assert_eq!(
+            (Xdecimal::new(75, 1) % Xdecimal::new(2, 0)).to_string(),
+            "1.5"
+        );
+        assert_eq!(b, Xdecimal::new(1, 0)); // scale does not matter
+        assert!(a.is_negative() && !(-Xdecimal::n_0()).is_negative());
+    }
+
+    #[test]
+    fn xdecimal_checked_test() {
+        let max = Xdecimal::from_str("79228162514264337593543950335").unwrap();
+        let one = Xdecimal::n_1();
+        assert_eq!(max.checked_add(one), Err(XdecimalErr::Overflow));
+        assert_eq!((-max).checked_sub(one), Err(XdecimalErr::Overflow));
+        assert_eq!(
+            max.checked_mul(Xdecimal::new(2, 0)),
+            Err(XdecimalErr::Overflow)
+        );
+        assert_eq!(
+            one.checked_div(Xdecimal::n_0()),
+            Err(XdecimalErr::DivideByZero)
+        );
+        assert_eq!(
+            one.checked_rem(Xdecimal::n_0()),
+            Err(XdecimalErr::DivideByZero)
+        );
+        assert_eq!(
+            one.checked_div(Xdecimal::new(3, 0)).unwrap().to_string(),
+            "0.3333333333333333333333333333"
+        );
+        assert_eq!(XNum::checked_add(max, one), None);
+    }
+
+    #[test]
+    fn xdecimal_round_dp_test() {
+        let cases = [
+            ("2.345", ["2.34", "2.35", "2.34", "2.35", "2.34"]),
+            ("2.355", ["2.36", "2.36", "2.35", "2.36", "2.35"]),
+            ("-2.345", ["-2.34", "-2.35", "-2.35", "-2.34", "-2.34"]),
+            ("2.341", ["2.34", "2.34", "2.34", "2.35", "2.34"]),
+        ];
+        let modes = [
+            RoundingMode::HalfEven,
+            RoundingMode::HalfUp,
+            RoundingMode::Floor,
+            RoundingMode::Ceil,
+            RoundingMode::Truncate,
+        ];
+        for (input, expected) in cases.iter() {
+            let x = Xdecimal::from_str(input).unwrap();
+            for (mode, want) in modes.iter().zip(expected.iter()) {
+                assert_eq!(
+                    x.round_dp(2, *mode).to_string(),
+                    *want,
+                    "{} {:?}",
+                    input,
+                    mode
+                );
+            }
+        }
+        let x = Xdecimal::from_str("12.300").unwrap();
+        assert_eq!(x.rescale(2).to_string(), "12.30");
+        assert_eq!(x.rescale(4).to_string(), "12.3000");
+        assert_eq!(x.rescale(2), x);
+    }
+
+    #[test]
+    fn xdecimal_maths_test() {
+        let two = Xdecimal::new(2, 0);
+        let close = |x: Xdecimal, want: f64| (x.to_f64() - want).abs() < 1e-12;
+        assert!(close(two.sqrt().unwrap(), std::f64::consts::SQRT_2));
+        assert_eq!(
+            Xdecimal::new(-1, 0).sqrt(),
+            Err(XdecimalErr::InvalidOperand)
+        );
+        assert_eq!(two.powi(10).unwrap(), Xdecimal::new(1024, 0));
+        assert_eq!(two.powi(-2).unwrap(), Xdecimal::new(25, 2));
+        assert_eq!(Xdecimal::n_0().powi(-1), Err(XdecimalErr::DivideByZero));
+        assert_eq!(
+            Xdecimal::new(-2, 0).pow(Xdecimal::new(3, 0)).unwrap(),
+            Xdecimal::new(-8, 0)
+        );
+        assert!(close(
+            two.pow(Xdecimal::new(5, 1)).unwrap(),
+            std::f64::consts::SQRT_2
+        ));
+        assert!(close(Xdecimal::n_1().exp().unwrap(), std::f64::consts::E));
+        assert!(close(two.ln().unwrap(), std::f64::consts::LN_2));
+        assert_eq!(Xdecimal::n_0().ln(), Err(XdecimalErr::InvalidOperand));
+        assert_eq!(Xdecimal::new(100, 0).exp(), Err(XdecimalErr::Overflow));
+        assert!(close(XFloat::sqrt(Xdecimal::new(9, 0)), 3.0));
+    }
 }
@@ -1,4 +1,6 @@
+use crate::number::hex_simd;
 use core::fmt;
+use std::io::{self, Read, Write};
 
 const HEX_TABLE_LOWER: &[u8] = b"0123456789abcdef";
 const HEX_TABLE_UPPER: &[u8] = b"0123456789ABCDEF";
@@ -28,6 +30,40 @@ pub trait HexDec {
     /// Decode hex string to hex number
     /// Example: decode str "1819" to u8 slice [0x18, 0x19]
     fn u_hex_decode(&self) -> Result<Vec<u8>, HexErr>;
+
+    /// Decode hex string which may have `0x` prefixes, whitespace and `:` or `-` separators between bytes.
+    /// Example: decode str "0x18:19 1a" to u8 slice [0x18, 0x19, 0x1a]
+    fn u_hex_decode_lenient(&self) -> Result<Vec<u8>, HexErr>;
+
+    /// Decode hex string to fixed size array without allocation, string length must be N * 2.
+    /// Example: decode str "1819" to [0x18, 0x19] of type [u8; 2]
+    fn u_hex_decode_array<const N: usize>(&self) -> Result<[u8; N], HexErr>;
+}
+
+/**
+Hex codec of unsigned integers.
+
+Fixed width codecs use exactly 2 digits per byte, big-endian "1234" and little-endian "3412" are both 0x1234u16.
+Minimal codecs have no leading zeros, like `format!("{:x}", n)`.
+*/
+pub trait HexInt: Sized {
+    /// Decode big-endian hex string of exactly size_of::<Self>() * 2 digits.
+    fn from_hex_be(src: &[u8]) -> Result<Self, HexErr>;
+
+    /// Decode little-endian hex string of exactly size_of::<Self>() * 2 digits.
+    fn from_hex_le(src: &[u8]) -> Result<Self, HexErr>;
+
+    /// Decode big-endian hex string of 1 to size_of::<Self>() * 2 digits, leading zeros are optional.
+    fn from_hex_minimal(src: &[u8]) -> Result<Self, HexErr>;
+
+    /// Encode to big-endian hex string of size_of::<Self>() * 2 digits.
+    fn to_hex_be(&self, upper: bool) -> String;
+
+    /// Encode to little-endian hex string of size_of::<Self>() * 2 digits.
+    fn to_hex_le(&self, upper: bool) -> String;
+
+    /// Encode to big-endian hex string without leading zeros, 0 is "0".
+    fn to_hex_minimal(&self, upper: bool) -> String;
 }
 
 impl std::error::Error for HexErr {}
@@ -47,8 +83,8 @@ impl fmt::Display for HexErr {
 impl HexEnc for &[u8] {
     fn u_hex_encode(&self, upper: bool) -> String {
         let mut dst = vec![0; self.len() * 2];
-        _hex_encode(self.as_ref(), &mut dst, upper);
-        return String::from_utf8(dst).unwrap();
+        _hex_encode(self, &mut dst, upper);
+        String::from_utf8(dst).unwrap()
     }
 }
 
@@ -56,12 +92,65 @@ impl HexDec for str {
     fn u_hex_decode(&self) -> Result<Vec<u8>, HexErr> {
         let mut dst = vec![0; self.len() / 2];
         _hex_decode(self.as_ref(), &mut dst)?;
-        return Ok(dst.to_vec());
+        Ok(dst)
     }
+
+    fn u_hex_decode_lenient(&self) -> Result<Vec<u8>, HexErr> {
+        hex_decode_lenient(self)
+    }
+
+    fn u_hex_decode_array<const N: usize>(&self) -> Result<[u8; N], HexErr> {
+        hex_decode_array(self.as_bytes())
+    }
+}
+
+macro_rules! impl_hex_int {
+    ($($t:ty),*) => {$(
+        impl HexInt for $t {
+            fn from_hex_be(src: &[u8]) -> Result<$t, HexErr> {
+                Ok(<$t>::from_be_bytes(hex_decode_array(src)?))
+            }
+
+            fn from_hex_le(src: &[u8]) -> Result<$t, HexErr> {
+                Ok(<$t>::from_le_bytes(hex_decode_array(src)?))
+            }
+
+            fn from_hex_minimal(src: &[u8]) -> Result<$t, HexErr> {
+                if src.is_empty() || src.len() > std::mem::size_of::<$t>() * 2 {
+                    return Err(HexErr::InvalidStrLen);
+                }
+                src.iter().enumerate().try_fold(0 as $t, |acc, (i, c)| {
+                    Ok(acc << 4 | _hex_char_to_u8(*c, i)? as $t)
+                })
+            }
+
+            fn to_hex_be(&self, upper: bool) -> String {
+                self.to_be_bytes().as_ref().u_hex_encode(upper)
+            }
+
+            fn to_hex_le(&self, upper: bool) -> String {
+                self.to_le_bytes().as_ref().u_hex_encode(upper)
+            }
+
+            fn to_hex_minimal(&self, upper: bool) -> String {
+                let s = self.to_hex_be(upper);
+                let zeros = s.bytes().take_while(|c| *c == b'0').count().min(s.len() - 1);
+                s[zeros..].to_string()
+            }
+        }
+    )*};
 }
 
+impl_hex_int!(u8, u16, u32, u64, u128);
+
 #[inline]
 pub fn _hex_encode(src: &[u8], dst: &mut [u8], upper: bool) {
+    let n = hex_simd::hex_encode_prefix(src, dst, upper);
+    _hex_encode_scalar(&src[n..], &mut dst[2 * n..], upper);
+}
+
+/// Encode byte by byte, also the reference of vectorized encoders.
+pub(crate) fn _hex_encode_scalar(src: &[u8], dst: &mut [u8], upper: bool) {
     let mut hex_table = HEX_TABLE_LOWER;
     if upper {
         hex_table = HEX_TABLE_UPPER
@@ -89,21 +178,345 @@ fn _hex_char_to_u8(c: u8, idx: usize) -> Result<u8, HexErr> {
 
 #[inline]
 pub fn _hex_decode(src: &[u8], dst: &mut [u8]) -> Result<(), HexErr> {
-    if src.len() % 2 != 0 {
+    if !src.len().is_multiple_of(2) {
         return Err(HexErr::OddLen);
     }
     if src.len() / 2 != dst.len() {
         return Err(HexErr::InvalidStrLen);
     }
 
-    for i in 0..dst.len() {
-        dst[i] =
-            _hex_char_to_u8(src[2 * i], 2 * i)? << 4 | _hex_char_to_u8(src[2 * i + 1], 2 * i + 1)?;
+    // vectorized decoders stop before the first block with invalid chars, which is reported by scalar one
+    let n = hex_simd::hex_decode_prefix(src, dst);
+    _hex_decode_scalar(src, dst, n)
+}
+
+/// Decode byte by byte from dst[start], also the reference of vectorized decoders.
+pub(crate) fn _hex_decode_scalar(src: &[u8], dst: &mut [u8], start: usize) -> Result<(), HexErr> {
+    for (i, d) in dst.iter_mut().enumerate().skip(start) {
+        *d = _hex_char_to_u8(src[2 * i], 2 * i)? << 4 | _hex_char_to_u8(src[2 * i + 1], 2 * i + 1)?;
     }
 
     Ok(())
 }
 
+/// Encode src into caller provided dst without allocation, dst length must be src length * 2.
+pub fn hex_encode_to_slice(src: &[u8], dst: &mut [u8], upper: bool) -> Result<(), HexErr> {
+    if dst.len() != src.len() * 2 {
+        return Err(HexErr::InvalidStrLen);
+    }
+    _hex_encode(src, dst, upper);
+    Ok(())
+}
+
+/// Decode hex string src into caller provided dst without allocation, dst length must be src length / 2.
+pub fn hex_decode_to_slice(src: &[u8], dst: &mut [u8]) -> Result<(), HexErr> {
+    _hex_decode(src, dst)
+}
+
+/// Decode hex string into fixed size array without allocation, src length must be N * 2.
+pub fn hex_decode_array<const N: usize>(src: &[u8]) -> Result<[u8; N], HexErr> {
+    let mut dst = [0u8; N];
+    _hex_decode(src, &mut dst)?;
+    Ok(dst)
+}
+
+#[inline]
+fn _is_hex_separator(c: u8) -> bool {
+    c.is_ascii_whitespace() || c == b':' || c == b'-'
+}
+
+/**
+Decode hex string which may have `0x` or `0X` prefixes, whitespace and `:` or `-` separators between bytes,
+such as "0x1819", "18:19", "18-19", "0x18 0x19" and multiple lines.
+A separator between the two digits of one byte is an error.
+*/
+pub fn hex_decode_lenient(src: &str) -> Result<Vec<u8>, HexErr> {
+    let s = src.as_bytes();
+    let mut res = Vec::with_capacity(s.len() / 2);
+    let mut token_start = true;
+    let mut i = 0;
+    while i < s.len() {
+        let c = s[i];
+        if _is_hex_separator(c) {
+            token_start = true;
+            i += 1;
+            continue;
+        }
+        if token_start && c == b'0' && i + 1 < s.len() && (s[i + 1] == b'x' || s[i + 1] == b'X') {
+            token_start = false;
+            i += 2;
+            continue;
+        }
+        token_start = false;
+        if i + 1 >= s.len() || _is_hex_separator(s[i + 1]) {
+            return Err(HexErr::OddLen);
+        }
+        res.push(_hex_char_to_u8(c, i)? << 4 | _hex_char_to_u8(s[i + 1], i + 1)?);
+        i += 2;
+    }
+    Ok(res)
+}
+
+/**
+Hexdump formatter like `hexdump -C`, every line has offset, 16 bytes in hex and their printable ASCII chars.
+
+```text
+00000000  48 65 6c 6c 6f 2c 20 68  65 78 64 75 6d 70 21 0a  |Hello, hexdump!.|
+00000010
+```
+*/
+pub struct HexDump<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> HexDump<'a> {
+    pub fn new(data: &'a [u8]) -> HexDump<'a> {
+        HexDump { data, offset: 0 }
+    }
+
+    /// Offset of first byte, useful when dumping part of a large file.
+    pub fn with_offset(mut self, offset: usize) -> HexDump<'a> {
+        self.offset = offset;
+        self
+    }
+}
+
+impl<'a> fmt::Display for HexDump<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (n, line) in self.data.chunks(16).enumerate() {
+            write!(f, "{:08x} ", self.offset + n * 16)?;
+            for i in 0..16 {
+                if i == 8 {
+                    write!(f, " ")?;
+                }
+                match line.get(i) {
+                    Some(b) => write!(f, " {:02x}", b)?,
+                    None => write!(f, "   ")?,
+                }
+            }
+            write!(f, "  |")?;
+            for b in line.iter() {
+                let c = if b.is_ascii_graphic() || *b == b' ' {
+                    *b as char
+                } else {
+                    '.'
+                };
+                write!(f, "{}", c)?;
+            }
+            writeln!(f, "|")?;
+        }
+        writeln!(f, "{:08x}", self.offset + self.data.len())
+    }
+}
+
+/// Hexdump data to string, see `HexDump`.
+pub fn hexdump(data: &[u8]) -> String {
+    HexDump::new(data).to_string()
+}
+
+const HEX_STREAM_CHUNK: usize = 4096;
+
+fn _invalid_data(e: HexErr) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e)
+}
+
+/// Writer adapter which encodes bytes written into hex string and writes it to inner writer.
+pub struct HexEncodeWriter<W: Write> {
+    inner: W,
+    upper: bool,
+}
+
+impl<W: Write> HexEncodeWriter<W> {
+    pub fn new(inner: W, upper: bool) -> HexEncodeWriter<W> {
+        HexEncodeWriter { inner, upper }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for HexEncodeWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut dst = [0u8; HEX_STREAM_CHUNK];
+        let src = &buf[..buf.len().min(HEX_STREAM_CHUNK / 2)];
+        _hex_encode(src, &mut dst, self.upper);
+        self.inner.write_all(&dst[..src.len() * 2])?;
+        Ok(src.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/**
+Writer adapter which decodes hex string written into bytes and writes them to inner writer.
+ASCII whitespace such as line breaks is skipped, call `finish` after the last write to check no half byte is left.
+*/
+pub struct HexDecodeWriter<W: Write> {
+    inner: W,
+    half: Option<u8>,
+    index: usize,
+}
+
+impl<W: Write> HexDecodeWriter<W> {
+    pub fn new(inner: W) -> HexDecodeWriter<W> {
+        HexDecodeWriter {
+            inner,
+            half: None,
+            index: 0,
+        }
+    }
+
+    /// Flush and return inner writer, error if count of hex digits written is odd.
+    pub fn finish(mut self) -> io::Result<W> {
+        if self.half.is_some() {
+            return Err(_invalid_data(HexErr::OddLen));
+        }
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+impl<W: Write> Write for HexDecodeWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut dst = Vec::with_capacity(buf.len() / 2 + 1);
+        for c in buf.iter() {
+            let index = self.index;
+            self.index += 1;
+            if c.is_ascii_whitespace() {
+                continue;
+            }
+            let v = _hex_char_to_u8(*c, index).map_err(_invalid_data)?;
+            match self.half.take() {
+                Some(high) => dst.push(high << 4 | v),
+                None => self.half = Some(v),
+            }
+        }
+        self.inner.write_all(&dst)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Reader adapter which reads bytes from inner reader and returns them as hex string.
+pub struct HexEncodeReader<R: Read> {
+    inner: R,
+    upper: bool,
+    // low digit of last byte which did not fit caller buffer
+    pending: Option<u8>,
+}
+
+impl<R: Read> HexEncodeReader<R> {
+    pub fn new(inner: R, upper: bool) -> HexEncodeReader<R> {
+        HexEncodeReader {
+            inner,
+            upper,
+            pending: None,
+        }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> Read for HexEncodeReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        if let Some(c) = self.pending.take() {
+            buf[0] = c;
+            return Ok(1);
+        }
+
+        let mut src = [0u8; HEX_STREAM_CHUNK / 2];
+        let want = (buf.len() / 2).clamp(1, src.len());
+        let n = self.inner.read(&mut src[..want])?;
+        if n == 0 {
+            return Ok(0);
+        }
+        let mut dst = [0u8; HEX_STREAM_CHUNK];
+        _hex_encode(&src[..n], &mut dst, self.upper);
+        if buf.len() == 1 {
+            buf[0] = dst[0];
+            self.pending = Some(dst[1]);
+            return Ok(1);
+        }
+        buf[..n * 2].copy_from_slice(&dst[..n * 2]);
+        Ok(n * 2)
+    }
+}
+
+/// Reader adapter which reads hex string from inner reader and returns decoded bytes, ASCII whitespace is skipped.
+pub struct HexDecodeReader<R: Read> {
+    inner: R,
+    half: Option<u8>,
+    index: usize,
+}
+
+impl<R: Read> HexDecodeReader<R> {
+    pub fn new(inner: R) -> HexDecodeReader<R> {
+        HexDecodeReader {
+            inner,
+            half: None,
+            index: 0,
+        }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> Read for HexDecodeReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        let mut src = [0u8; HEX_STREAM_CHUNK];
+        let want = (buf.len() * 2).min(src.len());
+        loop {
+            // count of digits read never exceeds buf.len() * 2, so decoded bytes always fit buf
+            let need = want - self.half.is_some() as usize;
+            let n = self.inner.read(&mut src[..need])?;
+            if n == 0 {
+                if self.half.is_some() {
+                    return Err(_invalid_data(HexErr::OddLen));
+                }
+                return Ok(0);
+            }
+
+            let mut count = 0;
+            for c in src[..n].iter() {
+                let index = self.index;
+                self.index += 1;
+                if c.is_ascii_whitespace() {
+                    continue;
+                }
+                let v = _hex_char_to_u8(*c, index).map_err(_invalid_data)?;
+                match self.half.take() {
+                    Some(high) => {
+                        buf[count] = high << 4 | v;
+                        count += 1;
+                    }
+                    None => self.half = Some(v),
+                }
+            }
+            // 0 bytes means EOF to caller, read again if only whitespace or a half byte was read
+            if count > 0 {
+                return Ok(count);
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -121,4 +534,165 @@ mod tests {
         let dst = src.u_hex_decode();
         assert_eq!(dst, Ok(vec![0x18, 0x19]));
     }
+
+    #[test]
+    fn hex_slice_test() {
+        let src = [0xde, 0xad, 0xbe, 0xef];
+        let mut dst = [0u8; 8];
+        assert_eq!(hex_encode_to_slice(&src, &mut dst, false), Ok(()));
+        assert_eq!(&dst, b"deadbeef");
+        assert_eq!(
+            hex_encode_to_slice(&src, &mut dst[..7], false),
+            Err(HexErr::InvalidStrLen)
+        );
+
+        let mut out = [0u8; 4];
+        assert_eq!(hex_decode_to_slice(b"DEADbeef", &mut out), Ok(()));
+        assert_eq!(out, src);
+        assert_eq!(
+            hex_decode_to_slice(b"DEADbeeg", &mut out),
+            Err(HexErr::InvalidHexChar { c: 'g', index: 7 })
+        );
+    }
+
+    #[test]
+    fn hex_decode_lenient_test() {
+        let want = Ok(vec![0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(hex_decode_lenient("0xdeadbeef"), want);
+        assert_eq!(hex_decode_lenient("DE:AD:BE:EF"), want);
+        assert_eq!(hex_decode_lenient("de-ad-be-ef"), want);
+        assert_eq!(hex_decode_lenient("0xde 0xad\n0Xbe\t0xef "), want);
+        assert_eq!("  dead beef\r\n".u_hex_decode_lenient(), want);
+        assert_eq!(hex_decode_lenient(""), Ok(vec![]));
+        assert_eq!(hex_decode_lenient("00"), Ok(vec![0]));
+
+        assert_eq!(hex_decode_lenient("dea"), Err(HexErr::OddLen));
+        assert_eq!(hex_decode_lenient("d e"), Err(HexErr::OddLen));
+        assert_eq!(
+            hex_decode_lenient("de:0y"),
+            Err(HexErr::InvalidHexChar { c: 'y', index: 4 })
+        );
+    }
+
+    #[test]
+    fn hexdump_test() {
+        let dump = hexdump(b"Hello, hexdump!\n\x00\x01\xff");
+        assert_eq!(
+            dump,
+            "00000000  48 65 6c 6c 6f 2c 20 68  65 78 64 75 6d 70 21 0a  |Hello, hexdump!.|\n\
+             00000010  00 01 ff                                          |...|\n\
+             00000013\n"
+        );
+        assert_eq!(hexdump(b""), "00000000\n");
+        assert!(HexDump::new(b"a")
+            .with_offset(0x100)
+            .to_string()
+            .starts_with("00000100  61 "));
+    }
+
+    #[test]
+    fn hex_writer_test() {
+        let data: Vec<u8> = (0..10000u32).map(|i| (i * 7) as u8).collect();
+        let mut enc = HexEncodeWriter::new(Vec::new(), false);
+        for chunk in data.chunks(333) {
+            enc.write_all(chunk).unwrap();
+        }
+        let text = enc.into_inner();
+        assert_eq!(text, data.as_slice().u_hex_encode(false).into_bytes());
+
+        // split in the middle of bytes, with line breaks
+        let mut dec = HexDecodeWriter::new(Vec::new());
+        for chunk in text.chunks(77) {
+            dec.write_all(chunk).unwrap();
+            dec.write_all(b"\n").unwrap();
+        }
+        assert_eq!(dec.finish().unwrap(), data);
+
+        let mut dec = HexDecodeWriter::new(Vec::new());
+        dec.write_all(b"abc").unwrap();
+        assert!(dec.finish().is_err());
+        let mut dec = HexDecodeWriter::new(Vec::new());
+        assert!(dec.write_all(b"zz").is_err());
+    }
+
+    #[test]
+    fn hex_reader_test() {
+        let data: Vec<u8> = (0..10000u32).map(|i| (i * 13) as u8).collect();
+        let mut text = String::new();
+        HexEncodeReader::new(data.as_slice(), true)
+            .read_to_string(&mut text)
+            .unwrap();
+        assert_eq!(text, data.as_slice().u_hex_encode(true));
+
+        // 1 byte buffer splits every hex pair
+        let mut enc = HexEncodeReader::new(&data[..3], false);
+        let mut one = [0u8; 1];
+        let mut chars = vec![];
+        while enc.read(&mut one).unwrap() == 1 {
+            chars.push(one[0]);
+        }
+        assert_eq!(chars, b"000d1a");
+
+        let lines: String = text
+            .as_bytes()
+            .chunks(61)
+            .map(|l| String::from_utf8(l.to_vec()).unwrap() + "\n")
+            .collect();
+        let mut decoded = vec![];
+        HexDecodeReader::new(lines.as_bytes())
+            .read_to_end(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, data);
+
+        let mut out = vec![];
+        assert!(HexDecodeReader::new(&b"abc"[..])
+            .read_to_end(&mut out)
+            .is_err());
+        assert!(HexDecodeReader::new(&b"0g"[..])
+            .read_to_end(&mut out)
+            .is_err());
+    }
+
+    #[test]
+    fn hex_decode_array_test() {
+        let hash: [u8; 4] = "deadBEEF".u_hex_decode_array().unwrap();
+        assert_eq!(hash, [0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(
+            "deadbe".u_hex_decode_array::<4>(),
+            Err(HexErr::InvalidStrLen)
+        );
+        assert_eq!("dea".u_hex_decode_array::<2>(), Err(HexErr::OddLen));
+        assert_eq!(hex_decode_array::<0>(b""), Ok([]));
+    }
+
+    #[test]
+    fn hex_int_test() {
+        assert_eq!(u16::from_hex_be(b"1234"), Ok(0x1234));
+        assert_eq!(u16::from_hex_le(b"3412"), Ok(0x1234));
+        assert_eq!(u32::from_hex_be(b"1234"), Err(HexErr::InvalidStrLen));
+        assert_eq!(
+            u128::from_hex_be(b"0123456789abcdef0123456789ABCDEF"),
+            Ok(0x0123456789abcdef0123456789abcdef)
+        );
+        assert_eq!(u64::from_hex_minimal(b"abc"), Ok(0xabc));
+        assert_eq!(u8::from_hex_minimal(b"0ff"), Err(HexErr::InvalidStrLen));
+        assert_eq!(u8::from_hex_minimal(b""), Err(HexErr::InvalidStrLen));
+        assert_eq!(
+            u8::from_hex_minimal(b"x"),
+            Err(HexErr::InvalidHexChar { c: 'x', index: 0 })
+        );
+
+        assert_eq!(0x1234u16.to_hex_be(false), "1234");
+        assert_eq!(0x1234u32.to_hex_le(true), "34120000");
+        assert_eq!(0xabcu64.to_hex_minimal(true), "ABC");
+        assert_eq!(0u32.to_hex_minimal(false), "0");
+        for n in [0u64, 1, 0xf0, 0xfff, u64::MAX].iter() {
+            assert_eq!(n.to_hex_minimal(false), format!("{:x}", n));
+            assert_eq!(
+                u64::from_hex_minimal(n.to_hex_minimal(false).as_bytes()),
+                Ok(*n)
+            );
+            assert_eq!(u64::from_hex_le(n.to_hex_le(false).as_bytes()), Ok(*n));
+        }
+    }
 }
@@ -0,0 +1,283 @@
+/*!
+Vectorized hex codecs for x86_64, AVX2 is detected at runtime and SSE2 is always available there.
+
+Both codecs only handle a prefix of whole blocks and return its length in bytes,
+the caller finishes the tail, and any block with invalid chars, with the scalar codec.
+On other architectures the prefix is always empty.
+*/
+
+/// Encode a prefix of src into dst, returns count of src bytes encoded.
+pub(crate) fn hex_encode_prefix(src: &[u8], dst: &mut [u8], upper: bool) -> usize {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") {
+            return unsafe { x86::encode_avx2(src, dst, upper) };
+        }
+        unsafe { x86::encode_sse2(src, dst, upper) }
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        let _ = (src, dst, upper);
+        0
+    }
+}
+
+/// Decode a prefix of src into dst, returns count of dst bytes decoded.
+pub(crate) fn hex_decode_prefix(src: &[u8], dst: &mut [u8]) -> usize {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") {
+            return unsafe { x86::decode_avx2(src, dst) };
+        }
+        unsafe { x86::decode_sse2(src, dst) }
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        let _ = (src, dst);
+        0
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+    use std::arch::x86_64::*;
+
+    /// Offset from `'0' + 10` to `'a'` or `'A'`.
+    #[inline]
+    fn alpha_offset(upper: bool) -> i8 {
+        if upper {
+            (b'A' - b'0' - 10) as i8
+        } else {
+            (b'a' - b'0' - 10) as i8
+        }
+    }
+
+    /// Nibbles 0..16 to ascii: n + '0', plus alpha offset if n > 9.
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn nibble_to_ascii_sse2(n: __m128i, offset: __m128i) -> __m128i {
+        let alpha = _mm_and_si128(_mm_cmpgt_epi8(n, _mm_set1_epi8(9)), offset);
+        _mm_add_epi8(_mm_add_epi8(n, _mm_set1_epi8(b'0' as i8)), alpha)
+    }
+
+    #[target_feature(enable = "sse2")]
+    pub(super) unsafe fn encode_sse2(src: &[u8], dst: &mut [u8], upper: bool) -> usize {
+        let blocks = src.len().min(dst.len() / 2) / 16;
+        let mask = _mm_set1_epi8(0x0f);
+        let offset = _mm_set1_epi8(alpha_offset(upper));
+        for i in 0..blocks {
+            let v = _mm_loadu_si128(src.as_ptr().add(i * 16) as *const __m128i);
+            let hi = nibble_to_ascii_sse2(_mm_and_si128(_mm_srli_epi16(v, 4), mask), offset);
+            let lo = nibble_to_ascii_sse2(_mm_and_si128(v, mask), offset);
+            let out = dst.as_mut_ptr().add(i * 32) as *mut __m128i;
+            _mm_storeu_si128(out, _mm_unpacklo_epi8(hi, lo));
+            _mm_storeu_si128(out.add(1), _mm_unpackhi_epi8(hi, lo));
+        }
+        blocks * 16
+    }
+
+    /// Ascii to nibbles, the mask has all bits set in lanes of valid hex chars.
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn ascii_to_nibble_sse2(v: __m128i) -> (__m128i, __m128i) {
+        // x <= k unsigned, iff max(x, k) == k
+        let le = |x: __m128i, k: u8| {
+            let k = _mm_set1_epi8(k as i8);
+            _mm_cmpeq_epi8(_mm_max_epu8(x, k), k)
+        };
+        let digit = _mm_sub_epi8(v, _mm_set1_epi8(b'0' as i8));
+        let alpha = _mm_sub_epi8(
+            _mm_or_si128(v, _mm_set1_epi8(0x20)),
+            _mm_set1_epi8(b'a' as i8),
+        );
+        let is_digit = le(digit, 9);
+        let is_alpha = le(alpha, 5);
+        let alpha = _mm_add_epi8(alpha, _mm_set1_epi8(10));
+        let n = _mm_or_si128(
+            _mm_and_si128(is_digit, digit),
+            _mm_and_si128(is_alpha, alpha),
+        );
+        (n, _mm_or_si128(is_digit, is_alpha))
+    }
+
+    /// Pairs of nibbles in 16 bit lanes to bytes in the low half of each lane.
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn pack_nibbles_sse2(n: __m128i) -> __m128i {
+        let hi = _mm_and_si128(n, _mm_set1_epi16(0xff));
+        _mm_or_si128(_mm_slli_epi16(hi, 4), _mm_srli_epi16(n, 8))
+    }
+
+    #[target_feature(enable = "sse2")]
+    pub(super) unsafe fn decode_sse2(src: &[u8], dst: &mut [u8]) -> usize {
+        let blocks = dst.len().min(src.len() / 2) / 16;
+        for i in 0..blocks {
+            let p = src.as_ptr().add(i * 32) as *const __m128i;
+            let (n0, ok0) = ascii_to_nibble_sse2(_mm_loadu_si128(p));
+            let (n1, ok1) = ascii_to_nibble_sse2(_mm_loadu_si128(p.add(1)));
+            if _mm_movemask_epi8(_mm_and_si128(ok0, ok1)) != 0xffff {
+                return i * 16;
+            }
+            let bytes = _mm_packus_epi16(pack_nibbles_sse2(n0), pack_nibbles_sse2(n1));
+            _mm_storeu_si128(dst.as_mut_ptr().add(i * 16) as *mut __m128i, bytes);
+        }
+        blocks * 16
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn nibble_to_ascii_avx2(n: __m256i, offset: __m256i) -> __m256i {
+        let alpha = _mm256_and_si256(_mm256_cmpgt_epi8(n, _mm256_set1_epi8(9)), offset);
+        _mm256_add_epi8(_mm256_add_epi8(n, _mm256_set1_epi8(b'0' as i8)), alpha)
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn encode_avx2(src: &[u8], dst: &mut [u8], upper: bool) -> usize {
+        let blocks = src.len().min(dst.len() / 2) / 32;
+        let mask = _mm256_set1_epi8(0x0f);
+        let offset = _mm256_set1_epi8(alpha_offset(upper));
+        for i in 0..blocks {
+            let v = _mm256_loadu_si256(src.as_ptr().add(i * 32) as *const __m256i);
+            let hi = nibble_to_ascii_avx2(_mm256_and_si256(_mm256_srli_epi16(v, 4), mask), offset);
+            let lo = nibble_to_ascii_avx2(_mm256_and_si256(v, mask), offset);
+            // unpack works in 128 bit lanes: bytes 0..8 and 16..24, bytes 8..16 and 24..32
+            let a = _mm256_unpacklo_epi8(hi, lo);
+            let b = _mm256_unpackhi_epi8(hi, lo);
+            let out = dst.as_mut_ptr().add(i * 64) as *mut __m256i;
+            _mm256_storeu_si256(out, _mm256_permute2x128_si256(a, b, 0x20));
+            _mm256_storeu_si256(out.add(1), _mm256_permute2x128_si256(a, b, 0x31));
+        }
+        blocks * 32
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn ascii_to_nibble_avx2(v: __m256i) -> (__m256i, __m256i) {
+        let le = |x: __m256i, k: u8| {
+            let k = _mm256_set1_epi8(k as i8);
+            _mm256_cmpeq_epi8(_mm256_max_epu8(x, k), k)
+        };
+        let digit = _mm256_sub_epi8(v, _mm256_set1_epi8(b'0' as i8));
+        let alpha = _mm256_sub_epi8(
+            _mm256_or_si256(v, _mm256_set1_epi8(0x20)),
+            _mm256_set1_epi8(b'a' as i8),
+        );
+        let is_digit = le(digit, 9);
+        let is_alpha = le(alpha, 5);
+        let alpha = _mm256_add_epi8(alpha, _mm256_set1_epi8(10));
+        let n = _mm256_or_si256(
+            _mm256_and_si256(is_digit, digit),
+            _mm256_and_si256(is_alpha, alpha),
+        );
+        (n, _mm256_or_si256(is_digit, is_alpha))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn pack_nibbles_avx2(n: __m256i) -> __m256i {
+        let hi = _mm256_and_si256(n, _mm256_set1_epi16(0xff));
+        _mm256_or_si256(_mm256_slli_epi16(hi, 4), _mm256_srli_epi16(n, 8))
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn decode_avx2(src: &[u8], dst: &mut [u8]) -> usize {
+        let blocks = dst.len().min(src.len() / 2) / 32;
+        for i in 0..blocks {
+            let p = src.as_ptr().add(i * 64) as *const __m256i;
+            let (n0, ok0) = ascii_to_nibble_avx2(_mm256_loadu_si256(p));
+            let (n1, ok1) = ascii_to_nibble_avx2(_mm256_loadu_si256(p.add(1)));
+            if _mm256_movemask_epi8(_mm256_and_si256(ok0, ok1)) != -1 {
+                return i * 32;
+            }
+            // pack works in 128 bit lanes too, restore order of the 4 quarters
+            let bytes = _mm256_packus_epi16(pack_nibbles_avx2(n0), pack_nibbles_avx2(n1));
+            let bytes = _mm256_permute4x64_epi64(bytes, 0b11_01_10_00);
+            _mm256_storeu_si256(dst.as_mut_ptr().add(i * 32) as *mut __m256i, bytes);
+        }
+        blocks * 32
+    }
+}
+
+#[cfg(all(test, target_arch = "x86_64"))]
+mod tests {
+    use super::x86::*;
+    use crate::number::hex::{_hex_decode, _hex_decode_scalar, _hex_encode, _hex_encode_scalar};
+
+    type Encoder = unsafe fn(&[u8], &mut [u8], bool) -> usize;
+    type Decoder = unsafe fn(&[u8], &mut [u8]) -> usize;
+
+    /// Vectorized encoders available on this machine, with their block size in bytes.
+    fn encoders() -> Vec<(&'static str, usize, Encoder)> {
+        let mut res: Vec<(&'static str, usize, Encoder)> = vec![("sse2", 16, encode_sse2)];
+        if is_x86_feature_detected!("avx2") {
+            res.push(("avx2", 32, encode_avx2));
+        }
+        res
+    }
+
+    fn decoders() -> Vec<(&'static str, Decoder)> {
+        let mut res: Vec<(&'static str, Decoder)> = vec![("sse2", decode_sse2)];
+        if is_x86_feature_detected!("avx2") {
+            res.push(("avx2", decode_avx2));
+        }
+        res
+    }
+
+    #[test]
+    fn hex_simd_encode_test() {
+        // every byte value at every lane, plus all tail lengths
+        let data: Vec<u8> = (0..=255)
+            .chain((0..=255).map(|b: u8| b.rotate_left(3)))
+            .collect();
+        for upper in [false, true].iter() {
+            for len in 0..=130 {
+                for start in 0..=data.len() - len {
+                    let src = &data[start..start + len];
+                    let mut want = vec![0u8; len * 2];
+                    _hex_encode_scalar(src, &mut want, *upper);
+                    for (name, block, encode) in encoders() {
+                        let mut got = vec![0u8; len * 2];
+                        let n = unsafe { encode(src, &mut got, *upper) };
+                        assert_eq!(n, len / block * block);
+                        assert_eq!(got[..n * 2], want[..n * 2], "{} {:?}", name, src);
+                    }
+                    let mut got = vec![0u8; len * 2];
+                    _hex_encode(src, &mut got, *upper);
+                    assert_eq!(got, want);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn hex_simd_decode_test() {
+        // every pair of byte values in each 16 chars quarter of a 64 chars block
+        let valid = b"0123456789abcdefABCDEF0123456789fedcbaFEDCBA9876543210aBcDeF0123";
+        for c0 in 0..=255u8 {
+            for c1 in 0..=255u8 {
+                for pos in [0, 14, 16, 30, 32, 46, 48, 62].iter().copied() {
+                    let mut src = valid.to_vec();
+                    src[pos] = c0;
+                    src[pos + 1] = c1;
+                    let mut want = [0u8; 32];
+                    let want_res = _hex_decode_scalar(&src, &mut want, 0);
+                    for (name, decode) in decoders() {
+                        let mut got = [0u8; 32];
+                        let n = unsafe { decode(&src, &mut got) };
+                        if want_res.is_ok() {
+                            assert_eq!(n, 32, "{} {:?}", name, src);
+                        } else {
+                            assert!(n * 2 <= pos, "{} {:?}", name, src);
+                        }
+                        assert_eq!(got[..n], want[..n], "{} {:?}", name, src);
+                    }
+                    let mut got = [0u8; 32];
+                    assert_eq!(_hex_decode(&src, &mut got), want_res);
+                    if want_res.is_ok() {
+                        assert_eq!(got, want);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,2 +1,11 @@
+pub mod base;
+pub mod base32;
+pub mod base58;
+pub mod base64;
 pub mod decimal;
-pub mod number;
\ No newline at end of file
+pub mod hex;
+mod hex_simd;
+pub mod modular;
+pub mod money;
+pub mod number;
+pub mod rational;
\ No newline at end of file
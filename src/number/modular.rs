@@ -0,0 +1,360 @@
+/*!
+Modular arithmetic on u64 and arbitrary precision integers.
+
+Arbitrary precision integers are `BigInt` and `BigUint` from the `num` crate, re-exported here.
+*/
+use core::fmt;
+pub use num::bigint::{BigInt, BigUint, Sign, ToBigInt, ToBigUint};
+use num::{Integer, One, Signed, ToPrimitive, Zero};
+use rand::Rng;
+
+/// Modular arithmetic error type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ModularErr {
+    /// Modulus must be positive.
+    ZeroModulus,
+
+    /// Number has no inverse because it is not coprime with modulus.
+    NotInvertible,
+
+    /// Residues and moduli of chinese remainder theorem have different lengths.
+    LengthMismatch,
+
+    /// Congruences of chinese remainder theorem have no common solution.
+    NoSolution,
+}
+
+impl std::error::Error for ModularErr {}
+
+impl fmt::Display for ModularErr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ModularErr::ZeroModulus => write!(f, "Modulus must be positive"),
+            ModularErr::NotInvertible => write!(f, "Number is not invertible"),
+            ModularErr::LengthMismatch => write!(f, "Residues and moduli length mismatch"),
+            ModularErr::NoSolution => write!(f, "Congruences have no common solution"),
+        }
+    }
+}
+
+/// $a b \mod m$ without overflow, m must > 0.
+#[inline(always)]
+pub fn mod_mul_u64(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+/// $a^e \mod m$ by square-and-multiply, m must > 0.
+pub fn mod_pow_u64(a: u64, e: u64, m: u64) -> u64 {
+    let mut res = 1 % m;
+    let mut base = a % m;
+    let mut e = e;
+    while e > 0 {
+        if e & 1 == 1 {
+            res = mod_mul_u64(res, base, m);
+        }
+        base = mod_mul_u64(base, base, m);
+        e >>= 1;
+    }
+    res
+}
+
+/**
+Extended Euclidean algorithm on i128, returns (g, x, y) with $g = \gcd(a, b) \ge 0$ and
+
+$$
+a x + b y = g
+$$
+*/
+pub fn ext_gcd_i128(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1i128, 0i128);
+    let (mut old_y, mut y) = (0i128, 1i128);
+    while r != 0 {
+        let q = old_r / r;
+        let tmp = old_r - q * r;
+        old_r = r;
+        r = tmp;
+        let tmp = old_x - q * x;
+        old_x = x;
+        x = tmp;
+        let tmp = old_y - q * y;
+        old_y = y;
+        y = tmp;
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// $a^{-1} \mod m$.
+pub fn mod_inv_u64(a: u64, m: u64) -> Result<u64, ModularErr> {
+    if m == 0 {
+        return Err(ModularErr::ZeroModulus);
+    }
+    let (g, x, _) = ext_gcd_i128(a as i128, m as i128);
+    if g != 1 {
+        return Err(ModularErr::NotInvertible);
+    }
+    Ok(x.rem_euclid(m as i128) as u64)
+}
+
+/**
+Deterministic Miller-Rabin primality test, the first 12 primes as bases are enough for every u64.
+*/
+pub fn is_prime_u64(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    for b in BASES.iter() {
+        if n.is_multiple_of(*b) {
+            return n == *b;
+        }
+    }
+
+    // n - 1 = d * 2^s
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    'witness: for a in BASES.iter() {
+        let mut x = mod_pow_u64(*a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mod_mul_u64(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// $a^e \mod m$.
+pub fn mod_pow(a: &BigUint, e: &BigUint, m: &BigUint) -> Result<BigUint, ModularErr> {
+    if m.is_zero() {
+        return Err(ModularErr::ZeroModulus);
+    }
+    Ok(a.modpow(e, m))
+}
+
+/**
+Extended Euclidean algorithm, returns (g, x, y) with $g = \gcd(a, b) \ge 0$ and
+
+$$
+a x + b y = g
+$$
+*/
+pub fn ext_gcd(a: &BigInt, b: &BigInt) -> (BigInt, BigInt, BigInt) {
+    let (mut old_r, mut r) = (a.clone(), b.clone());
+    let (mut old_x, mut x) = (BigInt::one(), BigInt::zero());
+    let (mut old_y, mut y) = (BigInt::zero(), BigInt::one());
+    while !r.is_zero() {
+        let q = &old_r / &r;
+        let tmp = &old_r - &q * &r;
+        old_r = std::mem::replace(&mut r, tmp);
+        let tmp = &old_x - &q * &x;
+        old_x = std::mem::replace(&mut x, tmp);
+        let tmp = &old_y - &q * &y;
+        old_y = std::mem::replace(&mut y, tmp);
+    }
+    if old_r.is_negative() {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// $a^{-1} \mod m$, result is in $[0, m)$.
+pub fn mod_inv(a: &BigInt, m: &BigInt) -> Result<BigInt, ModularErr> {
+    if !m.is_positive() {
+        return Err(ModularErr::ZeroModulus);
+    }
+    let (g, x, _) = ext_gcd(a, m);
+    if !g.is_one() {
+        return Err(ModularErr::NotInvertible);
+    }
+    Ok(x.mod_floor(m))
+}
+
+/**
+Chinese remainder theorem, solves $x \equiv r_i \mod m_i$ for all i, moduli need not be coprime.
+Returns (x, M) with $0 \le x < M$ and M = lcm of all moduli, every solution is $x + k M$.
+*/
+pub fn crt(residues: &[BigInt], moduli: &[BigInt]) -> Result<(BigInt, BigInt), ModularErr> {
+    if residues.len() != moduli.len() {
+        return Err(ModularErr::LengthMismatch);
+    }
+    let mut x = BigInt::zero();
+    let mut m = BigInt::one();
+    for (r_i, m_i) in residues.iter().zip(moduli.iter()) {
+        if !m_i.is_positive() {
+            return Err(ModularErr::ZeroModulus);
+        }
+        // x + m * k = r_i mod m_i  =>  m * k = r_i - x mod m_i
+        let (g, p, _) = ext_gcd(&m, m_i);
+        let diff = r_i - &x;
+        if !(&diff % &g).is_zero() {
+            return Err(ModularErr::NoSolution);
+        }
+        let m_i_g = m_i / &g;
+        let k = ((diff / &g) * p).mod_floor(&m_i_g);
+        x += &m * k;
+        m *= m_i_g;
+        x = x.mod_floor(&m);
+    }
+    Ok((x, m))
+}
+
+/**
+Miller-Rabin primality test.
+
+Numbers below $2^{64}$ are tested deterministically, larger ones with `rounds` random bases,
+a composite number passes with probability at most $4^{-rounds}$.
+*/
+pub fn is_probable_prime(n: &BigUint, rounds: usize) -> bool {
+    if let Some(n) = n.to_u64() {
+        return is_prime_u64(n);
+    }
+    const SMALL_PRIMES: [u32; 11] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31];
+    for p in SMALL_PRIMES.iter() {
+        if (n % *p).is_zero() {
+            return false;
+        }
+    }
+
+    let one = BigUint::one();
+    let two = BigUint::from(2u32);
+    let n_1 = n - &one;
+    let mut d = n_1.clone();
+    let mut s = 0usize;
+    while d.is_even() {
+        d >>= 1;
+        s += 1;
+    }
+
+    let mut rng = rand::thread_rng();
+    let byte_len = n.bits().div_ceil(8);
+    let mut bytes = vec![0u8; byte_len];
+    'witness: for round in 0..rounds.max(1) {
+        // base 2 first, then random bases in [2, n - 2]
+        let a = if round == 0 {
+            two.clone()
+        } else {
+            rng.fill(bytes.as_mut_slice());
+            BigUint::from_bytes_le(&bytes) % (n - 3u32) + &two
+        };
+        let mut x = a.modpow(&d, n);
+        if x == one || x == n_1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = x.modpow(&two, n);
+            if x == n_1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn big(s: &str) -> BigInt {
+        BigInt::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn mod_pow_u64_test() {
+        assert_eq!(mod_pow_u64(2, 10, 1000), 24);
+        assert_eq!(mod_pow_u64(5, 0, 1), 0);
+        assert_eq!(mod_pow_u64(u64::MAX, u64::MAX, u64::MAX - 1), 1);
+        assert_eq!(
+            mod_pow(
+                &BigUint::from(2u32),
+                &BigUint::from(100u32),
+                &BigUint::from(1_000_000_007u32)
+            ),
+            Ok(BigUint::from(976_371_285u32))
+        );
+        assert_eq!(
+            mod_pow(&BigUint::one(), &BigUint::one(), &BigUint::zero()),
+            Err(ModularErr::ZeroModulus)
+        );
+    }
+
+    #[test]
+    fn ext_gcd_test() {
+        assert_eq!(ext_gcd_i128(240, 46), (2, -9, 47));
+        assert_eq!(ext_gcd_i128(-4, 6).0, 2);
+
+        let (a, b) = (
+            big("123456789012345678901234567890"),
+            big("987654321098765432109876543210"),
+        );
+        let (g, x, y) = ext_gcd(&a, &b);
+        assert_eq!(g, big("9000000000900000000090"));
+        assert_eq!(&a * x + &b * y, g);
+    }
+
+    #[test]
+    fn mod_inv_test() {
+        assert_eq!(mod_inv_u64(3, 11), Ok(4));
+        assert_eq!(mod_inv_u64(6, 9), Err(ModularErr::NotInvertible));
+        assert_eq!(mod_inv_u64(1, 0), Err(ModularErr::ZeroModulus));
+
+        let m = big("170141183460469231731687303715884105727"); // 2^127 - 1
+        let a = big("-12345678901234567890");
+        let inv = mod_inv(&a, &m).unwrap();
+        assert!(inv.is_positive() && inv < m);
+        assert!((a * inv).mod_floor(&m).is_one());
+    }
+
+    #[test]
+    fn crt_test() {
+        let r: Vec<BigInt> = [2, 3, 2].iter().map(|x| BigInt::from(*x)).collect();
+        let m: Vec<BigInt> = [3, 5, 7].iter().map(|x| BigInt::from(*x)).collect();
+        assert_eq!(crt(&r, &m), Ok((BigInt::from(23), BigInt::from(105))));
+
+        // moduli need not be coprime
+        let r: Vec<BigInt> = [2, 4].iter().map(|x| BigInt::from(*x)).collect();
+        let m: Vec<BigInt> = [6, 8].iter().map(|x| BigInt::from(*x)).collect();
+        assert_eq!(crt(&r, &m), Ok((BigInt::from(20), BigInt::from(24))));
+
+        let r: Vec<BigInt> = [1, 2].iter().map(|x| BigInt::from(*x)).collect();
+        assert_eq!(crt(&r, &m), Err(ModularErr::NoSolution));
+        assert_eq!(crt(&r, &m[..1]), Err(ModularErr::LengthMismatch));
+        assert_eq!(crt(&[], &[]), Ok((BigInt::zero(), BigInt::one())));
+    }
+
+    #[test]
+    fn is_prime_test() {
+        let primes: Vec<u64> = (0..100).filter(|n| is_prime_u64(*n)).collect();
+        assert_eq!(
+            primes,
+            vec![
+                2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79,
+                83, 89, 97
+            ]
+        );
+        assert!(!is_prime_u64(3_215_031_751)); // strong pseudoprime to bases 2, 3, 5, 7
+        assert!(!is_prime_u64(3_825_123_056_546_413_051)); // strong pseudoprime to bases 2..=23
+        assert!(is_prime_u64(18_446_744_073_709_551_557));
+
+        let mersenne_127 = BigUint::from_str("170141183460469231731687303715884105727").unwrap();
+        assert!(is_probable_prime(&mersenne_127, 20));
+        // 2^128 + 1 = 59649589127497217 * 5704689200685129054721
+        let fermat_7 = BigUint::from_str("340282366920938463463374607431768211457").unwrap();
+        assert!(!is_probable_prime(&fermat_7, 20));
+        // Carmichael number 561 * large prime
+        let composite = &mersenne_127 * BigUint::from(561u32);
+        assert!(!is_probable_prime(&composite, 20));
+    }
+}
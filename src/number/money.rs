@@ -0,0 +1,457 @@
+/*!
+Money is an `Xdecimal` amount with an ISO 4217 currency.
+
+Amounts always fit the minor unit of their currency, 12.345 USD can't be represented,
+and are kept with exactly minor unit digits, so 12.300 USD is stored as 12.30,
+arithmetic between different currencies is refused, conversion needs an explicit `ExchangeRate`.
+*/
+use crate::number::decimal::{RoundingMode, Xdecimal, XdecimalErr};
+use crate::number::number::XNum;
+use core::fmt;
+use std::ops::Neg;
+
+/// Money error type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MoneyErr {
+    /// Currency code must be 3 upper case ASCII letters and minor unit must <= 28.
+    InvalidCurrency,
+
+    /// Currency code is not in the builtin currency list.
+    UnknownCurrency,
+
+    /// Operands have different currencies.
+    CurrencyMismatch {
+        left: Currency,
+        right: Currency,
+    },
+
+    /// Amount has more decimal places than minor unit of currency.
+    PrecisionLoss,
+
+    /// Allocation ratios are empty or sum to zero.
+    InvalidRatios,
+
+    Decimal(XdecimalErr),
+}
+
+impl std::error::Error for MoneyErr {}
+
+impl fmt::Display for MoneyErr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            MoneyErr::InvalidCurrency => write!(f, "Invalid currency"),
+            MoneyErr::UnknownCurrency => write!(f, "Unknown currency"),
+            MoneyErr::CurrencyMismatch { left, right } => {
+                write!(f, "Currency mismatch {} and {}", left, right)
+            }
+            MoneyErr::PrecisionLoss => write!(f, "Amount exceeds currency minor unit"),
+            MoneyErr::InvalidRatios => write!(f, "Invalid allocation ratios"),
+            MoneyErr::Decimal(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::convert::From<XdecimalErr> for MoneyErr {
+    fn from(e: XdecimalErr) -> MoneyErr {
+        MoneyErr::Decimal(e)
+    }
+}
+
+/// ISO 4217 currency, a 3 letter code and count of minor unit digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Currency {
+    code: [u8; 3],
+    minor_unit: u32,
+}
+
+pub const USD: Currency = Currency::builtin(b"USD", 2);
+pub const EUR: Currency = Currency::builtin(b"EUR", 2);
+pub const GBP: Currency = Currency::builtin(b"GBP", 2);
+pub const CNY: Currency = Currency::builtin(b"CNY", 2);
+pub const CHF: Currency = Currency::builtin(b"CHF", 2);
+pub const JPY: Currency = Currency::builtin(b"JPY", 0);
+pub const KRW: Currency = Currency::builtin(b"KRW", 0);
+pub const KWD: Currency = Currency::builtin(b"KWD", 3);
+pub const BHD: Currency = Currency::builtin(b"BHD", 3);
+
+const BUILTIN_CURRENCIES: [Currency; 9] = [USD, EUR, GBP, CNY, CHF, JPY, KRW, KWD, BHD];
+
+impl Currency {
+    const fn builtin(code: &[u8; 3], minor_unit: u32) -> Currency {
+        Currency {
+            code: *code,
+            minor_unit,
+        }
+    }
+
+    /// Custom currency, `Currency::new("USD", 2)`.
+    pub fn new(code: &str, minor_unit: u32) -> Result<Currency, MoneyErr> {
+        let bytes = code.as_bytes();
+        if bytes.len() != 3 || !bytes.iter().all(|b| b.is_ascii_uppercase()) || minor_unit > 28 {
+            return Err(MoneyErr::InvalidCurrency);
+        }
+        Ok(Currency {
+            code: [bytes[0], bytes[1], bytes[2]],
+            minor_unit,
+        })
+    }
+
+    /// Look up builtin currency by code.
+    pub fn from_code(code: &str) -> Result<Currency, MoneyErr> {
+        BUILTIN_CURRENCIES
+            .iter()
+            .find(|c| c.code() == code)
+            .copied()
+            .ok_or(MoneyErr::UnknownCurrency)
+    }
+
+    pub fn code(&self) -> &str {
+        std::str::from_utf8(&self.code).unwrap()
+    }
+
+    /// Count of digits after decimal point, 2 for USD (cent), 0 for JPY.
+    pub fn minor_unit(&self) -> u32 {
+        self.minor_unit
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Money {
+    amount: Xdecimal,
+    currency: Currency,
+}
+
+impl Money {
+    /// Error if amount has more decimal places than minor unit of currency.
+    pub fn new(amount: Xdecimal, currency: Currency) -> Result<Money, MoneyErr> {
+        if amount.round_dp(currency.minor_unit, RoundingMode::Truncate) != amount {
+            return Err(MoneyErr::PrecisionLoss);
+        }
+        Ok(Money::with_minor_unit(amount, currency))
+    }
+
+    /// Round amount to minor unit of currency.
+    pub fn new_rounded(amount: Xdecimal, currency: Currency, mode: RoundingMode) -> Money {
+        Money::with_minor_unit(amount.round_dp(currency.minor_unit, mode), currency)
+    }
+
+    /// From count of minor units, 1234 cents is 12.34 USD.
+    pub fn from_minor_units(units: i64, currency: Currency) -> Money {
+        Money {
+            amount: Xdecimal::new(units, currency.minor_unit),
+            currency,
+        }
+    }
+
+    pub fn zero(currency: Currency) -> Money {
+        Money::from_minor_units(0, currency)
+    }
+
+    pub fn amount(&self) -> Xdecimal {
+        self.amount
+    }
+
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// Count of minor units, 12.34 USD is 1234 cents.
+    pub fn minor_units(&self) -> i128 {
+        let mut units = self.amount.mantissa();
+        for _ in self.amount.scale()..self.currency.minor_unit {
+            units *= 10;
+        }
+        units
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.amount.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.amount.is_negative()
+    }
+
+    pub fn checked_add(&self, other: &Money) -> Result<Money, MoneyErr> {
+        self.check_currency(other)?;
+        let amount = self.amount.checked_add(other.amount)?;
+        Ok(Money::with_minor_unit(amount, self.currency))
+    }
+
+    pub fn checked_sub(&self, other: &Money) -> Result<Money, MoneyErr> {
+        self.check_currency(other)?;
+        let amount = self.amount.checked_sub(other.amount)?;
+        Ok(Money::with_minor_unit(amount, self.currency))
+    }
+
+    /// Multiply by a factor such as tax rate, result is rounded to minor unit.
+    pub fn checked_mul(&self, factor: Xdecimal, mode: RoundingMode) -> Result<Money, MoneyErr> {
+        let amount = self.amount.checked_mul(factor)?;
+        Ok(Money::new_rounded(amount, self.currency, mode))
+    }
+
+    /**
+    Split amount by ratios with largest remainder method, sum of parts always equals to the amount.
+
+    Every part first gets $\lfloor amount \cdot r_i / \sum r \rfloor$ minor units,
+    the minor units left are given one by one to parts with the largest remainders, ties go to earlier parts.
+    100.00 USD allocated by [1, 1, 1] is [33.34, 33.33, 33.33].
+    */
+    pub fn allocate(&self, ratios: &[u64]) -> Result<Vec<Money>, MoneyErr> {
+        let total_ratio: u128 = ratios.iter().map(|r| *r as u128).sum();
+        if total_ratio == 0 {
+            return Err(MoneyErr::InvalidRatios);
+        }
+        let total_ratio = total_ratio as i128;
+
+        // allocate absolute value, then restore sign, so negative amounts are split symmetrically
+        let units = self.minor_units();
+        let abs_units = units.abs();
+        let mut parts = Vec::with_capacity(ratios.len());
+        let mut remainders = Vec::with_capacity(ratios.len());
+        for (i, r) in ratios.iter().enumerate() {
+            let product = abs_units
+                .checked_mul(*r as i128)
+                .ok_or(MoneyErr::Decimal(XdecimalErr::Overflow))?;
+            parts.push(product / total_ratio);
+            remainders.push((product % total_ratio, i));
+        }
+        let left = abs_units - parts.iter().sum::<i128>();
+        remainders.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        for (_, i) in remainders.iter().take(left as usize) {
+            parts[*i] += 1;
+        }
+
+        parts
+            .into_iter()
+            .map(|p| {
+                let p = if units < 0 { -p } else { p };
+                Ok(Money {
+                    amount: Xdecimal::from_i128_with_scale(p, self.currency.minor_unit)?,
+                    currency: self.currency,
+                })
+            })
+            .collect()
+    }
+
+    /// Split amount into n parts as even as possible, 1.00 USD split into 3 is [0.34, 0.33, 0.33].
+    pub fn split(&self, n: usize) -> Result<Vec<Money>, MoneyErr> {
+        self.allocate(&vec![1; n])
+    }
+
+    /// Convert to another currency, result is rounded to minor unit of target currency.
+    pub fn convert(&self, rate: &ExchangeRate, mode: RoundingMode) -> Result<Money, MoneyErr> {
+        if rate.from != self.currency {
+            return Err(MoneyErr::CurrencyMismatch {
+                left: self.currency,
+                right: rate.from,
+            });
+        }
+        let amount = self.amount.checked_mul(rate.rate)?;
+        Ok(Money::new_rounded(amount, rate.to, mode))
+    }
+
+    /// Store amount with exactly minor unit digits, so 12.300 USD is kept as 12.30 and
+    /// the mantissa counts minor units. Amount must already fit the minor unit.
+    fn with_minor_unit(amount: Xdecimal, currency: Currency) -> Money {
+        Money {
+            amount: amount.rescale(currency.minor_unit),
+            currency,
+        }
+    }
+
+    fn check_currency(&self, other: &Money) -> Result<(), MoneyErr> {
+        if self.currency != other.currency {
+            return Err(MoneyErr::CurrencyMismatch {
+                left: self.currency,
+                right: other.currency,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Neg for Money {
+    type Output = Money;
+    fn neg(self) -> Money {
+        Money {
+            amount: -self.amount,
+            currency: self.currency,
+        }
+    }
+}
+
+impl fmt::Display for Money {
+    /// Amount with all minor unit digits and currency code, such as "12.30 USD".
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:.*} {}",
+            self.currency.minor_unit as usize, self.amount, self.currency
+        )
+    }
+}
+
+/// 1 unit of currency `from` equals to `rate` units of currency `to`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExchangeRate {
+    from: Currency,
+    to: Currency,
+    rate: Xdecimal,
+}
+
+impl ExchangeRate {
+    /// Rate must be positive.
+    pub fn new(from: Currency, to: Currency, rate: Xdecimal) -> Result<ExchangeRate, MoneyErr> {
+        if rate.is_negative() || rate.is_zero() {
+            return Err(MoneyErr::Decimal(XdecimalErr::InvalidOperand));
+        }
+        Ok(ExchangeRate { from, to, rate })
+    }
+
+    pub fn from(&self) -> Currency {
+        self.from
+    }
+
+    pub fn to(&self) -> Currency {
+        self.to
+    }
+
+    pub fn rate(&self) -> Xdecimal {
+        self.rate
+    }
+
+    /// Rate of the opposite direction, $1 / rate$.
+    pub fn inverse(&self) -> Result<ExchangeRate, MoneyErr> {
+        Ok(ExchangeRate {
+            from: self.to,
+            to: self.from,
+            rate: Xdecimal::n_1().checked_div(self.rate)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn usd(s: &str) -> Money {
+        Money::new(Xdecimal::from_str(s).unwrap(), USD).unwrap()
+    }
+
+    fn amounts(v: &[Money]) -> Vec<String> {
+        v.iter().map(|m| m.amount().to_string()).collect()
+    }
+
+    #[test]
+    fn currency_test() {
+        assert_eq!(Currency::from_code("JPY"), Ok(JPY));
+        assert_eq!(Currency::from_code("XYZ"), Err(MoneyErr::UnknownCurrency));
+        assert_eq!(Currency::new("BTC", 8).unwrap().minor_unit(), 8);
+        assert_eq!(Currency::new("usd", 2), Err(MoneyErr::InvalidCurrency));
+        assert_eq!(Currency::new("USDT", 2), Err(MoneyErr::InvalidCurrency));
+        assert_eq!(USD.to_string(), "USD");
+    }
+
+    #[test]
+    fn money_new_test() {
+        assert_eq!(usd("12.3").to_string(), "12.30 USD");
+        assert_eq!(usd("-0.05").minor_units(), -5);
+        assert_eq!(
+            Money::new(Xdecimal::from_str("12.345").unwrap(), USD),
+            Err(MoneyErr::PrecisionLoss)
+        );
+        assert_eq!(
+            Money::new_rounded(
+                Xdecimal::from_str("12.345").unwrap(),
+                USD,
+                RoundingMode::HalfEven
+            ),
+            usd("12.34")
+        );
+        assert_eq!(Money::from_minor_units(1234, USD), usd("12.34"));
+        assert_eq!(Money::from_minor_units(1234, JPY).to_string(), "1234 JPY");
+        assert_eq!(Money::from_minor_units(1234, KWD).to_string(), "1.234 KWD");
+    }
+
+    #[test]
+    fn money_arithmetic_test() {
+        assert_eq!(usd("0.10").checked_add(&usd("0.20")), Ok(usd("0.30")));
+        assert_eq!(usd("0.10").checked_sub(&usd("0.20")), Ok(usd("-0.10")));
+        assert_eq!(-usd("1"), usd("-1"));
+        let yen = Money::from_minor_units(100, JPY);
+        assert_eq!(
+            usd("1").checked_add(&yen),
+            Err(MoneyErr::CurrencyMismatch {
+                left: USD,
+                right: JPY
+            })
+        );
+        // 8.25% tax on 19.99
+        let tax = usd("19.99").checked_mul(Xdecimal::new(825, 4), RoundingMode::HalfUp);
+        assert_eq!(tax, Ok(usd("1.65")));
+    }
+
+    #[test]
+    fn money_allocate_test() {
+        assert_eq!(
+            amounts(&usd("100").allocate(&[1, 1, 1]).unwrap()),
+            vec!["33.34", "33.33", "33.33"]
+        );
+        assert_eq!(
+            amounts(&usd("0.05").allocate(&[3, 7]).unwrap()),
+            vec!["0.02", "0.03"]
+        );
+        assert_eq!(
+            amounts(&usd("-1.00").split(3).unwrap()),
+            vec!["-0.34", "-0.33", "-0.33"]
+        );
+        assert_eq!(
+            amounts(&usd("10").allocate(&[0, 1]).unwrap()),
+            vec!["0.00", "10.00"]
+        );
+        assert_eq!(usd("1").allocate(&[]), Err(MoneyErr::InvalidRatios));
+        assert_eq!(usd("1").split(0), Err(MoneyErr::InvalidRatios));
+
+        // trailing zeros beyond the minor unit don't change the amount
+        let m = usd("12.300");
+        assert_eq!(m.minor_units(), 1230);
+        assert_eq!(m.amount().to_string(), "12.30");
+        assert_eq!(amounts(&m.split(3).unwrap()), vec!["4.10", "4.10", "4.10"]);
+        assert_eq!(amounts(&m.allocate(&[1, 2]).unwrap()), vec!["4.10", "8.20"]);
+        assert_eq!(
+            usd("0.100").checked_add(&usd("0.2")).unwrap().minor_units(),
+            30
+        );
+
+        // parts always sum to the amount
+        let total = usd("1234.57");
+        for ratios in [vec![1u64, 2, 3], vec![5; 7], vec![1, 1000000, 3]].iter() {
+            let parts = total.allocate(ratios).unwrap();
+            let sum = parts
+                .iter()
+                .try_fold(Money::zero(USD), |acc, p| acc.checked_add(p))
+                .unwrap();
+            assert_eq!(sum, total);
+        }
+    }
+
+    #[test]
+    fn money_convert_test() {
+        let rate = ExchangeRate::new(USD, JPY, Xdecimal::from_str("149.735").unwrap()).unwrap();
+        let yen = usd("10.99").convert(&rate, RoundingMode::HalfEven).unwrap();
+        assert_eq!(yen.to_string(), "1646 JPY");
+        assert!(yen.convert(&rate, RoundingMode::HalfEven).is_err());
+
+        let back = yen.convert(&rate.inverse().unwrap(), RoundingMode::HalfEven);
+        assert_eq!(back, Ok(usd("10.99")));
+        assert!(ExchangeRate::new(USD, EUR, Xdecimal::n_0()).is_err());
+    }
+}
@@ -1,105 +1,295 @@
+use std::convert::TryFrom;
+use std::fmt::Debug;
 use std::ops::*;
 
-pub trait XNum: Add + Sub + Mul + Div {
-    fn num_type() ->String;
-    fn n_0() ->XNum;
-    fn n_1() ->XNum;
-    fn n_u8(n: u8) ->XNum;
-}
+/**
+Common number trait, implemented for all primitive integers, floats and `Xdecimal`,
+so algorithms can be written once for any number type.
 
+Checked operations return None on overflow, division by zero, or a non finite float result.
+*/
+pub trait XNum:
+    Copy
+    + Debug
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    fn num_type() -> String;
+    fn n_0() -> Self;
+    fn n_1() -> Self;
 
-impl XNum for u8 {
-    fn num_type() ->String {
-        return "u8".to_string();
-    }
+    /// Small constant n, panics if it doesn't fit the type, which is only i8 for n > 127.
+    fn n_u8(n: u8) -> Self;
 
-    fn n_0() ->XNum { 0u8 }
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_div(self, rhs: Self) -> Option<Self>;
 
-    fn n_1() ->XNum { 1u8 }
+    /// Lossy conversion to f64.
+    fn to_f64(self) -> f64;
 
-    fn n_u8(n: u8) ->XNum { n }
-}
+    /// Conversion from f64, None if n is out of range or not a number, integers are truncated.
+    fn from_f64(n: f64) -> Option<Self>;
 
-impl XNum2 for u8 {
-    fn N0() ->XNum2 {
-        return 0u8;
+    fn is_zero(&self) -> bool {
+        *self == Self::n_0()
     }
 }
 
-impl XNum for u16 {
-    fn num_type() ->String {
-        return "u16".to_string();
-    }
+/// Primitive integer extensions.
+pub trait XInt: XNum + Eq + Ord + Rem<Output = Self> {
+    fn min_value() -> Self;
+    fn max_value() -> Self;
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+    fn checked_pow(self, exp: u32) -> Option<Self>;
 }
 
-impl XNum for u32 {
-    fn num_type() ->String {
-        return "u32".to_string();
-    }
+/// Float extensions, for types with fractional part.
+pub trait XFloat: XNum + Neg<Output = Self> {
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+    /// Natural logarithm.
+    fn ln(self) -> Self;
+    fn exp(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
 }
 
-impl XNum for u64 {
-    fn num_type() ->String {
-        return "u64".to_string();
-    }
+macro_rules! impl_xnum_int {
+    ($($t:ty),*) => {$(
+        impl XNum for $t {
+            fn num_type() -> String {
+                stringify!($t).to_string()
+            }
+
+            fn n_0() -> $t {
+                0
+            }
+
+            fn n_1() -> $t {
+                1
+            }
+
+            fn n_u8(n: u8) -> $t {
+                <$t>::try_from(n).expect("n_u8 out of range")
+            }
+
+            fn checked_add(self, rhs: $t) -> Option<$t> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: $t) -> Option<$t> {
+                <$t>::checked_sub(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: $t) -> Option<$t> {
+                <$t>::checked_mul(self, rhs)
+            }
+
+            fn checked_div(self, rhs: $t) -> Option<$t> {
+                <$t>::checked_div(self, rhs)
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn from_f64(n: f64) -> Option<$t> {
+                num_traits::cast::FromPrimitive::from_f64(n)
+            }
+        }
+
+        impl XInt for $t {
+            fn min_value() -> $t {
+                <$t>::MIN
+            }
+
+            fn max_value() -> $t {
+                <$t>::MAX
+            }
+
+            fn checked_rem(self, rhs: $t) -> Option<$t> {
+                <$t>::checked_rem(self, rhs)
+            }
+
+            fn checked_pow(self, exp: u32) -> Option<$t> {
+                <$t>::checked_pow(self, exp)
+            }
+        }
+    )*};
 }
 
-impl XNum for u128 {
-    fn num_type() ->String {
-        return "u128".to_string();
-    }
+macro_rules! impl_xnum_float {
+    ($($t:ty),*) => {$(
+        impl XNum for $t {
+            fn num_type() -> String {
+                stringify!($t).to_string()
+            }
+
+            fn n_0() -> $t {
+                0.0
+            }
+
+            fn n_1() -> $t {
+                1.0
+            }
+
+            fn n_u8(n: u8) -> $t {
+                n as $t
+            }
+
+            fn checked_add(self, rhs: $t) -> Option<$t> {
+                Some(self + rhs).filter(|x| x.is_finite())
+            }
+
+            fn checked_sub(self, rhs: $t) -> Option<$t> {
+                Some(self - rhs).filter(|x| x.is_finite())
+            }
+
+            fn checked_mul(self, rhs: $t) -> Option<$t> {
+                Some(self * rhs).filter(|x| x.is_finite())
+            }
+
+            fn checked_div(self, rhs: $t) -> Option<$t> {
+                Some(self / rhs).filter(|x| x.is_finite())
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn from_f64(n: f64) -> Option<$t> {
+                if n.is_nan() {
+                    return None;
+                }
+                Some(n as $t)
+            }
+        }
+
+        impl XFloat for $t {
+            fn abs(self) -> $t {
+                <$t>::abs(self)
+            }
+
+            fn sqrt(self) -> $t {
+                <$t>::sqrt(self)
+            }
+
+            fn ln(self) -> $t {
+                <$t>::ln(self)
+            }
+
+            fn exp(self) -> $t {
+                <$t>::exp(self)
+            }
+
+            fn powi(self, n: i32) -> $t {
+                <$t>::powi(self, n)
+            }
+
+            fn powf(self, n: $t) -> $t {
+                <$t>::powf(self, n)
+            }
+
+            fn floor(self) -> $t {
+                <$t>::floor(self)
+            }
+
+            fn ceil(self) -> $t {
+                <$t>::ceil(self)
+            }
+
+            fn round(self) -> $t {
+                <$t>::round(self)
+            }
+        }
+    )*};
 }
 
+impl_xnum_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_xnum_float!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-impl XNum for i8 {
-    fn num_type() ->String {
-        return "i8".to_string();
+    fn sum<T: XNum>(v: &[T]) -> T {
+        v.iter().fold(T::n_0(), |acc, x| acc + *x)
     }
-}
 
-impl XNum for i16 {
-    fn num_type() ->String {
-        return "i16".to_string();
+    fn checked_sum<T: XNum>(v: &[T]) -> Option<T> {
+        v.iter().try_fold(T::n_0(), |acc, x| acc.checked_add(*x))
     }
-}
 
-impl XNum for i32 {
-    fn num_type() ->String {
-        return "i32".to_string();
+    #[test]
+    fn num_type() {
+        assert_eq!(u8::num_type(), "u8");
+        assert_eq!(i128::num_type(), "i128");
+        assert_eq!(f64::num_type(), "f64");
     }
-}
 
-impl XNum for i64 {
-    fn num_type() ->String {
-        return "i64".to_string();
+    #[test]
+    fn generic_sum_test() {
+        assert_eq!(sum(&[1u8, 2, 3]), 6);
+        assert_eq!(sum(&[-1i64, 2, 3]), 4);
+        assert_eq!(sum(&[0.5f64, 0.25]), 0.75);
+        assert_eq!(checked_sum(&[200u8, 100]), None);
+        assert_eq!(checked_sum(&[100u8, 100]), Some(200));
+        assert_eq!(checked_sum(&[f64::MAX, f64::MAX]), None);
     }
-}
 
-impl XNum for i128 {
-    fn num_type() ->String {
-        return "i128".to_string();
+    #[test]
+    fn n_u8_test() {
+        assert_eq!(u8::n_u8(200), 200);
+        assert_eq!(i16::n_u8(200), 200);
+        assert_eq!(i8::n_u8(127), 127);
+        assert_eq!(f32::n_u8(255), 255.0);
     }
-}
 
-impl XNum for f32 {
-    fn num_type() ->String {
-        return "f32".to_string();
+    #[test]
+    #[should_panic(expected = "n_u8 out of range")]
+    fn n_u8_out_of_range_test() {
+        i8::n_u8(200);
     }
-}
 
-impl XNum for f64 {
-    fn num_type() ->String {
-        return "f64".to_string();
+    #[test]
+    fn checked_ops_test() {
+        assert_eq!(XNum::checked_div(1i32, 0), None);
+        assert_eq!(XNum::checked_div(1.0f32, 0.0), None);
+        assert_eq!(XNum::checked_sub(0u32, 1), None);
+        assert_eq!(XNum::checked_mul(i8::MIN, -1), None);
+        assert_eq!(XInt::checked_pow(2u16, 16), None);
+        assert_eq!(XInt::checked_rem(7i32, 0), None);
+        assert_eq!(<u8 as XInt>::max_value(), 255);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn conversion_test() {
+        assert_eq!(u8::from_f64(255.9), Some(255));
+        assert_eq!(u8::from_f64(256.0), None);
+        assert_eq!(i32::from_f64(-1.5), Some(-1));
+        assert_eq!(i32::from_f64(f64::NAN), None);
+        assert_eq!(f32::from_f64(0.5), Some(0.5));
+        assert_eq!(XNum::to_f64(u64::MAX), 18446744073709551615.0);
+        assert!(0u16.is_zero() && !1.0f64.is_zero());
+    }
 
     #[test]
-    fn num_type() {
-        println!("{}", u8::num_type());
+    fn float_test() {
+        fn hypot<T: XFloat>(a: T, b: T) -> T {
+            (a * a + b * b).sqrt()
+        }
+        assert_eq!(hypot(3.0f64, 4.0), 5.0);
+        assert_eq!(hypot(-3.0f32, 4.0), 5.0);
+        assert_eq!(XFloat::ln(XFloat::exp(1.0f64)), 1.0);
+        assert_eq!(XFloat::powi(-2.0f64, 3), -8.0);
     }
 }
-
@@ -0,0 +1,678 @@
+/*!
+Exact rational numbers backed by i64 or `BigInt`.
+
+A rational is always normalized: denominator is positive and $\gcd(numer, denom) = 1$, so 2/-4 is stored as -1/2.
+Operators panic on division by zero like primitive integers, `Rational64` arithmetic may overflow like i64,
+use `BigRational` when numbers grow.
+*/
+use crate::number::decimal::{RoundingMode, Xdecimal, XdecimalErr};
+use crate::number::modular::{BigInt, ToBigInt};
+use core::fmt;
+use num::{CheckedMul, FromPrimitive, Integer, One, Signed, ToPrimitive, Zero};
+use std::cmp::Ordering;
+use std::ops::*;
+use std::str::FromStr;
+
+/// Rational error type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RationalErr {
+    ZeroDenominator,
+
+    /// Result does not fit the integer type.
+    Overflow,
+
+    /// Input is NaN or infinite.
+    NotFinite,
+
+    /// String is not "numer/denom" or "integer".
+    Parse,
+}
+
+impl std::error::Error for RationalErr {}
+
+impl fmt::Display for RationalErr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RationalErr::ZeroDenominator => write!(f, "Zero denominator"),
+            RationalErr::Overflow => write!(f, "Rational overflow"),
+            RationalErr::NotFinite => write!(f, "Number is not finite"),
+            RationalErr::Parse => write!(f, "Invalid rational string"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational<T> {
+    numer: T,
+    denom: T,
+}
+
+pub type Rational64 = Rational<i64>;
+pub type BigRational = Rational<BigInt>;
+
+impl<T> Rational<T>
+where
+    T: Clone + Integer + Signed,
+{
+    pub fn from_integer(n: T) -> Rational<T> {
+        Rational {
+            numer: n,
+            denom: T::one(),
+        }
+    }
+
+    // denom must not be zero, the sign overflows like i64 at i64::MIN
+    fn new_unchecked(numer: T, denom: T) -> Rational<T> {
+        let g = numer.gcd(&denom);
+        let (mut numer, mut denom) = (numer / g.clone(), denom / g);
+        if denom.is_negative() {
+            numer = -numer;
+            denom = -denom;
+        }
+        Rational { numer, denom }
+    }
+}
+
+impl<T> Rational<T>
+where
+    T: Clone + Integer + Signed + CheckedMul,
+{
+    /// Error if denom is zero, or the normalized value does not fit T, such as `i64::MIN / -1`.
+    pub fn new(numer: T, denom: T) -> Result<Rational<T>, RationalErr> {
+        if denom.is_zero() {
+            return Err(RationalErr::ZeroDenominator);
+        }
+        // gcd of i64::MIN with 0 or itself is 2^63, which overflows
+        if numer.is_zero() {
+            return Ok(Rational::from_integer(T::zero()));
+        }
+        if numer == denom {
+            return Ok(Rational::from_integer(T::one()));
+        }
+        let g = numer.gcd(&denom);
+        let (numer, denom) = (numer / g.clone(), denom / g);
+        if !denom.is_negative() {
+            return Ok(Rational { numer, denom });
+        }
+        let minus_one = -T::one();
+        Ok(Rational {
+            numer: numer.checked_mul(&minus_one).ok_or(RationalErr::Overflow)?,
+            denom: denom.checked_mul(&minus_one).ok_or(RationalErr::Overflow)?,
+        })
+    }
+
+    pub fn numer(&self) -> &T {
+        &self.numer
+    }
+
+    pub fn denom(&self) -> &T {
+        &self.denom
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denom.is_one()
+    }
+
+    pub fn abs(&self) -> Rational<T> {
+        Rational {
+            numer: self.numer.abs(),
+            denom: self.denom.clone(),
+        }
+    }
+
+    /// $1 / self$.
+    pub fn recip(&self) -> Result<Rational<T>, RationalErr> {
+        Rational::new(self.denom.clone(), self.numer.clone())
+    }
+
+    /// Largest integer <= self.
+    pub fn floor(&self) -> T {
+        self.numer.div_floor(&self.denom)
+    }
+
+    /// Smallest integer >= self.
+    pub fn ceil(&self) -> T {
+        -((-self.numer.clone()).div_floor(&self.denom))
+    }
+
+    /// Integer part, rounded toward zero.
+    pub fn trunc(&self) -> T {
+        self.numer.clone() / self.denom.clone()
+    }
+
+    /// $self^{exp}$, error if self is 0 and exp < 0.
+    pub fn pow(&self, exp: i32) -> Result<Rational<T>, RationalErr> {
+        let base = if exp < 0 { self.recip()? } else { self.clone() };
+        let e = exp.unsigned_abs() as usize;
+        Ok(Rational {
+            numer: num::pow(base.numer, e),
+            denom: num::pow(base.denom, e),
+        })
+    }
+
+    /**
+    Regular continued fraction $[a_0; a_1, a_2, \dots]$ of self, finite since self is rational.
+
+    $$
+    self = a_0 + \cfrac{1}{a_1 + \cfrac{1}{a_2 + \dots}}
+    $$
+    */
+    pub fn continued_fraction(&self) -> Vec<T> {
+        let mut res = vec![];
+        let (mut p, mut q) = (self.numer.clone(), self.denom.clone());
+        while !q.is_zero() {
+            let (a, r) = p.div_mod_floor(&q);
+            res.push(a);
+            p = std::mem::replace(&mut q, r);
+        }
+        res
+    }
+
+    /// Build rational from continued fraction terms, None if terms is empty or a denominator becomes zero.
+    pub fn from_continued_fraction(terms: &[T]) -> Option<Rational<T>> {
+        let (last, rest) = terms.split_last()?;
+        let mut res = Rational::from_integer(last.clone());
+        for a in rest.iter().rev() {
+            res = Rational::from_integer(a.clone()) + res.recip().ok()?;
+        }
+        Some(res)
+    }
+
+    /// Convergents $h_n / k_n$ of continued fraction, the last one equals to self.
+    pub fn convergents(&self) -> Vec<Rational<T>> {
+        let (mut h_1, mut h_2) = (T::one(), T::zero());
+        let (mut k_1, mut k_2) = (T::zero(), T::one());
+        self.continued_fraction()
+            .into_iter()
+            .map(|a| {
+                let h = a.clone() * h_1.clone() + h_2.clone();
+                let k = a * k_1.clone() + k_2.clone();
+                h_2 = std::mem::replace(&mut h_1, h.clone());
+                k_2 = std::mem::replace(&mut k_1, k.clone());
+                Rational { numer: h, denom: k }
+            })
+            .collect()
+    }
+
+    /**
+    Best rational approximation with denominator <= max_denom, which is nearest to self among all such rationals,
+    ties go to the smaller denominator.
+
+    It is either the last convergent within the limit or a semiconvergent between it and the next convergent.
+    Returns self if max_denom < 1.
+    */
+    pub fn best_approximation(&self, max_denom: &T) -> Rational<T> {
+        if *max_denom < T::one() || self.denom <= *max_denom {
+            return self.clone();
+        }
+
+        let (mut h_1, mut h_2) = (T::one(), T::zero());
+        let (mut k_1, mut k_2) = (T::zero(), T::one());
+        for a in self.continued_fraction() {
+            let k = a.clone() * k_1.clone() + k_2.clone();
+            if k > *max_denom {
+                // largest semiconvergent (t h_1 + h_2) / (t k_1 + k_2) within the limit
+                let t = (max_denom.clone() - k_2.clone()) / k_1.clone();
+                let semi =
+                    Rational::new_unchecked(t.clone() * h_1.clone() + h_2, t * k_1.clone() + k_2);
+                let conv = Rational {
+                    numer: h_1,
+                    denom: k_1,
+                };
+                let semi_err = (semi.clone() - self.clone()).abs();
+                let conv_err = (conv.clone() - self.clone()).abs();
+                return if semi_err < conv_err { semi } else { conv };
+            }
+            let h = a * h_1.clone() + h_2;
+            h_2 = std::mem::replace(&mut h_1, h);
+            k_2 = std::mem::replace(&mut k_1, k);
+        }
+        self.clone()
+    }
+}
+
+impl<T> Rational<T>
+where
+    T: Clone + Integer + Signed + CheckedMul + FromPrimitive + ToPrimitive,
+{
+    /// Exact value of x, every finite f64 is a dyadic rational $m \cdot 2^e$.
+    pub fn from_f64(x: f64) -> Result<Rational<T>, RationalErr> {
+        if !x.is_finite() {
+            return Err(RationalErr::NotFinite);
+        }
+        if x == 0.0 {
+            return Ok(Rational::from_integer(T::zero()));
+        }
+
+        let bits = x.to_bits();
+        let biased_exp = ((bits >> 52) & 0x7FF) as i32;
+        let fraction = bits & ((1u64 << 52) - 1);
+        // subnormal numbers have no implicit leading bit
+        let (mut mantissa, mut exp) = if biased_exp == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | (1u64 << 52), biased_exp - 1075)
+        };
+        let zeros = mantissa.trailing_zeros();
+        mantissa >>= zeros;
+        exp += zeros as i32;
+
+        let mut numer = T::from_u64(mantissa).ok_or(RationalErr::Overflow)?;
+        if x < 0.0 {
+            numer = -numer;
+        }
+        let two = T::from_u8(2).ok_or(RationalErr::Overflow)?;
+        let power =
+            num::checked_pow(two, exp.unsigned_abs() as usize).ok_or(RationalErr::Overflow)?;
+        if exp >= 0 {
+            let numer = numer.checked_mul(&power).ok_or(RationalErr::Overflow)?;
+            Ok(Rational::from_integer(numer))
+        } else {
+            Ok(Rational {
+                numer,
+                denom: power,
+            })
+        }
+    }
+
+    /**
+    Nearest f64, ties to even, also when numerator or denominator is beyond f64 range.
+
+    The quotient is taken to 55 bits with a sticky remainder and rounded once,
+    so the result is correctly rounded, subnormal results included.
+    */
+    pub fn to_f64(&self) -> f64
+    where
+        T: ToBigInt,
+    {
+        let numer = self.numer.to_bigint().unwrap();
+        let denom = self.denom.to_bigint().unwrap();
+        if numer.is_zero() {
+            return 0.0;
+        }
+
+        // value = q * 2^(-shift), q has 55 or 56 bits
+        let shift = denom.bits() as i64 - numer.bits() as i64 + 55;
+        let (a, b) = if shift >= 0 {
+            (numer.abs() << shift as usize, denom)
+        } else {
+            (numer.abs(), denom << (-shift) as usize)
+        };
+        let (q, r) = a.div_rem(&b);
+        let q = q.to_u64().unwrap();
+        let len = 64 - q.leading_zeros() as i64;
+
+        // keep 53 significant bits, or bits down to 2^-1074 for subnormals
+        let drop = (len - 53).max(shift - 1074);
+        let value = if drop > len {
+            0.0
+        } else {
+            let mut m = q >> drop;
+            let rest = q & ((1 << drop) - 1);
+            let half = 1 << (drop - 1);
+            if rest > half || (rest == half && (!r.is_zero() || m & 1 == 1)) {
+                m += 1;
+            }
+            let mut exp = drop - shift;
+            if m == 1 << 53 {
+                m >>= 1;
+                exp += 1;
+            }
+            if m < 1 << 52 {
+                // subnormal, exp is -1074
+                f64::from_bits(m)
+            } else if exp + 52 + 1023 >= 2047 {
+                f64::INFINITY
+            } else {
+                f64::from_bits(((exp + 52 + 1023) as u64) << 52 | (m & ((1 << 52) - 1)))
+            }
+        };
+        if self.numer.is_negative() {
+            -value
+        } else {
+            value
+        }
+    }
+
+    /// Exact value of x, error if it does not fit T.
+    pub fn from_xdecimal(x: Xdecimal) -> Result<Rational<T>, RationalErr> {
+        let numer = T::from_i128(x.mantissa()).ok_or(RationalErr::Overflow)?;
+        let ten = T::from_u8(10).ok_or(RationalErr::Overflow)?;
+        let denom = num::checked_pow(ten, x.scale() as usize).ok_or(RationalErr::Overflow)?;
+        Ok(Rational::new_unchecked(numer, denom))
+    }
+
+    /// Decimal with scale digits after decimal point, rounded by mode.
+    pub fn to_xdecimal(&self, scale: u32, mode: RoundingMode) -> Result<Xdecimal, XdecimalErr> {
+        let numer = self.numer.to_i128().ok_or(XdecimalErr::Overflow)?;
+        let denom = self.denom.to_i128().ok_or(XdecimalErr::Overflow)?;
+        let numer = Xdecimal::from_i128_with_scale(numer, 0)?;
+        let denom = Xdecimal::from_i128_with_scale(denom, 0)?;
+        Ok(numer.checked_div(denom)?.round_dp(scale, mode))
+    }
+}
+
+impl<T: Clone + Integer + Signed> Add for Rational<T> {
+    type Output = Rational<T>;
+    fn add(self, other: Rational<T>) -> Rational<T> {
+        // a/b + c/d = (a d/g + c b/g) / (b d/g), g = gcd(b, d), keeps intermediate numbers small
+        let g = self.denom.gcd(&other.denom);
+        let b_g = self.denom.clone() / g.clone();
+        let d_g = other.denom / g;
+        Rational::new_unchecked(
+            self.numer * d_g.clone() + other.numer * b_g,
+            self.denom * d_g,
+        )
+    }
+}
+
+impl<T: Clone + Integer + Signed> Sub for Rational<T> {
+    type Output = Rational<T>;
+    fn sub(self, other: Rational<T>) -> Rational<T> {
+        self + (-other)
+    }
+}
+
+impl<T: Clone + Integer + Signed> Mul for Rational<T> {
+    type Output = Rational<T>;
+    fn mul(self, other: Rational<T>) -> Rational<T> {
+        // cross reduce first, (a/b) (c/d) = (a/g1 c/g2) / (b/g2 d/g1)
+        let g1 = self.numer.gcd(&other.denom);
+        let g2 = other.numer.gcd(&self.denom);
+        Rational::new_unchecked(
+            (self.numer / g1.clone()) * (other.numer / g2.clone()),
+            (self.denom / g2) * (other.denom / g1),
+        )
+    }
+}
+
+impl<T: Clone + Integer + Signed> Div for Rational<T> {
+    type Output = Rational<T>;
+    /// Panics if other is zero.
+    fn div(self, other: Rational<T>) -> Rational<T> {
+        if other.is_zero() {
+            panic!("rational division by zero");
+        }
+        // (a/b) / (c/d) = (a/g1 d/g2) / (b/g2 c/g1)
+        let g1 = self.numer.gcd(&other.numer);
+        let g2 = self.denom.gcd(&other.denom);
+        Rational::new_unchecked(
+            (self.numer / g1.clone()) * (other.denom / g2.clone()),
+            (self.denom / g2) * (other.numer / g1),
+        )
+    }
+}
+
+impl<T: Clone + Integer + Signed> Neg for Rational<T> {
+    type Output = Rational<T>;
+    fn neg(self) -> Rational<T> {
+        Rational {
+            numer: -self.numer,
+            denom: self.denom,
+        }
+    }
+}
+
+impl<T: Clone + Integer + Signed> PartialOrd for Rational<T> {
+    fn partial_cmp(&self, other: &Rational<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Clone + Integer + Signed> Ord for Rational<T> {
+    fn cmp(&self, other: &Rational<T>) -> Ordering {
+        // compare continued fraction terms one by one instead of a d <=> c b, which may overflow
+        let (mut a, mut b) = (self.numer.clone(), self.denom.clone());
+        let (mut c, mut d) = (other.numer.clone(), other.denom.clone());
+        let mut reversed = false;
+        loop {
+            let (q1, r1) = a.div_mod_floor(&b);
+            let (q2, r2) = c.div_mod_floor(&d);
+            let ord = match (q1.cmp(&q2), r1.is_zero(), r2.is_zero()) {
+                (Ordering::Equal, true, true) => Ordering::Equal,
+                (Ordering::Equal, true, false) => Ordering::Less,
+                (Ordering::Equal, false, true) => Ordering::Greater,
+                (Ordering::Equal, false, false) => {
+                    // a/b = q + r1/b, compare b/r1 and d/r2 in reversed order
+                    a = std::mem::replace(&mut b, r1);
+                    c = std::mem::replace(&mut d, r2);
+                    reversed = !reversed;
+                    continue;
+                }
+                (ord, _, _) => ord,
+            };
+            return if reversed { ord.reverse() } else { ord };
+        }
+    }
+}
+
+impl<T: Clone + Integer + Signed> Zero for Rational<T> {
+    fn zero() -> Rational<T> {
+        Rational::from_integer(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.numer.is_zero()
+    }
+}
+
+impl<T: Clone + Integer + Signed> Default for Rational<T> {
+    fn default() -> Rational<T> {
+        Rational::zero()
+    }
+}
+
+impl<T: Clone + Integer + Signed> One for Rational<T> {
+    fn one() -> Rational<T> {
+        Rational::from_integer(T::one())
+    }
+}
+
+impl<T: fmt::Display + One + PartialEq> fmt::Display for Rational<T> {
+    /// "numer/denom", or only numer if denom is 1.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.denom.is_one() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+impl<T: Clone + Integer + Signed + CheckedMul + FromStr> FromStr for Rational<T> {
+    type Err = RationalErr;
+    /// Parse "numer/denom" or "integer", such as "-3/4" and "5".
+    fn from_str(s: &str) -> Result<Rational<T>, RationalErr> {
+        let mut parts = s.trim().splitn(2, '/');
+        let numer = parts.next().unwrap_or("");
+        let numer = T::from_str(numer.trim()).map_err(|_| RationalErr::Parse)?;
+        match parts.next() {
+            Some(denom) => {
+                let denom = T::from_str(denom.trim()).map_err(|_| RationalErr::Parse)?;
+                Rational::new(numer, denom)
+            }
+            None => Ok(Rational::from_integer(numer)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn r(n: i64, d: i64) -> Rational64 {
+        Rational::new(n, d).unwrap()
+    }
+
+    #[test]
+    fn rational_normalize_test() {
+        assert_eq!(r(2, -4), r(-1, 2));
+        assert_eq!(*r(2, -4).numer(), -1);
+        assert_eq!(*r(2, -4).denom(), 2);
+        assert_eq!(r(0, -5), Rational64::zero());
+        assert_eq!(Rational64::new(1, 0), Err(RationalErr::ZeroDenominator));
+        assert!(r(6, 3).is_integer());
+
+        // i64::MIN has no positive counterpart
+        assert_eq!(Rational64::new(i64::MIN, -1), Err(RationalErr::Overflow));
+        assert_eq!(Rational64::new(1, i64::MIN), Err(RationalErr::Overflow));
+        assert_eq!(r(i64::MIN, -2), r(1 << 62, 1));
+        assert_eq!(r(i64::MIN, 1), Rational64::from_integer(i64::MIN));
+        assert_eq!(r(0, i64::MIN), Rational64::zero());
+        assert_eq!(r(i64::MIN, i64::MIN), Rational64::one());
+        assert_eq!(r(i64::MIN, 4).recip(), Ok(r(-1, 1 << 61)));
+    }
+
+    #[test]
+    fn rational_arithmetic_test() {
+        assert_eq!(r(1, 6) + r(1, 3), r(1, 2));
+        assert_eq!(r(1, 6) - r(1, 3), r(-1, 6));
+        assert_eq!(r(2, 3) * r(9, 4), r(3, 2));
+        assert_eq!(r(2, 3) / r(-4, 9), r(-3, 2));
+        assert_eq!(r(2, 3).pow(-2), Ok(r(9, 4)));
+        assert_eq!(
+            Rational64::zero().pow(-1),
+            Err(RationalErr::ZeroDenominator)
+        );
+        assert_eq!(r(-7, 2).floor(), -4);
+        assert_eq!(r(-7, 2).ceil(), -3);
+        assert_eq!(r(-7, 2).trunc(), -3);
+
+        // sum of 1/k for k = 1..=20 with big integers
+        let h = (1..=20).fold(BigRational::zero(), |acc, k| {
+            acc + BigRational::new(BigInt::one(), BigInt::from(k)).unwrap()
+        });
+        assert_eq!(h.to_string(), "55835135/15519504");
+    }
+
+    #[test]
+    fn rational_ord_test() {
+        assert!(r(1, 3) < r(1, 2));
+        assert!(r(-1, 2) < r(-1, 3));
+        // cross multiplication would overflow i64
+        assert!(r(i64::MAX - 1, i64::MAX) > r(i64::MAX - 2, i64::MAX - 1));
+        assert!(r(-i64::MAX, i64::MAX - 1) > r(-(i64::MAX - 1), i64::MAX - 2));
+        let mut v = vec![r(3, 4), r(-1, 2), r(2, 3), r(0, 1)];
+        v.sort();
+        assert_eq!(v, vec![r(-1, 2), r(0, 1), r(2, 3), r(3, 4)]);
+    }
+
+    #[test]
+    fn rational_string_test() {
+        assert_eq!(r(-3, 4).to_string(), "-3/4");
+        assert_eq!(r(4, 2).to_string(), "2");
+        assert_eq!(Rational64::from_str(" 6 / -8 "), Ok(r(-3, 4)));
+        assert_eq!(Rational64::from_str("5"), Ok(r(5, 1)));
+        assert_eq!(
+            Rational64::from_str("1/0"),
+            Err(RationalErr::ZeroDenominator)
+        );
+        assert_eq!(Rational64::from_str("a/2"), Err(RationalErr::Parse));
+    }
+
+    #[test]
+    fn rational_f64_test() {
+        assert_eq!(Rational64::from_f64(0.375), Ok(r(3, 8)));
+        assert_eq!(Rational64::from_f64(-2.0), Ok(r(-2, 1)));
+        assert_eq!(
+            Rational64::from_f64(0.1),
+            Ok(r(3602879701896397, 36028797018963968))
+        );
+        assert_eq!(Rational64::from_f64(1e300), Err(RationalErr::Overflow));
+        assert_eq!(Rational64::from_f64(f64::NAN), Err(RationalErr::NotFinite));
+        let tiny = BigRational::from_f64(f64::MIN_POSITIVE / 4.0).unwrap();
+        assert_eq!(*tiny.numer(), BigInt::one());
+        assert_eq!(*tiny.denom(), num::pow(BigInt::from(2), 1024));
+        // (2^2000 + 1) / 2^2000 is beyond f64 range on both sides
+        let huge = BigRational::from_integer(num::pow(BigInt::from(2), 2000));
+        assert_eq!(((huge.clone() + BigRational::one()) / huge).to_f64(), 1.0);
+        assert_eq!(r(1, 3).to_f64(), 1.0 / 3.0);
+        assert_eq!(r(-7, 2).to_f64(), -3.5);
+        // i64 operands beyond 2^53 are rounded once, not before dividing
+        assert_eq!(
+            r(3274088665612346123, 2258848922518918982).to_f64(),
+            1.4494500419980716
+        );
+        let two = |e: usize| num::pow(BigInt::from(2), e);
+        let big = |n: BigInt, d: BigInt| BigRational::new(n, d).unwrap();
+        assert_eq!(
+            big(two(1100) + BigInt::one(), num::pow(BigInt::from(3), 700)).to_f64(),
+            0.0014064261301652214
+        );
+        assert_eq!(
+            big(two(1026), BigInt::from(5)).to_f64(),
+            1.4381545078898528e308
+        );
+        assert_eq!(big(two(1024), BigInt::one()).to_f64(), f64::INFINITY);
+        // subnormal results, 2^-1075 is a tie and rounds to even 0
+        assert_eq!(big(BigInt::from(3), two(1076)).to_f64(), 5e-324);
+        assert_eq!(big(BigInt::from(3), two(1075)).to_f64(), 1e-323);
+        assert_eq!(big(BigInt::one(), two(1075)).to_f64(), 0.0);
+        assert_eq!(big(BigInt::one(), two(1022)).to_f64(), f64::MIN_POSITIVE);
+        // 2^53 + 1 is a tie between 2^53 and 2^53 + 2
+        assert_eq!(
+            big(two(53) + BigInt::one(), BigInt::one()).to_f64(),
+            9007199254740992.0
+        );
+        assert_eq!(
+            big(two(54) + BigInt::from(3), BigInt::from(2)).to_f64(),
+            9007199254740994.0
+        );
+    }
+
+    #[test]
+    fn rational_xdecimal_test() {
+        let x = Xdecimal::from_str("-12.375").unwrap();
+        assert_eq!(Rational64::from_xdecimal(x), Ok(r(-99, 8)));
+        assert_eq!(
+            r(2, 3)
+                .to_xdecimal(4, RoundingMode::HalfEven)
+                .unwrap()
+                .to_string(),
+            "0.6667"
+        );
+        assert_eq!(
+            r(-2, 3)
+                .to_xdecimal(2, RoundingMode::Truncate)
+                .unwrap()
+                .to_string(),
+            "-0.66"
+        );
+    }
+
+    #[test]
+    fn continued_fraction_test() {
+        // 415/93 = [4; 2, 6, 7]
+        let x = r(415, 93);
+        assert_eq!(x.continued_fraction(), vec![4, 2, 6, 7]);
+        assert_eq!(Rational::from_continued_fraction(&[4, 2, 6, 7]), Some(x));
+        assert_eq!(r(-7, 2).continued_fraction(), vec![-4, 2]);
+        assert_eq!(
+            x.convergents(),
+            vec![r(4, 1), r(9, 2), r(58, 13), r(415, 93)]
+        );
+        assert_eq!(Rational64::from_continued_fraction(&[]), None);
+    }
+
+    #[test]
+    fn best_approximation_test() {
+        let pi = Rational64::from_f64(std::f64::consts::PI).unwrap();
+        assert_eq!(pi.best_approximation(&1), r(3, 1));
+        assert_eq!(pi.best_approximation(&10), r(22, 7));
+        assert_eq!(pi.best_approximation(&100), r(311, 99)); // semiconvergent
+        assert_eq!(pi.best_approximation(&1000), r(355, 113));
+        assert_eq!(r(1, 3).best_approximation(&1000), r(1, 3));
+
+        // brute force check
+        let x = r(8119, 25000);
+        for max_denom in 1..60i64 {
+            let best = x.best_approximation(&max_denom);
+            for d in 1..=max_denom {
+                let n = (x * r(d, 1)).floor();
+                for candidate in [r(n, d), r(n + 1, d)].iter() {
+                    assert!((best - x).abs() <= (*candidate - x).abs());
+                }
+            }
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::galois_field::field::GaloisField;
+
 // TODO:
 // how to find all irreducible polynomials
 // how to find all primitive polynomials
@@ -6,3 +8,240 @@
 pub fn all_primitive_polynomials(src: u8) -> Vec<u8> {
     vec![]
 }
+
+/*
+Polynomials over any galois field.
+
+A polynomial is a coefficient vector from low degree to high degree, [1, 0, 2] means $2x^2 + 1$.
+Results are always trimmed, so the zero polynomial is an empty vector.
+*/
+
+/// Coefficients of polynomial over field F, from low degree to high degree.
+pub type Polynomial<F> = Vec<<F as GaloisField>::Element>;
+
+/// Remove zero coefficients of high degree terms.
+pub fn poly_trim<F: GaloisField>(gf: &F, a: &mut Vec<F::Element>) {
+    while let Some(c) = a.last() {
+        if !gf.is_zero(c) {
+            break;
+        }
+        a.pop();
+    }
+}
+
+/// Degree of polynomial, None for the zero polynomial.
+pub fn poly_degree<F: GaloisField>(gf: &F, a: &[F::Element]) -> Option<usize> {
+    a.iter().rposition(|c| !gf.is_zero(c))
+}
+
+pub fn poly_add<F: GaloisField>(gf: &F, a: &[F::Element], b: &[F::Element]) -> Polynomial<F> {
+    let mut res: Vec<F::Element> = (0..a.len().max(b.len()))
+        .map(|i| match (a.get(i), b.get(i)) {
+            (Some(x), Some(y)) => gf.add(x.clone(), y.clone()),
+            (Some(x), None) => x.clone(),
+            (None, Some(y)) => y.clone(),
+            (None, None) => gf.zero(),
+        })
+        .collect();
+    poly_trim(gf, &mut res);
+    res
+}
+
+pub fn poly_sub<F: GaloisField>(gf: &F, a: &[F::Element], b: &[F::Element]) -> Polynomial<F> {
+    let neg_b: Vec<F::Element> = b.iter().map(|c| gf.neg(c.clone())).collect();
+    poly_add(gf, a, &neg_b)
+}
+
+/// Multiply every coefficient by scalar c.
+pub fn poly_scale<F: GaloisField>(gf: &F, a: &[F::Element], c: F::Element) -> Polynomial<F> {
+    let mut res: Vec<F::Element> = a.iter().map(|x| gf.mul(x.clone(), c.clone())).collect();
+    poly_trim(gf, &mut res);
+    res
+}
+
+pub fn poly_mul<F: GaloisField>(gf: &F, a: &[F::Element], b: &[F::Element]) -> Polynomial<F> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let mut res = vec![gf.zero(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        if gf.is_zero(x) {
+            continue;
+        }
+        for (j, y) in b.iter().enumerate() {
+            let term = gf.mul(x.clone(), y.clone());
+            res[i + j] = gf.add(res[i + j].clone(), term);
+        }
+    }
+    poly_trim(gf, &mut res);
+    res
+}
+
+/// Long division, returns (quotient, remainder), panics if b is the zero polynomial.
+pub fn poly_div_rem<F: GaloisField>(
+    gf: &F,
+    a: &[F::Element],
+    b: &[F::Element],
+) -> (Polynomial<F>, Polynomial<F>) {
+    let deg_b = poly_degree(gf, b).expect("polynomial division by zero");
+    let lead_inv = gf.inv(b[deg_b].clone());
+
+    let mut rem = a.to_vec();
+    poly_trim(gf, &mut rem);
+    if rem.len() <= deg_b {
+        return (vec![], rem);
+    }
+    let mut quo = vec![gf.zero(); rem.len() - deg_b];
+    for i in (0..quo.len()).rev() {
+        let c = gf.mul(rem[i + deg_b].clone(), lead_inv.clone());
+        if gf.is_zero(&c) {
+            continue;
+        }
+        for (j, y) in b[..=deg_b].iter().enumerate() {
+            let term = gf.mul(c.clone(), y.clone());
+            rem[i + j] = gf.sub(rem[i + j].clone(), term);
+        }
+        quo[i] = c;
+    }
+    poly_trim(gf, &mut quo);
+    poly_trim(gf, &mut rem);
+    (quo, rem)
+}
+
+/// Make leading coefficient 1, the zero polynomial stays zero.
+pub fn poly_monic<F: GaloisField>(gf: &F, a: &[F::Element]) -> Polynomial<F> {
+    match poly_degree(gf, a) {
+        Some(d) => poly_scale(gf, a, gf.inv(a[d].clone())),
+        None => vec![],
+    }
+}
+
+/// Monic greatest common divisor.
+pub fn poly_gcd<F: GaloisField>(gf: &F, a: &[F::Element], b: &[F::Element]) -> Polynomial<F> {
+    let (g, _, _) = poly_ext_gcd(gf, a, b);
+    g
+}
+
+/**
+Extended Euclidean algorithm, returns (g, s, t) with monic g = gcd(a, b) and
+
+$$
+a s + b t = g
+$$
+*/
+pub fn poly_ext_gcd<F: GaloisField>(
+    gf: &F,
+    a: &[F::Element],
+    b: &[F::Element],
+) -> (Polynomial<F>, Polynomial<F>, Polynomial<F>) {
+    let mut old_r = a.to_vec();
+    let mut r = b.to_vec();
+    poly_trim(gf, &mut old_r);
+    poly_trim(gf, &mut r);
+    let (mut old_s, mut s) = (vec![gf.one()], vec![]);
+    let (mut old_t, mut t) = (vec![], vec![gf.one()]);
+    while !r.is_empty() {
+        let (q, rem) = poly_div_rem(gf, &old_r, &r);
+        old_r = std::mem::replace(&mut r, rem);
+        let new_s = poly_sub(gf, &old_s, &poly_mul(gf, &q, &s));
+        old_s = std::mem::replace(&mut s, new_s);
+        let new_t = poly_sub(gf, &old_t, &poly_mul(gf, &q, &t));
+        old_t = std::mem::replace(&mut t, new_t);
+    }
+
+    match poly_degree(gf, &old_r) {
+        Some(d) => {
+            let lead_inv = gf.inv(old_r[d].clone());
+            (
+                poly_scale(gf, &old_r, lead_inv.clone()),
+                poly_scale(gf, &old_s, lead_inv.clone()),
+                poly_scale(gf, &old_t, lead_inv),
+            )
+        }
+        None => (vec![], vec![], vec![]),
+    }
+}
+
+/// Evaluate polynomial at x by Horner's method.
+pub fn poly_eval<F: GaloisField>(gf: &F, a: &[F::Element], x: F::Element) -> F::Element {
+    a.iter().rev().fold(gf.zero(), |acc, c| {
+        gf.add(gf.mul(acc, x.clone()), c.clone())
+    })
+}
+
+/// $a^e \mod m$ by square-and-multiply.
+pub fn poly_pow_mod<F: GaloisField>(
+    gf: &F,
+    a: &[F::Element],
+    e: u64,
+    m: &[F::Element],
+) -> Polynomial<F> {
+    let (_, mut base) = poly_div_rem(gf, a, m);
+    let (_, mut res) = poly_div_rem(gf, &[gf.one()], m);
+    let mut e = e;
+    while e > 0 {
+        if e & 1 == 1 {
+            res = poly_div_rem(gf, &poly_mul(gf, &res, &base), m).1;
+        }
+        base = poly_div_rem(gf, &poly_mul(gf, &base, &base), m).1;
+        e >>= 1;
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::galois_field::gf_u8::Gf2p;
+    use crate::galois_field::gfp::Gfp;
+
+    #[test]
+    fn poly_mul_div_rem_test() {
+        let gf = Gfp::new(7).unwrap();
+        // (x + 1)(x + 2) = x^2 + 3x + 2
+        let a = poly_mul(&gf, &[1, 1], &[2, 1]);
+        assert_eq!(a, vec![2, 3, 1]);
+
+        let (q, r) = poly_div_rem(&gf, &[3, 3, 1], &[1, 1]);
+        assert_eq!(q, vec![2, 1]);
+        assert_eq!(r, vec![1]);
+
+        assert_eq!(poly_sub(&gf, &a, &a), Vec::<u64>::new());
+        assert_eq!(poly_degree(&gf, &[1, 0, 0]), Some(0));
+        assert_eq!(poly_degree(&gf, &[0, 0]), None);
+    }
+
+    #[test]
+    fn poly_gcd_test() {
+        let gf = Gfp::new(5).unwrap();
+        // gcd((x + 1)(x + 2), (x + 1)(x + 3)) = x + 1
+        let a = poly_mul(&gf, &[1, 1], &[2, 1]);
+        let b = poly_mul(&gf, &[1, 1], &[3, 1]);
+        let (g, s, t) = poly_ext_gcd(&gf, &a, &b);
+        assert_eq!(g, vec![1, 1]);
+        let combination = poly_add(&gf, &poly_mul(&gf, &a, &s), &poly_mul(&gf, &b, &t));
+        assert_eq!(combination, g);
+
+        // 2x + 2 is made monic
+        assert_eq!(poly_gcd(&gf, &[2, 2], &[0]), vec![1, 1]);
+    }
+
+    #[test]
+    fn poly_eval_test() {
+        let gf = Gfp::new(11).unwrap();
+        // 2x^2 + 1 at 3 = 19 = 8 mod 11
+        assert_eq!(poly_eval(&gf, &[1, 0, 2], 3), 8);
+
+        let gf = Gf2p::new(8, 0x1D).unwrap();
+        // x + 1 at x = 1 on GF(2^8) is 0
+        assert_eq!(poly_eval(&gf, &[1, 1], 1), 0);
+    }
+
+    #[test]
+    fn poly_pow_mod_test() {
+        let gf = Gfp::new(3).unwrap();
+        // on GF(3)[x] / (x^2 + 1), x^2 = -1, so x^4 = 1
+        assert_eq!(poly_pow_mod(&gf, &[0, 1], 2, &[1, 0, 1]), vec![2]);
+        assert_eq!(poly_pow_mod(&gf, &[0, 1], 4, &[1, 0, 1]), vec![1]);
+    }
+}
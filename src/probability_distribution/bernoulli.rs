@@ -0,0 +1,84 @@
+/*!
+Bernoulli distribution, 1 with probability p and 0 with probability 1 - p.
+*/
+use crate::probability_distribution::{search_quantile, DiscreteDistribution, DistributionErr};
+use rand::Rng;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bernoulli {
+    p: f64,
+}
+
+impl Bernoulli {
+    /// Error unless p is in [0, 1].
+    pub fn new(p: f64) -> Result<Bernoulli, DistributionErr> {
+        if !(0.0..=1.0).contains(&p) {
+            return Err(DistributionErr::InvalidParameter);
+        }
+        Ok(Bernoulli { p })
+    }
+
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+}
+
+impl DiscreteDistribution for Bernoulli {
+    fn ln_pmf(&self, k: u64) -> f64 {
+        match k {
+            0 => (-self.p).ln_1p(),
+            1 => self.p.ln(),
+            _ => f64::NEG_INFINITY,
+        }
+    }
+
+    fn cdf(&self, k: u64) -> f64 {
+        if k == 0 {
+            1.0 - self.p
+        } else {
+            1.0
+        }
+    }
+
+    fn quantile(&self, p: f64) -> Result<u64, DistributionErr> {
+        search_quantile(self, p, 0, 1)
+    }
+
+    fn mean(&self) -> f64 {
+        self.p
+    }
+
+    fn variance(&self) -> f64 {
+        self.p * (1.0 - self.p)
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        (rng.gen::<f64>() < self.p) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn bernoulli_test() {
+        let b = Bernoulli::new(0.25).unwrap();
+        assert_eq!((b.pmf(0), b.pmf(1), b.pmf(2)), (0.75, 0.25, 0.0));
+        assert_eq!((b.cdf(0), b.cdf(1), b.sf(0)), (0.75, 1.0, 0.25));
+        assert_eq!(b.quantile(0.75), Ok(0));
+        assert_eq!(b.quantile(0.76), Ok(1));
+        assert_eq!((b.mean(), b.variance()), (0.25, 0.1875));
+
+        let mut rng = StdRng::seed_from_u64(17);
+        let ones: u64 = (0..10_000).map(|_| b.sample(&mut rng)).sum();
+        assert!((2300..2700).contains(&ones), "{}", ones);
+        // degenerate ones
+        assert_eq!(Bernoulli::new(0.0).unwrap().sample(&mut rng), 0);
+        assert_eq!(Bernoulli::new(1.0).unwrap().sample(&mut rng), 1);
+
+        assert_eq!(Bernoulli::new(1.5), Err(DistributionErr::InvalidParameter));
+    }
+}
@@ -0,0 +1,106 @@
+/*!
+Beta distribution with shapes $\alpha$ and $\beta$, on [0, 1]
+$$
+f(x) = \frac{x^{\alpha-1} (1-x)^{\beta-1}}{B(\alpha, \beta)}, \quad F(x) = I_x(\alpha, \beta)
+$$
+*/
+use crate::probability_distribution::gamma::standard_gamma_sample;
+use crate::probability_distribution::{
+    check_positive, numeric_inverse_cdf, xlnx, ContinuousDistribution, DistributionErr,
+};
+use crate::special_function::{ln_beta, regularized_beta};
+use rand::Rng;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Beta {
+    alpha: f64,
+    beta: f64,
+}
+
+impl Beta {
+    /// Error unless both shapes are positive and finite.
+    pub fn new(alpha: f64, beta: f64) -> Result<Beta, DistributionErr> {
+        check_positive(&[alpha, beta])?;
+        Ok(Beta { alpha, beta })
+    }
+
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    pub fn beta(&self) -> f64 {
+        self.beta
+    }
+}
+
+impl ContinuousDistribution for Beta {
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            return f64::NEG_INFINITY;
+        }
+        let (a, b) = (self.alpha, self.beta);
+        xlnx(a - 1.0, x) + xlnx(b - 1.0, 1.0 - x) - ln_beta(a, b)
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        regularized_beta(self.alpha, self.beta, x.clamp(0.0, 1.0))
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        regularized_beta(self.beta, self.alpha, (1.0 - x).clamp(0.0, 1.0))
+    }
+
+    fn inverse_cdf(&self, p: f64) -> Result<f64, DistributionErr> {
+        numeric_inverse_cdf(self, p, 0.0, 1.0)
+    }
+
+    fn mean(&self) -> f64 {
+        self.alpha / (self.alpha + self.beta)
+    }
+
+    fn variance(&self) -> f64 {
+        let sum = self.alpha + self.beta;
+        self.alpha * self.beta / (sum * sum * (sum + 1.0))
+    }
+
+    /// Random value by $X / (X + Y)$ of gamma distributed X and Y.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let x = standard_gamma_sample(self.alpha, rng);
+        let y = standard_gamma_sample(self.beta, rng);
+        x / (x + y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::probability_distribution::assert_close;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn beta_test() {
+        // mpmath.betainc(2.5, 4, 0, x, regularized=True) and mpmath.findroot
+        let b = Beta::new(2.5, 4.0).unwrap();
+        assert_close(b.pdf(0.3), 2.03426725306907, 1e-13);
+        assert_close(b.cdf(0.3), 0.35219758590676724, 1e-13);
+        assert_close(b.sf(0.3), 1.0 - 0.35219758590676724, 1e-13);
+        assert_close(b.inverse_cdf(0.9).unwrap(), 0.6301815250966563, 1e-13);
+        assert_eq!((b.cdf(-1.0), b.cdf(2.0)), (0.0, 1.0));
+        assert_eq!(b.pdf(1.5), 0.0);
+        assert_close(b.mean(), 2.5 / 6.5, 1e-15);
+
+        // Beta(1, 1) is uniform, and density is infinite at 0 for alpha < 1
+        let u = Beta::new(1.0, 1.0).unwrap();
+        assert_close(u.pdf(0.0), 1.0, 1e-14);
+        assert_close(u.inverse_cdf(0.25).unwrap(), 0.25, 1e-14);
+        assert_eq!(Beta::new(0.5, 0.5).unwrap().pdf(0.0), f64::INFINITY);
+
+        let mut rng = StdRng::seed_from_u64(5);
+        let n = 100_000;
+        let mean = (0..n).map(|_| b.sample(&mut rng)).sum::<f64>() / n as f64;
+        assert!((mean - b.mean()).abs() < 0.005, "{}", mean);
+
+        assert_eq!(Beta::new(1.0, 0.0), Err(DistributionErr::InvalidParameter));
+    }
+}
@@ -0,0 +1,116 @@
+/*!
+Binomial distribution, number of successes in n independent trials with success probability p
+$$
+P(X = k) = \binom{n}{k} p^k (1-p)^{n-k}, \quad F(k) = I_{1-p}(n - k, k + 1)
+$$
+*/
+use crate::probability_distribution::{search_quantile, DiscreteDistribution, DistributionErr};
+use crate::special_function::{ln_binomial_pmf, regularized_beta};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Binomial {
+    n: u64,
+    p: f64,
+}
+
+impl Binomial {
+    /// Error unless p is in [0, 1].
+    pub fn new(n: u64, p: f64) -> Result<Binomial, DistributionErr> {
+        if !(0.0..=1.0).contains(&p) {
+            return Err(DistributionErr::InvalidParameter);
+        }
+        Ok(Binomial { n, p })
+    }
+
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+}
+
+impl DiscreteDistribution for Binomial {
+    /// Loader's saddle point expansion, accurate for large n.
+    fn ln_pmf(&self, k: u64) -> f64 {
+        if k > self.n {
+            return f64::NEG_INFINITY;
+        }
+        ln_binomial_pmf(k as f64, self.n as f64, self.p, 1.0 - self.p)
+    }
+
+    fn cdf(&self, k: u64) -> f64 {
+        if k >= self.n {
+            return 1.0;
+        }
+        regularized_beta((self.n - k) as f64, k as f64 + 1.0, 1.0 - self.p)
+    }
+
+    fn sf(&self, k: u64) -> f64 {
+        if k >= self.n {
+            return 0.0;
+        }
+        regularized_beta(k as f64 + 1.0, (self.n - k) as f64, self.p)
+    }
+
+    fn quantile(&self, p: f64) -> Result<u64, DistributionErr> {
+        search_quantile(self, p, 0, self.n)
+    }
+
+    fn mean(&self) -> f64 {
+        self.n as f64 * self.p
+    }
+
+    fn variance(&self) -> f64 {
+        self.n as f64 * self.p * (1.0 - self.p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::probability_distribution::assert_close;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn binomial_test() {
+        // mpmath.binomial(n, k) * p**k * (1 - p)**(n - k) and its sums
+        let b = Binomial::new(10, 0.3).unwrap();
+        assert_close(b.pmf(3), 0.266827932, 1e-14);
+        assert_close(b.ln_pmf(3), -1.3211512777668886, 1e-14);
+        assert_close(b.cdf(3), 0.6496107184, 1e-14);
+        assert_close(b.sf(3), 1.0 - 0.6496107184, 1e-14);
+        assert_close(b.pmf(0), 0.7f64.powi(10), 1e-14);
+        assert_close(b.pmf(10), 0.3f64.powi(10), 1e-14);
+        assert_eq!((b.pmf(11), b.cdf(10), b.sf(10)), (0.0, 1.0, 0.0));
+        assert_close((0..=10).map(|k| b.pmf(k)).sum::<f64>(), 1.0, 1e-14);
+        assert_eq!(b.quantile(0.6496), Ok(3));
+        assert_eq!(b.quantile(0.65), Ok(4));
+        assert_eq!((b.quantile(0.0), b.quantile(1.0)), (Ok(0), Ok(10)));
+        assert_close(b.variance(), 2.1, 1e-15);
+
+        // log-space PMF keeps relative accuracy for large n
+        let b = Binomial::new(1_000_000, 0.5).unwrap();
+        assert_close(b.ln_pmf(500_000), -7.133546881626865, 1e-14);
+        let b = Binomial::new(1_000_000, 1e-5).unwrap();
+        assert_close(b.ln_pmf(10), -2.0785566431108916, 1e-14);
+
+        // degenerate ones
+        assert_eq!(Binomial::new(5, 0.0).unwrap().pmf(0), 1.0);
+        assert_eq!(Binomial::new(5, 1.0).unwrap().pmf(4), 0.0);
+        assert_eq!(Binomial::new(0, 0.5).unwrap().pmf(0), 1.0);
+
+        let b = Binomial::new(20, 0.4).unwrap();
+        let mut rng = StdRng::seed_from_u64(19);
+        let n = 20_000;
+        let mean = (0..n).map(|_| b.sample(&mut rng)).sum::<u64>() as f64 / n as f64;
+        assert!((mean - 8.0).abs() < 0.1, "{}", mean);
+
+        assert_eq!(
+            Binomial::new(10, f64::NAN),
+            Err(DistributionErr::InvalidParameter)
+        );
+    }
+}
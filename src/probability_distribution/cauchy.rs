@@ -0,0 +1,101 @@
+/*!
+Cauchy distribution with location $x_0$ and scale $\gamma$
+$$
+f(x) = \frac{1}{\pi \gamma (1 + z^2)}, \quad F(x) = \frac{1}{2} + \frac{\arctan z}{\pi}, \quad z = \frac{x - x_0}{\gamma}
+$$
+Its mean and variance are undefined.
+*/
+use crate::probability_distribution::{check_probability, ContinuousDistribution, DistributionErr};
+use std::f64::consts::PI;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cauchy {
+    location: f64,
+    scale: f64,
+}
+
+impl Cauchy {
+    /// Error if location is not finite or scale is not positive and finite.
+    pub fn new(location: f64, scale: f64) -> Result<Cauchy, DistributionErr> {
+        if !location.is_finite() || !scale.is_finite() || scale <= 0.0 {
+            return Err(DistributionErr::InvalidParameter);
+        }
+        Ok(Cauchy { location, scale })
+    }
+
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+/// CDF of standard Cauchy distribution, $\arctan(-1/z) / \pi$ in the lower tail avoids cancellation.
+fn standard_cdf(z: f64) -> f64 {
+    if z < -1.0 {
+        (-1.0 / z).atan() / PI
+    } else {
+        0.5 + z.atan() / PI
+    }
+}
+
+impl ContinuousDistribution for Cauchy {
+    fn ln_pdf(&self, x: f64) -> f64 {
+        let z = (x - self.location) / self.scale;
+        -(PI * self.scale).ln() - (z * z).ln_1p()
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        standard_cdf((x - self.location) / self.scale)
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        standard_cdf((self.location - x) / self.scale)
+    }
+
+    fn inverse_cdf(&self, p: f64) -> Result<f64, DistributionErr> {
+        check_probability(p)?;
+        Ok(if p == 0.0 {
+            f64::NEG_INFINITY
+        } else if p == 1.0 {
+            f64::INFINITY
+        } else {
+            self.location + self.scale * (PI * (p - 0.5)).tan()
+        })
+    }
+
+    fn mean(&self) -> f64 {
+        f64::NAN
+    }
+
+    fn variance(&self) -> f64 {
+        f64::NAN
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::probability_distribution::assert_close;
+
+    #[test]
+    fn cauchy_test() {
+        let c = Cauchy::new(1.0, 2.0).unwrap();
+        assert_close(c.pdf(2.0), 0.12732395447351627, 1e-14);
+        assert_eq!(c.cdf(1.0), 0.5);
+        assert_close(c.cdf(3.0), 0.75, 1e-15);
+        assert_close(c.sf(3.0), 0.25, 1e-15);
+        // mpmath: 0.5 + atan((x - 1) / 2) / pi
+        assert_close(c.cdf(-1e10), 6.366197723039194e-11, 1e-14);
+        assert_close(c.sf(2.0 + 1e10), 6.366197723039194e-11, 1e-14);
+        assert_close(c.inverse_cdf(0.75).unwrap(), 3.0, 1e-14);
+        assert_eq!(c.inverse_cdf(0.0), Ok(f64::NEG_INFINITY));
+        assert!(c.mean().is_nan() && c.variance().is_nan());
+        assert_eq!(
+            Cauchy::new(0.0, 0.0),
+            Err(DistributionErr::InvalidParameter)
+        );
+    }
+}
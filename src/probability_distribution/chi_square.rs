@@ -0,0 +1,85 @@
+/*!
+Chi-square distribution with k degrees of freedom, sum of squares of k standard normal values.
+
+It's gamma distribution with shape k / 2 and scale 2
+$$
+f(x) = \frac{x^{k/2-1} e^{-x/2}}{2^{k/2} \Gamma(k/2)}
+$$
+*/
+use crate::probability_distribution::gamma::Gamma;
+use crate::probability_distribution::{check_positive, ContinuousDistribution, DistributionErr};
+use rand::Rng;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChiSquare {
+    df: f64,
+    gamma: Gamma,
+}
+
+impl ChiSquare {
+    /// Error unless degrees of freedom is positive and finite, it doesn't need to be an integer.
+    pub fn new(df: f64) -> Result<ChiSquare, DistributionErr> {
+        check_positive(&[df])?;
+        Ok(ChiSquare {
+            df,
+            gamma: Gamma::new(0.5 * df, 2.0)?,
+        })
+    }
+
+    pub fn df(&self) -> f64 {
+        self.df
+    }
+}
+
+impl ContinuousDistribution for ChiSquare {
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.gamma.ln_pdf(x)
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        self.gamma.cdf(x)
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        self.gamma.sf(x)
+    }
+
+    fn inverse_cdf(&self, p: f64) -> Result<f64, DistributionErr> {
+        self.gamma.inverse_cdf(p)
+    }
+
+    fn mean(&self) -> f64 {
+        self.df
+    }
+
+    fn variance(&self) -> f64 {
+        2.0 * self.df
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.gamma.sample(rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::probability_distribution::assert_close;
+
+    #[test]
+    fn chi_square_test() {
+        // mpmath.gammainc(1.5, 0, x / 2, regularized=True) and mpmath.findroot
+        let c = ChiSquare::new(3.0).unwrap();
+        assert_close(c.pdf(2.0), 0.20755374871029734, 1e-13);
+        assert_close(c.cdf(2.0), 0.4275932955291202, 1e-13);
+        assert_close(c.inverse_cdf(0.95).unwrap(), 7.81472790325118, 1e-13);
+        assert_close(c.sf(7.81472790325118), 0.05, 1e-12);
+        assert_eq!((c.mean(), c.variance()), (3.0, 6.0));
+
+        // 2 degrees of freedom is exponential distribution with rate 1/2
+        let c = ChiSquare::new(2.0).unwrap();
+        assert_close(c.sf(3.0), (-1.5f64).exp(), 1e-14);
+
+        assert_eq!(ChiSquare::new(-1.0), Err(DistributionErr::InvalidParameter));
+    }
+}
@@ -0,0 +1,113 @@
+/*!
+Exponential distribution with rate $\lambda$, on $[0, \infty)$
+$$
+f(x) = \lambda e^{-\lambda x}, \quad F(x) = 1 - e^{-\lambda x}
+$$
+*/
+use crate::probability_distribution::{
+    check_data, check_positive, check_probability, ContinuousDistribution, DistributionErr, Fit,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Exponential {
+    rate: f64,
+}
+
+impl Exponential {
+    /// Error unless rate is positive and finite.
+    pub fn new(rate: f64) -> Result<Exponential, DistributionErr> {
+        check_positive(&[rate])?;
+        Ok(Exponential { rate })
+    }
+
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// Maximum likelihood fit, rate is the reciprocal of mean, error if any value is negative.
+    pub fn fit(data: &[f64]) -> Result<Fit<Exponential>, DistributionErr> {
+        check_data(data, |x| x >= 0.0)?;
+        let mean = data.iter().sum::<f64>() / data.len() as f64;
+        let exponential = Exponential::new(1.0 / mean).map_err(|_| DistributionErr::InvalidData)?;
+        Ok(Fit::new(exponential, data, 1))
+    }
+}
+
+impl ContinuousDistribution for Exponential {
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        self.rate.ln() - self.rate * x
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        -(-self.rate * x).exp_m1()
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 1.0;
+        }
+        (-self.rate * x).exp()
+    }
+
+    fn inverse_cdf(&self, p: f64) -> Result<f64, DistributionErr> {
+        check_probability(p)?;
+        Ok(-(-p).ln_1p() / self.rate)
+    }
+
+    fn mean(&self) -> f64 {
+        1.0 / self.rate
+    }
+
+    fn variance(&self) -> f64 {
+        1.0 / (self.rate * self.rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exponential_test() {
+        let e = Exponential::new(2.0).unwrap();
+        assert_eq!(e.pdf(0.5), 2.0 * (-1.0f64).exp());
+        assert_eq!(e.pdf(-0.5), 0.0);
+        assert!((e.cdf(0.5) - (1.0 - (-1.0f64).exp())).abs() <= 1e-15);
+        assert_eq!(e.sf(0.5), (-1.0f64).exp());
+        // no cancellation for tiny x
+        assert!((e.cdf(1e-20) - 2e-20).abs() <= 1e-35);
+        assert!((e.inverse_cdf(e.cdf(0.5)).unwrap() - 0.5).abs() <= 1e-15);
+        assert_eq!(e.inverse_cdf(0.0), Ok(0.0));
+        assert_eq!(e.inverse_cdf(1.0), Ok(f64::INFINITY));
+        assert_eq!((e.mean(), e.variance()), (0.5, 0.25));
+        assert_eq!(
+            Exponential::new(0.0),
+            Err(DistributionErr::InvalidParameter)
+        );
+    }
+
+    #[test]
+    fn exponential_fit_test() {
+        let data = [1.2, 0.4, 2.7, 0.9, 1.6, 3.1, 0.7, 1.1, 5.2, 0.3, 1.9, 2.2];
+        let fit = Exponential::fit(&data).unwrap();
+        assert!((fit.distribution.rate() - 12.0 / 21.3).abs() <= 1e-15);
+        // n ln(rate) - rate * sum = 12 ln(12 / 21.3) - 12
+        assert!((fit.log_likelihood - (12.0 * (12.0f64 / 21.3).ln() - 12.0)).abs() <= 1e-13);
+        assert!((fit.bic - (12f64.ln() - 2.0 * fit.log_likelihood)).abs() <= 1e-13);
+
+        assert_eq!(
+            Exponential::fit(&[1.0, -1.0]),
+            Err(DistributionErr::InvalidData)
+        );
+        assert_eq!(
+            Exponential::fit(&[0.0, 0.0]),
+            Err(DistributionErr::InvalidData)
+        );
+    }
+}
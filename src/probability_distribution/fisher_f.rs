@@ -0,0 +1,127 @@
+/*!
+F distribution with $d_1$ and $d_2$ degrees of freedom, ratio $\frac{U_1 / d_1}{U_2 / d_2}$ of chi-square distributed values
+$$
+f(x) = \frac{1}{x B(\frac{d_1}{2}, \frac{d_2}{2})} \sqrt{\frac{(d_1 x)^{d_1} d_2^{d_2}}{(d_1 x + d_2)^{d_1 + d_2}}},
+\quad F(x) = I_{\frac{d_1 x}{d_1 x + d_2}}\left(\frac{d_1}{2}, \frac{d_2}{2}\right)
+$$
+*/
+use crate::probability_distribution::gamma::standard_gamma_sample;
+use crate::probability_distribution::{
+    check_positive, numeric_inverse_cdf, xlnx, ContinuousDistribution, DistributionErr,
+};
+use crate::special_function::{ln_beta, regularized_beta};
+use rand::Rng;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FisherF {
+    d1: f64,
+    d2: f64,
+}
+
+impl FisherF {
+    /// Error unless both degrees of freedom are positive and finite.
+    pub fn new(d1: f64, d2: f64) -> Result<FisherF, DistributionErr> {
+        check_positive(&[d1, d2])?;
+        Ok(FisherF { d1, d2 })
+    }
+
+    pub fn d1(&self) -> f64 {
+        self.d1
+    }
+
+    pub fn d2(&self) -> f64 {
+        self.d2
+    }
+}
+
+impl ContinuousDistribution for FisherF {
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        let (a, b) = (0.5 * self.d1, 0.5 * self.d2);
+        a * (self.d1 / self.d2).ln() + xlnx(a - 1.0, x)
+            - (a + b) * (self.d1 * x / self.d2).ln_1p()
+            - ln_beta(a, b)
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        let d1x = self.d1 * x;
+        regularized_beta(0.5 * self.d1, 0.5 * self.d2, d1x / (d1x + self.d2))
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 1.0;
+        }
+        let d1x = self.d1 * x;
+        regularized_beta(0.5 * self.d2, 0.5 * self.d1, self.d2 / (d1x + self.d2))
+    }
+
+    fn inverse_cdf(&self, p: f64) -> Result<f64, DistributionErr> {
+        numeric_inverse_cdf(self, p, 0.0, f64::INFINITY)
+    }
+
+    /// $d_2 / (d_2 - 2)$ for $d_2 > 2$, undefined otherwise.
+    fn mean(&self) -> f64 {
+        if self.d2 > 2.0 {
+            self.d2 / (self.d2 - 2.0)
+        } else {
+            f64::NAN
+        }
+    }
+
+    /// Defined for $d_2 > 4$, infinity for $2 < d_2 \leq 4$, undefined otherwise.
+    fn variance(&self) -> f64 {
+        let (d1, d2) = (self.d1, self.d2);
+        if d2 > 4.0 {
+            2.0 * d2 * d2 * (d1 + d2 - 2.0) / (d1 * (d2 - 2.0).powi(2) * (d2 - 4.0))
+        } else if d2 > 2.0 {
+            f64::INFINITY
+        } else {
+            f64::NAN
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let u1 = standard_gamma_sample(0.5 * self.d1, rng);
+        let u2 = standard_gamma_sample(0.5 * self.d2, rng);
+        (u1 / self.d1) / (u2 / self.d2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::probability_distribution::assert_close;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn fisher_f_test() {
+        // mpmath.betainc(1.5, 5, 0, 3x / (3x + 10), regularized=True) and mpmath.findroot
+        let f = FisherF::new(3.0, 10.0).unwrap();
+        assert_close(f.pdf(1.0), 0.40412281148856268, 1e-13);
+        assert_close(f.cdf(1.0), 0.5676627969783029, 1e-13);
+        assert_close(f.sf(20.0), 1.5116502658600844e-4, 1e-12);
+        assert_close(f.inverse_cdf(0.95).unwrap(), 3.7082648190468445, 1e-13);
+        assert_close(f.mean(), 1.25, 1e-15);
+        assert_close(f.variance(), 2.0 * 100.0 * 11.0 / (3.0 * 64.0 * 6.0), 1e-15);
+        assert_eq!(f.pdf(0.0), 0.0);
+        assert_eq!(f.cdf(-1.0), 0.0);
+        assert!(FisherF::new(3.0, 2.0).unwrap().mean().is_nan());
+
+        let mut rng = StdRng::seed_from_u64(11);
+        let n = 100_000;
+        let mean = (0..n).map(|_| f.sample(&mut rng)).sum::<f64>() / n as f64;
+        assert!((mean - 1.25).abs() < 0.03, "{}", mean);
+
+        assert_eq!(
+            FisherF::new(f64::NAN, 1.0),
+            Err(DistributionErr::InvalidParameter)
+        );
+    }
+}
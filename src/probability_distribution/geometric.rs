@@ -0,0 +1,83 @@
+/*!
+Geometric distribution, number of failures before the first success with success probability p
+$$
+P(X = k) = p (1-p)^k, \quad F(k) = 1 - (1-p)^{k+1}
+$$
+It's negative binomial distribution with r = 1.
+*/
+use crate::probability_distribution::{search_quantile, DiscreteDistribution, DistributionErr};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Geometric {
+    p: f64,
+}
+
+impl Geometric {
+    /// Error unless p is in (0, 1].
+    pub fn new(p: f64) -> Result<Geometric, DistributionErr> {
+        if !(p > 0.0 && p <= 1.0) {
+            return Err(DistributionErr::InvalidParameter);
+        }
+        Ok(Geometric { p })
+    }
+
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+}
+
+impl DiscreteDistribution for Geometric {
+    fn ln_pmf(&self, k: u64) -> f64 {
+        if k == 0 {
+            return self.p.ln();
+        }
+        self.p.ln() + k as f64 * (-self.p).ln_1p()
+    }
+
+    fn cdf(&self, k: u64) -> f64 {
+        -((k as f64 + 1.0) * (-self.p).ln_1p()).exp_m1()
+    }
+
+    fn sf(&self, k: u64) -> f64 {
+        ((k as f64 + 1.0) * (-self.p).ln_1p()).exp()
+    }
+
+    fn quantile(&self, p: f64) -> Result<u64, DistributionErr> {
+        search_quantile(self, p, 0, u64::MAX)
+    }
+
+    fn mean(&self) -> f64 {
+        (1.0 - self.p) / self.p
+    }
+
+    fn variance(&self) -> f64 {
+        (1.0 - self.p) / (self.p * self.p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::probability_distribution::assert_close;
+
+    #[test]
+    fn geometric_test() {
+        let g = Geometric::new(0.2).unwrap();
+        assert_close(g.pmf(0), 0.2, 1e-15);
+        assert_close(g.pmf(3), 0.2 * 0.8f64.powi(3), 1e-14);
+        assert_close(g.cdf(3), 1.0 - 0.8f64.powi(4), 1e-14);
+        assert_close(g.sf(3), 0.8f64.powi(4), 1e-14);
+        // 1 - 0.8^4 = 0.5904
+        assert_eq!(g.quantile(0.59), Ok(3));
+        assert_eq!(g.quantile(0.6), Ok(4));
+        assert_close(g.mean(), 4.0, 1e-15);
+        assert_close(g.variance(), 20.0, 1e-15);
+
+        // always succeeds at the first trial
+        let g = Geometric::new(1.0).unwrap();
+        assert_eq!((g.pmf(0), g.pmf(1), g.cdf(0)), (1.0, 0.0, 1.0));
+        assert_eq!(g.quantile(0.5), Ok(0));
+
+        assert_eq!(Geometric::new(0.0), Err(DistributionErr::InvalidParameter));
+    }
+}
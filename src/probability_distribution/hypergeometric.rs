@@ -0,0 +1,169 @@
+/*!
+Hypergeometric distribution, number of successes in n draws without replacement
+from a population of N with K successes
+$$
+P(X = k) = \frac{\binom{K}{k} \binom{N-K}{n-k}}{\binom{N}{n}}, \quad \max(0, n + K - N) \leq k \leq \min(n, K)
+$$
+*/
+use crate::probability_distribution::{search_quantile, DiscreteDistribution, DistributionErr};
+use crate::special_function::ln_binomial_pmf;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hypergeometric {
+    population: u64,
+    successes: u64,
+    draws: u64,
+}
+
+impl Hypergeometric {
+    /// Error if population is 0, or successes or draws are more than population.
+    pub fn new(
+        population: u64,
+        successes: u64,
+        draws: u64,
+    ) -> Result<Hypergeometric, DistributionErr> {
+        if population == 0 || successes > population || draws > population {
+            return Err(DistributionErr::InvalidParameter);
+        }
+        Ok(Hypergeometric {
+            population,
+            successes,
+            draws,
+        })
+    }
+
+    pub fn population(&self) -> u64 {
+        self.population
+    }
+
+    pub fn successes(&self) -> u64 {
+        self.successes
+    }
+
+    pub fn draws(&self) -> u64 {
+        self.draws
+    }
+
+    /// Bounds of support.
+    fn support(&self) -> (u64, u64) {
+        (
+            (self.draws + self.successes).saturating_sub(self.population),
+            self.draws.min(self.successes),
+        )
+    }
+
+    fn sum_pmf(&self, from: u64, to: u64) -> f64 {
+        (from..=to).map(|k| self.pmf(k)).sum()
+    }
+}
+
+impl DiscreteDistribution for Hypergeometric {
+    /**
+    With $p = n / N$ and binomial PMF b, it's
+    $$
+    \frac{b(k; K, p) \, b(n - k; N - K, p)}{b(n; N, p)}
+    $$
+    where every factor is accurate for large population.
+    */
+    fn ln_pmf(&self, k: u64) -> f64 {
+        let (min, max) = self.support();
+        if k < min || k > max {
+            return f64::NEG_INFINITY;
+        }
+        let (n, big_n, big_k) = (
+            self.draws as f64,
+            self.population as f64,
+            self.successes as f64,
+        );
+        let p = n / big_n;
+        let q = (big_n - n) / big_n;
+        let k = k as f64;
+        ln_binomial_pmf(k, big_k, p, q) + ln_binomial_pmf(n - k, big_n - big_k, p, q)
+            - ln_binomial_pmf(n, big_n, p, q)
+    }
+
+    /// Sum of PMF from the nearer end of support.
+    fn cdf(&self, k: u64) -> f64 {
+        let (min, max) = self.support();
+        if k < min {
+            0.0
+        } else if k >= max {
+            1.0
+        } else if k - min <= max - k {
+            self.sum_pmf(min, k)
+        } else {
+            1.0 - self.sum_pmf(k + 1, max)
+        }
+    }
+
+    fn sf(&self, k: u64) -> f64 {
+        let (min, max) = self.support();
+        if k < min {
+            1.0
+        } else if k >= max {
+            0.0
+        } else if k - min <= max - k {
+            1.0 - self.sum_pmf(min, k)
+        } else {
+            self.sum_pmf(k + 1, max)
+        }
+    }
+
+    fn quantile(&self, p: f64) -> Result<u64, DistributionErr> {
+        let (min, max) = self.support();
+        search_quantile(self, p, min, max)
+    }
+
+    fn mean(&self) -> f64 {
+        self.draws as f64 * self.successes as f64 / self.population as f64
+    }
+
+    fn variance(&self) -> f64 {
+        if self.population == 1 {
+            return 0.0;
+        }
+        let (n, big_n, big_k) = (
+            self.draws as f64,
+            self.population as f64,
+            self.successes as f64,
+        );
+        n * big_k / big_n * (big_n - big_k) / big_n * (big_n - n) / (big_n - 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::probability_distribution::assert_close;
+
+    #[test]
+    fn hypergeometric_test() {
+        // mpmath: binomial(K, k) * binomial(N - K, n - k) / binomial(N, n) and its sums
+        let h = Hypergeometric::new(50, 10, 12).unwrap();
+        assert_close(h.pmf(4), 0.13303155078013235, 1e-14);
+        assert_close(h.cdf(4), 0.9539750735741612, 1e-14);
+        assert_close(h.sf(4), 1.0 - 0.9539750735741612, 1e-12);
+        assert_close((0..=10).map(|k| h.pmf(k)).sum::<f64>(), 1.0, 1e-14);
+        assert_eq!((h.pmf(11), h.cdf(10), h.sf(10)), (0.0, 1.0, 0.0));
+        assert_eq!(h.quantile(0.95), Ok(4));
+        assert_close(h.mean(), 2.4, 1e-15);
+        assert_close(h.variance(), 12.0 * 0.2 * 0.8 * 38.0 / 49.0, 1e-14);
+
+        // support starts at n + K - N = 3
+        let h = Hypergeometric::new(10, 7, 6).unwrap();
+        assert_eq!((h.pmf(2), h.cdf(2), h.quantile(0.0)), (0.0, 0.0, Ok(3)));
+        assert_close(h.pmf(3), 1.0 / 6.0, 1e-14);
+
+        let h = Hypergeometric::new(1_000_000, 100_000, 50_000).unwrap();
+        assert_close(h.ln_pmf(5000), -5.099225120088911, 1e-13);
+
+        // drawing everything
+        let h = Hypergeometric::new(10, 4, 10).unwrap();
+        assert_eq!((h.pmf(4), h.variance()), (1.0, 0.0));
+
+        assert_eq!(
+            Hypergeometric::new(10, 11, 5),
+            Err(DistributionErr::InvalidParameter)
+        );
+    }
+}
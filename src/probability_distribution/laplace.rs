@@ -0,0 +1,111 @@
+/*!
+Laplace distribution with location $\mu$ and scale b
+$$
+f(x) = \frac{1}{2b} e^{-\frac{|x - \mu|}{b}}
+$$
+*/
+use crate::probability_distribution::{check_probability, ContinuousDistribution, DistributionErr};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Laplace {
+    location: f64,
+    scale: f64,
+}
+
+impl Laplace {
+    /// Error if location is not finite or scale is not positive and finite.
+    pub fn new(location: f64, scale: f64) -> Result<Laplace, DistributionErr> {
+        if !location.is_finite() || !scale.is_finite() || scale <= 0.0 {
+            return Err(DistributionErr::InvalidParameter);
+        }
+        Ok(Laplace { location, scale })
+    }
+
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+impl ContinuousDistribution for Laplace {
+    fn ln_pdf(&self, x: f64) -> f64 {
+        -(2.0 * self.scale).ln() - (x - self.location).abs() / self.scale
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        let z = (x - self.location) / self.scale;
+        if z < 0.0 {
+            0.5 * z.exp()
+        } else {
+            1.0 - 0.5 * (-z).exp()
+        }
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        let z = (self.location - x) / self.scale;
+        if z < 0.0 {
+            0.5 * z.exp()
+        } else {
+            1.0 - 0.5 * (-z).exp()
+        }
+    }
+
+    fn inverse_cdf(&self, p: f64) -> Result<f64, DistributionErr> {
+        check_probability(p)?;
+        Ok(if p < 0.5 {
+            self.location + self.scale * (2.0 * p).ln()
+        } else {
+            self.location - self.scale * (2.0 * (1.0 - p)).ln()
+        })
+    }
+
+    fn mean(&self) -> f64 {
+        self.location
+    }
+
+    fn variance(&self) -> f64 {
+        2.0 * self.scale * self.scale
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::probability_distribution::assert_close;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn laplace_test() {
+        let l = Laplace::new(1.0, 2.0).unwrap();
+        assert_close(l.pdf(4.0), 0.055782540037107457, 1e-15);
+        assert_close(l.pdf(-2.0), 0.055782540037107457, 1e-15);
+        assert_close(l.cdf(-1.0), 0.18393972058572116, 1e-15);
+        assert_close(l.sf(3.0), 0.18393972058572116, 1e-15);
+        assert_eq!(l.cdf(1.0), 0.5);
+        assert_close(l.inverse_cdf(0.18393972058572116).unwrap(), -1.0, 1e-14);
+        assert_close(
+            l.inverse_cdf(1.0 - 0.18393972058572116).unwrap(),
+            3.0,
+            1e-14,
+        );
+        assert_eq!(l.inverse_cdf(1.0), Ok(f64::INFINITY));
+        assert_eq!((l.mean(), l.variance()), (1.0, 8.0));
+
+        // default inverse transform sampling
+        let mut rng = StdRng::seed_from_u64(13);
+        let n = 100_000;
+        let samples: Vec<f64> = (0..n).map(|_| l.sample(&mut rng)).collect();
+        let mean = samples.iter().sum::<f64>() / n as f64;
+        assert!((mean - 1.0).abs() < 0.05, "{}", mean);
+        assert!(samples.iter().all(|x| x.is_finite()));
+
+        assert_eq!(
+            Laplace::new(0.0, -1.0),
+            Err(DistributionErr::InvalidParameter)
+        );
+    }
+}
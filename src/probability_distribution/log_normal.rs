@@ -0,0 +1,119 @@
+/*!
+Log-normal distribution, $\ln X$ is normal distribution $N(\mu, \sigma^2)$
+$$
+f(x) = \frac{1}{x \sigma \sqrt{2\pi}} e^{-\frac{(\ln x - \mu)^2}{2\sigma^2}}
+$$
+*/
+use crate::probability_distribution::normal::Normal;
+use crate::probability_distribution::{check_data, ContinuousDistribution, DistributionErr, Fit};
+use rand::Rng;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LogNormal {
+    normal: Normal,
+}
+
+impl LogNormal {
+    /// mu and sigma of $\ln X$, error if mu is not finite or sigma is not positive and finite.
+    pub fn new(mu: f64, sigma: f64) -> Result<LogNormal, DistributionErr> {
+        Ok(LogNormal {
+            normal: Normal::new(mu, sigma)?,
+        })
+    }
+
+    pub fn mu(&self) -> f64 {
+        self.normal.mu()
+    }
+
+    pub fn sigma(&self) -> f64 {
+        self.normal.sigma()
+    }
+
+    /// Maximum likelihood fit, normal distribution fitted to $\ln x$, error unless all values are positive.
+    pub fn fit(data: &[f64]) -> Result<Fit<LogNormal>, DistributionErr> {
+        check_data(data, |x| x > 0.0)?;
+        let ln_data: Vec<f64> = data.iter().map(|x| x.ln()).collect();
+        let normal = Normal::fit(&ln_data)?.distribution;
+        Ok(Fit::new(LogNormal { normal }, data, 2))
+    }
+}
+
+impl ContinuousDistribution for LogNormal {
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        let ln_x = x.ln();
+        self.normal.ln_pdf(ln_x) - ln_x
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        self.normal.cdf(x.ln())
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 1.0;
+        }
+        self.normal.sf(x.ln())
+    }
+
+    fn inverse_cdf(&self, p: f64) -> Result<f64, DistributionErr> {
+        self.normal.inverse_cdf(p).map(f64::exp)
+    }
+
+    fn mean(&self) -> f64 {
+        (self.mu() + 0.5 * self.normal.variance()).exp()
+    }
+
+    fn variance(&self) -> f64 {
+        let s2 = self.normal.variance();
+        s2.exp_m1() * (2.0 * self.mu() + s2).exp()
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.normal.sample(rng).exp()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::probability_distribution::assert_close;
+
+    #[test]
+    fn log_normal_test() {
+        // mpmath.npdf(mpmath.log(x), 0.5, 0.8) / x and mpmath.ncdf
+        let l = LogNormal::new(0.5, 0.8).unwrap();
+        assert_close(l.pdf(2.0), 0.24217677488483339, 1e-14);
+        assert_close(l.cdf(2.0), 0.595390608679215, 1e-14);
+        assert_close(l.sf(2.0), 1.0 - 0.595390608679215, 1e-14);
+        assert_close(l.inverse_cdf(0.595390608679215).unwrap(), 2.0, 1e-13);
+        assert_eq!(l.inverse_cdf(0.0), Ok(0.0));
+        assert_eq!((l.pdf(0.0), l.cdf(-1.0)), (0.0, 0.0));
+        assert_close(l.mean(), 0.82f64.exp(), 1e-15);
+        assert_close(l.variance(), (0.64f64.exp() - 1.0) * 1.64f64.exp(), 1e-14);
+        assert_eq!(
+            LogNormal::new(0.0, 0.0),
+            Err(DistributionErr::InvalidParameter)
+        );
+    }
+
+    #[test]
+    fn log_normal_fit_test() {
+        let data = [1.2, 0.4, 2.7, 0.9, 1.6, 3.1, 0.7, 1.1, 5.2, 0.3, 1.9, 2.2];
+        let fit = LogNormal::fit(&data).unwrap();
+        assert_close(fit.distribution.mu(), 0.280746677222733, 1e-14);
+        assert_close(fit.distribution.sigma(), 0.8043215469141669, 1e-14);
+        assert_close(fit.log_likelihood, -17.783148655737655, 1e-14);
+        assert_close(fit.aic, 4.0 + 2.0 * 17.783148655737655, 1e-14);
+
+        assert_eq!(
+            LogNormal::fit(&[1.0, 0.0]),
+            Err(DistributionErr::InvalidData)
+        );
+    }
+}
@@ -0,0 +1,327 @@
+use rand::distributions::Open01;
+use rand::Rng;
+use std::fmt;
+
+pub mod bernoulli;
+pub mod beta;
+pub mod binomial;
+pub mod cauchy;
+pub mod chi_square;
+pub mod exponential;
+pub mod fisher_f;
+pub mod gamma;
+pub mod geometric;
+pub mod hypergeometric;
+pub mod laplace;
+pub mod log_normal;
+pub mod negative_binomial;
+pub mod normal;
+pub mod poisson;
+pub mod student_t;
+pub mod uniform;
+pub mod weibull;
+
+const EPSILON: f64 = 1e-15;
+const MAX_ITERATIONS: usize = 1000;
+
+/// Errors of probability distributions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DistributionErr {
+    /// Parameter is out of its domain, such as non-positive standard deviation.
+    InvalidParameter,
+
+    /// Probability is out of [0, 1].
+    InvalidProbability,
+
+    /// Data to fit has fewer than 2 values, values out of support, or no spread.
+    InvalidData,
+}
+
+impl std::error::Error for DistributionErr {}
+
+impl fmt::Display for DistributionErr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DistributionErr::InvalidParameter => write!(f, "Invalid parameter"),
+            DistributionErr::InvalidProbability => write!(f, "Probability out of [0, 1]"),
+            DistributionErr::InvalidData => write!(f, "Invalid data to fit"),
+        }
+    }
+}
+
+/**
+Continuous probability distribution with PDF $f(x)$ and CDF $F(x) = \int_{-\infty}^x f(t) dt$.
+
+Arguments out of support are not errors: PDF is 0 and CDF is 0 or 1 there.
+Mean and variance are NaN if undefined, and infinity if the integral diverges to it.
+*/
+pub trait ContinuousDistribution {
+    /// Probability density function.
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Logarithm of PDF, negative infinity out of support.
+    fn ln_pdf(&self, x: f64) -> f64;
+
+    /// Cumulative distribution function $F(x) = P(X \leq x)$.
+    fn cdf(&self, x: f64) -> f64;
+
+    /// Survival function $1 - F(x)$, implementations keep relative accuracy in the upper tail if they can.
+    fn sf(&self, x: f64) -> f64 {
+        1.0 - self.cdf(x)
+    }
+
+    /// Inverse of CDF, error if p is out of [0, 1], 0 and 1 give the bounds of support.
+    fn inverse_cdf(&self, p: f64) -> Result<f64, DistributionErr>;
+
+    fn mean(&self) -> f64;
+
+    fn variance(&self) -> f64;
+
+    /// Random value, by inverse transform sampling $F^{-1}(U)$ with U uniform in (0, 1) if not overridden.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.inverse_cdf(rng.sample(Open01))
+            .expect("probability in (0, 1) is valid")
+    }
+}
+
+/**
+Discrete probability distribution on non-negative integers with PMF $p(k) = P(X = k)$.
+
+Arguments out of support are not errors, PMF is 0 there.
+*/
+pub trait DiscreteDistribution {
+    /// Probability mass function.
+    fn pmf(&self, k: u64) -> f64 {
+        self.ln_pmf(k).exp()
+    }
+
+    /// Logarithm of PMF, negative infinity out of support.
+    fn ln_pmf(&self, k: u64) -> f64;
+
+    /// Cumulative distribution function $F(k) = P(X \leq k)$.
+    fn cdf(&self, k: u64) -> f64;
+
+    /// Survival function $P(X > k) = 1 - F(k)$.
+    fn sf(&self, k: u64) -> f64 {
+        1.0 - self.cdf(k)
+    }
+
+    /// The smallest k with $F(k) \geq p$, error if p is out of [0, 1], 0 gives the lower bound of support.
+    fn quantile(&self, p: f64) -> Result<u64, DistributionErr>;
+
+    fn mean(&self) -> f64;
+
+    fn variance(&self) -> f64;
+
+    /// Random value, by inverse transform sampling $F^{-1}(U)$ with U uniform in (0, 1) if not overridden.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        self.quantile(rng.sample(Open01))
+            .expect("probability in (0, 1) is valid")
+    }
+}
+
+/**
+Distribution fitted to n values by maximum likelihood, with information criteria of its k parameters
+$$
+AIC = 2k - 2 \ln L, \quad BIC = k \ln n - 2 \ln L
+$$
+Lower criteria are better fits, BIC penalizes parameters more for n >= 8.
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fit<D> {
+    pub distribution: D,
+    pub log_likelihood: f64,
+    pub aic: f64,
+    pub bic: f64,
+}
+
+impl<D: ContinuousDistribution> Fit<D> {
+    pub(crate) fn new(distribution: D, data: &[f64], parameters: usize) -> Fit<D> {
+        let log_likelihood = data.iter().map(|x| distribution.ln_pdf(*x)).sum::<f64>();
+        let k = parameters as f64;
+        Fit {
+            distribution,
+            log_likelihood,
+            aic: 2.0 * k - 2.0 * log_likelihood,
+            bic: k * (data.len() as f64).ln() - 2.0 * log_likelihood,
+        }
+    }
+}
+
+/// Error unless data has at least 2 values, and all of them are finite and in support.
+pub(crate) fn check_data<F: Fn(f64) -> bool>(
+    data: &[f64],
+    in_support: F,
+) -> Result<(), DistributionErr> {
+    if data.len() >= 2 && data.iter().all(|x| x.is_finite() && in_support(*x)) {
+        Ok(())
+    } else {
+        Err(DistributionErr::InvalidData)
+    }
+}
+
+/// Error unless p is in [0, 1].
+pub(crate) fn check_probability(p: f64) -> Result<(), DistributionErr> {
+    if (0.0..=1.0).contains(&p) {
+        Ok(())
+    } else {
+        Err(DistributionErr::InvalidProbability)
+    }
+}
+
+/// Error unless every parameter is positive and finite.
+pub(crate) fn check_positive(params: &[f64]) -> Result<(), DistributionErr> {
+    if params.iter().all(|v| v.is_finite() && *v > 0.0) {
+        Ok(())
+    } else {
+        Err(DistributionErr::InvalidParameter)
+    }
+}
+
+/// $a \ln x$ which is 0 if a is 0, even if x is 0.
+pub(crate) fn xlnx(a: f64, x: f64) -> f64 {
+    if a == 0.0 {
+        0.0
+    } else {
+        a * x.ln()
+    }
+}
+
+/// Quantile of discrete distribution with support [min, max] by exponential and binary search on CDF.
+pub(crate) fn search_quantile<D: DiscreteDistribution + ?Sized>(
+    d: &D,
+    p: f64,
+    min: u64,
+    max: u64,
+) -> Result<u64, DistributionErr> {
+    check_probability(p)?;
+    if p == 0.0 {
+        return Ok(min);
+    }
+    if p == 1.0 {
+        return Ok(max);
+    }
+    // F(hi) >= p, and F(lo - 1) < p
+    let (mut lo, mut hi) = (min, min);
+    let mut step = 1u64;
+    while hi < max && d.cdf(hi) < p {
+        lo = hi + 1;
+        hi = hi.saturating_add(step).min(max);
+        step = step.saturating_mul(2);
+    }
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if d.cdf(mid) < p {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    Ok(lo)
+}
+
+/**
+Inverse of CDF by Newton's method safeguarded with bisection, for distributions without a closed form.
+
+Support is [lower, upper], infinite bounds are bracketed by stepping out from the mean, or 0 if it's undefined.
+*/
+pub(crate) fn numeric_inverse_cdf<D: ContinuousDistribution + ?Sized>(
+    d: &D,
+    p: f64,
+    lower: f64,
+    upper: f64,
+) -> Result<f64, DistributionErr> {
+    check_probability(p)?;
+    if p == 0.0 {
+        return Ok(lower);
+    }
+    if p == 1.0 {
+        return Ok(upper);
+    }
+
+    let mean = d.mean();
+    let start = if mean.is_finite() { mean } else { 0.0 }.clamp(lower, upper);
+    let (mut lo, mut hi) = (lower, upper);
+    let mut step = start.abs().max(1.0);
+    if lo.is_infinite() {
+        lo = start - step;
+        while d.cdf(lo) > p {
+            step *= 2.0;
+            lo = start - step;
+        }
+    }
+    step = start.abs().max(1.0);
+    if hi.is_infinite() {
+        hi = start + step;
+        while d.cdf(hi) < p {
+            step *= 2.0;
+            hi = start + step;
+        }
+    }
+
+    let mut x = 0.5 * (lo + hi);
+    for _ in 0..MAX_ITERATIONS {
+        let diff = d.cdf(x) - p;
+        if diff == 0.0 {
+            break;
+        }
+        if diff < 0.0 {
+            lo = x;
+        } else {
+            hi = x;
+        }
+        let newton = x - diff / d.pdf(x);
+        let next = if newton > lo && newton < hi {
+            newton
+        } else {
+            0.5 * (lo + hi)
+        };
+        if (next - x).abs() <= EPSILON * x.abs() || hi - lo <= EPSILON * x.abs() {
+            x = next;
+            break;
+        }
+        x = next;
+    }
+    Ok(x)
+}
+
+/// x: x axis
+///
+/**
+$$
+f(x)=\frac{1}{\sqrt{2 \pi} \sigma} e^{-\frac{(x-\mu)^{2}}{2 \sigma^{2}}}
+$$
+*/
+pub fn normal_distribution(x: f64, mu: f64, sigma: f64) -> f64 {
+    (std::f64::consts::E.powf(-((x - mu).powi(2) / (2.0 * sigma * sigma))))
+        / ((std::f64::consts::PI * 2.0).sqrt() * sigma)
+}
+
+pub fn standard_normal_distribution(x: f64) -> f64 {
+    normal_distribution(x, 0.0, 1.0)
+}
+
+/// Assert a is within relative tolerance of b, for tests.
+#[cfg(test)]
+pub(crate) fn assert_close(a: f64, b: f64, tolerance: f64) {
+    assert!((a - b).abs() <= tolerance * b.abs(), "{} != {}", a, b);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::probability_distribution::*;
+
+    #[test]
+    fn normal_distribution_test() {
+        let y = normal_distribution(1.0, 2.0, 3.0);
+        println!("{}", y)
+    }
+
+    #[test]
+    fn standard_normal_distribution_test() {
+        let y = standard_normal_distribution(1.0);
+        println!("{}", y)
+    }
+}
@@ -0,0 +1,104 @@
+/*!
+Negative binomial distribution, number of failures before the r-th success with success probability p
+$$
+P(X = k) = \frac{\Gamma(k + r)}{k! \Gamma(r)} p^r (1-p)^k, \quad F(k) = I_p(r, k + 1)
+$$
+r doesn't need to be an integer, which makes it a gamma mixture of Poisson distributions for overdispersed counts.
+*/
+use crate::probability_distribution::{
+    check_positive, search_quantile, DiscreteDistribution, DistributionErr,
+};
+use crate::special_function::{ln_binomial_pmf, regularized_beta};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NegativeBinomial {
+    r: f64,
+    p: f64,
+}
+
+impl NegativeBinomial {
+    /// Error unless r is positive and finite and p is in (0, 1].
+    pub fn new(r: f64, p: f64) -> Result<NegativeBinomial, DistributionErr> {
+        check_positive(&[r])?;
+        if !(p > 0.0 && p <= 1.0) {
+            return Err(DistributionErr::InvalidParameter);
+        }
+        Ok(NegativeBinomial { r, p })
+    }
+
+    pub fn r(&self) -> f64 {
+        self.r
+    }
+
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+}
+
+impl DiscreteDistribution for NegativeBinomial {
+    /// $\frac{r}{r + k}$ times binomial PMF of r successes in r + k trials, accurate for large k and r.
+    fn ln_pmf(&self, k: u64) -> f64 {
+        let n = self.r + k as f64;
+        (self.r / n).ln() + ln_binomial_pmf(self.r, n, self.p, 1.0 - self.p)
+    }
+
+    fn cdf(&self, k: u64) -> f64 {
+        regularized_beta(self.r, k as f64 + 1.0, self.p)
+    }
+
+    fn sf(&self, k: u64) -> f64 {
+        regularized_beta(k as f64 + 1.0, self.r, 1.0 - self.p)
+    }
+
+    fn quantile(&self, p: f64) -> Result<u64, DistributionErr> {
+        search_quantile(self, p, 0, u64::MAX)
+    }
+
+    fn mean(&self) -> f64 {
+        self.r * (1.0 - self.p) / self.p
+    }
+
+    fn variance(&self) -> f64 {
+        self.r * (1.0 - self.p) / (self.p * self.p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::probability_distribution::assert_close;
+    use crate::probability_distribution::geometric::Geometric;
+
+    #[test]
+    fn negative_binomial_test() {
+        // mpmath: loggamma(k + r) - loggamma(k + 1) - loggamma(r) + r log(p) + k log(1 - p)
+        let nb = NegativeBinomial::new(2.5, 0.4).unwrap();
+        assert_close(nb.pmf(4), 0.1183387545988211, 1e-14);
+        assert_close(nb.cdf(4), 0.6741406761500155, 1e-14);
+        assert_close(nb.sf(4), 1.0 - 0.6741406761500155, 1e-13);
+        assert_close(nb.pmf(0), 0.4f64.powf(2.5), 1e-14);
+        assert_eq!(nb.quantile(0.67), Ok(4));
+        assert_close(nb.mean(), 3.75, 1e-15);
+        assert_close(nb.variance(), 9.375, 1e-15);
+
+        let nb = NegativeBinomial::new(1000.0, 0.01).unwrap();
+        assert_close(nb.ln_pmf(100_000), -9.033214045859548, 1e-13);
+
+        // r = 1 is geometric distribution
+        let nb = NegativeBinomial::new(1.0, 0.2).unwrap();
+        let g = Geometric::new(0.2).unwrap();
+        for k in 0..10 {
+            assert_close(nb.pmf(k), g.pmf(k), 1e-14);
+            assert_close(nb.cdf(k), g.cdf(k), 1e-14);
+        }
+
+        assert_eq!(
+            NegativeBinomial::new(1.0, 0.0),
+            Err(DistributionErr::InvalidParameter)
+        );
+        assert_eq!(
+            NegativeBinomial::new(0.0, 0.5),
+            Err(DistributionErr::InvalidParameter)
+        );
+    }
+}
@@ -0,0 +1,101 @@
+/*!
+Poisson distribution with rate $\lambda$, number of events in an interval
+$$
+P(X = k) = \frac{\lambda^k e^{-\lambda}}{k!}, \quad F(k) = Q(k + 1, \lambda)
+$$
+*/
+use crate::probability_distribution::{
+    check_positive, search_quantile, DiscreteDistribution, DistributionErr,
+};
+use crate::special_function::{deviance, regularized_gamma_p, regularized_gamma_q, stirling_error};
+use std::f64::consts::PI;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Poisson {
+    lambda: f64,
+}
+
+impl Poisson {
+    /// Error unless lambda is positive and finite.
+    pub fn new(lambda: f64) -> Result<Poisson, DistributionErr> {
+        check_positive(&[lambda])?;
+        Ok(Poisson { lambda })
+    }
+
+    pub fn lambda(&self) -> f64 {
+        self.lambda
+    }
+}
+
+impl DiscreteDistribution for Poisson {
+    /**
+    Loader's saddle point expansion, accurate for large k and $\lambda$
+    $$
+    \ln P(X = k) = -\delta(k) - D(k, \lambda) - \ln \sqrt{2\pi k}
+    $$
+    */
+    fn ln_pmf(&self, k: u64) -> f64 {
+        if k == 0 {
+            return -self.lambda;
+        }
+        let k = k as f64;
+        -stirling_error(k) - deviance(k, self.lambda) - 0.5 * (2.0 * PI * k).ln()
+    }
+
+    fn cdf(&self, k: u64) -> f64 {
+        regularized_gamma_q(k as f64 + 1.0, self.lambda)
+    }
+
+    fn sf(&self, k: u64) -> f64 {
+        regularized_gamma_p(k as f64 + 1.0, self.lambda)
+    }
+
+    fn quantile(&self, p: f64) -> Result<u64, DistributionErr> {
+        search_quantile(self, p, 0, u64::MAX)
+    }
+
+    fn mean(&self) -> f64 {
+        self.lambda
+    }
+
+    fn variance(&self) -> f64 {
+        self.lambda
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::probability_distribution::assert_close;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn poisson_test() {
+        // mpmath: k * log(lambda) - lambda - loggamma(k + 1) and its sums
+        let p = Poisson::new(2.5).unwrap();
+        assert_close(p.pmf(3), 0.21376301724973645, 1e-14);
+        assert_eq!(p.ln_pmf(0), -2.5);
+        assert_close(p.cdf(3), 0.757576133133066, 1e-14);
+        assert_close(p.sf(3), 1.0 - 0.757576133133066, 1e-13);
+        assert_close((0..100).map(|k| p.pmf(k)).sum::<f64>(), 1.0, 1e-14);
+        assert_eq!(p.quantile(0.7575), Ok(3));
+        assert_eq!(p.quantile(0.76), Ok(4));
+        assert_eq!(p.quantile(0.0), Ok(0));
+        assert_eq!(p.quantile(1.0), Ok(u64::MAX));
+
+        let p = Poisson::new(1e6).unwrap();
+        assert_close(p.ln_pmf(1_000_000), -7.826693895520143, 1e-14);
+        let p = Poisson::new(1000.0).unwrap();
+        assert_close(p.cdf(1000), 0.508409367168506, 1e-13);
+        assert_eq!(p.quantile(0.5084), Ok(1000));
+
+        let p = Poisson::new(4.0).unwrap();
+        let mut rng = StdRng::seed_from_u64(23);
+        let n = 20_000;
+        let mean = (0..n).map(|_| p.sample(&mut rng)).sum::<u64>() as f64 / n as f64;
+        assert!((mean - 4.0).abs() < 0.05, "{}", mean);
+
+        assert_eq!(Poisson::new(0.0), Err(DistributionErr::InvalidParameter));
+    }
+}
@@ -0,0 +1,308 @@
+/*!
+Student's t distribution with $\nu$ degrees of freedom
+$$
+f(t) = \frac{\Gamma(\frac{\nu+1}{2})}{\sqrt{\nu\pi}\,\Gamma(\frac{\nu}{2})}
+\left(1 + \frac{t^2}{\nu}\right)^{-\frac{\nu+1}{2}}
+$$
+Tails are $\frac{1}{2} I_{\nu/(\nu+t^2)}(\frac{\nu}{2}, \frac{1}{2})$.
+With location $\mu$ and scale $\sigma$, $(X - \mu) / \sigma$ has this distribution.
+*/
+use crate::probability_distribution::gamma::standard_gamma_sample;
+use crate::probability_distribution::normal::Normal;
+use crate::probability_distribution::{
+    check_data, check_positive, numeric_inverse_cdf, ContinuousDistribution, DistributionErr, Fit,
+    MAX_ITERATIONS,
+};
+use crate::special_function::{ln_gamma, regularized_beta};
+use rand::Rng;
+use std::f64::consts::PI;
+
+/// Bounds of degrees of freedom searched by `StudentT::fit`.
+const FIT_DF_RANGE: (f64, f64) = (0.1, 1000.0);
+/// Relative tolerance of EM iterations and of the search on $\ln \nu$.
+const FIT_TOLERANCE: f64 = 1e-10;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StudentT {
+    df: f64,
+    location: f64,
+    scale: f64,
+}
+
+impl StudentT {
+    /// Error unless degrees of freedom is positive and finite.
+    pub fn new(df: f64) -> Result<StudentT, DistributionErr> {
+        StudentT::with_location_scale(df, 0.0, 1.0)
+    }
+
+    /// Error unless location is finite, and degrees of freedom and scale are positive and finite.
+    pub fn with_location_scale(
+        df: f64,
+        location: f64,
+        scale: f64,
+    ) -> Result<StudentT, DistributionErr> {
+        check_positive(&[df, scale])?;
+        if !location.is_finite() {
+            return Err(DistributionErr::InvalidParameter);
+        }
+        Ok(StudentT {
+            df,
+            location,
+            scale,
+        })
+    }
+
+    pub fn df(&self) -> f64 {
+        self.df
+    }
+
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /**
+    Maximum likelihood fit of degrees of freedom, location and scale.
+
+    Location and scale for fixed $\nu$ are found by EM algorithm, which iterates weighted mean and variance
+    with weights $w_i = \frac{\nu + 1}{\nu + z_i^2}$ of standardized values $z_i$.
+    $\nu$ is found by golden section search of this profile likelihood on $\ln \nu$,
+    within [0.1, 1000], so the upper bound means data is close to normal distribution.
+    */
+    pub fn fit(data: &[f64]) -> Result<Fit<StudentT>, DistributionErr> {
+        check_data(data, |_| true)?;
+        let start = Normal::fit(data)?.distribution;
+        let mut estimate = (start.mu(), start.sigma());
+        let mut profile = |ln_df: f64| {
+            let df = ln_df.exp();
+            estimate = fit_location_scale(data, df, estimate.0, estimate.1);
+            let t = StudentT {
+                df,
+                location: estimate.0,
+                scale: estimate.1,
+            };
+            data.iter().map(|x| t.ln_pdf(*x)).sum::<f64>()
+        };
+
+        let ratio = 0.5 * (5f64.sqrt() - 1.0);
+        let (mut a, mut b) = (FIT_DF_RANGE.0.ln(), FIT_DF_RANGE.1.ln());
+        let mut c = b - ratio * (b - a);
+        let mut d = a + ratio * (b - a);
+        let (mut fc, mut fd) = (profile(c), profile(d));
+        while b - a > FIT_TOLERANCE {
+            if fc >= fd {
+                b = d;
+                d = c;
+                fd = fc;
+                c = b - ratio * (b - a);
+                fc = profile(c);
+            } else {
+                a = c;
+                c = d;
+                fc = fd;
+                d = a + ratio * (b - a);
+                fd = profile(d);
+            }
+        }
+        let df = (0.5 * (a + b)).exp();
+        let (location, scale) = fit_location_scale(data, df, estimate.0, estimate.1);
+        let t = StudentT::with_location_scale(df, location, scale)
+            .map_err(|_| DistributionErr::InvalidData)?;
+        Ok(Fit::new(t, data, 3))
+    }
+
+    /// $P(T \leq t)$ of standardized value $t \leq 0$.
+    fn lower_tail(&self, t: f64) -> f64 {
+        let nu = self.df;
+        0.5 * regularized_beta(0.5 * nu, 0.5, nu / (nu + t * t))
+    }
+}
+
+/// Location and scale maximizing likelihood for fixed degrees of freedom by EM algorithm.
+fn fit_location_scale(data: &[f64], df: f64, location: f64, scale: f64) -> (f64, f64) {
+    let n = data.len() as f64;
+    let (mut location, mut scale) = (location, scale);
+    let mut weights = vec![0.0; data.len()];
+    for _ in 0..MAX_ITERATIONS {
+        for (w, x) in weights.iter_mut().zip(data) {
+            let z = (x - location) / scale;
+            *w = (df + 1.0) / (df + z * z);
+        }
+        let next_location =
+            weights.iter().zip(data).map(|(w, x)| w * x).sum::<f64>() / weights.iter().sum::<f64>();
+        let next_scale = (weights
+            .iter()
+            .zip(data)
+            .map(|(w, x)| w * (x - next_location) * (x - next_location))
+            .sum::<f64>()
+            / n)
+            .sqrt();
+        let done = (next_location - location).abs() <= FIT_TOLERANCE * scale
+            && (next_scale - scale).abs() <= FIT_TOLERANCE * scale;
+        location = next_location;
+        scale = next_scale;
+        if done {
+            break;
+        }
+    }
+    (location, scale)
+}
+
+impl ContinuousDistribution for StudentT {
+    fn ln_pdf(&self, x: f64) -> f64 {
+        let nu = self.df;
+        let z = (x - self.location) / self.scale;
+        ln_gamma(0.5 * (nu + 1.0))
+            - ln_gamma(0.5 * nu)
+            - 0.5 * (nu * PI).ln()
+            - 0.5 * (nu + 1.0) * (z * z / nu).ln_1p()
+            - self.scale.ln()
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        let z = (x - self.location) / self.scale;
+        if z <= 0.0 {
+            self.lower_tail(z)
+        } else {
+            1.0 - self.lower_tail(z)
+        }
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        let z = (x - self.location) / self.scale;
+        if z >= 0.0 {
+            self.lower_tail(z)
+        } else {
+            1.0 - self.lower_tail(z)
+        }
+    }
+
+    fn inverse_cdf(&self, p: f64) -> Result<f64, DistributionErr> {
+        let standard = StudentT {
+            df: self.df,
+            location: 0.0,
+            scale: 1.0,
+        };
+        // symmetric, and the lower half keeps relative accuracy of tiny p
+        let t = if p > 0.5 && p <= 1.0 {
+            -numeric_inverse_cdf(&standard, 1.0 - p, f64::NEG_INFINITY, f64::INFINITY)?
+        } else {
+            numeric_inverse_cdf(&standard, p, f64::NEG_INFINITY, f64::INFINITY)?
+        };
+        Ok(self.location + self.scale * t)
+    }
+
+    /// Location for $\nu > 1$, undefined otherwise.
+    fn mean(&self) -> f64 {
+        if self.df > 1.0 {
+            self.location
+        } else {
+            f64::NAN
+        }
+    }
+
+    /// $\sigma^2 \nu / (\nu - 2)$ for $\nu > 2$, infinity for $1 < \nu \leq 2$, undefined otherwise.
+    fn variance(&self) -> f64 {
+        if self.df > 2.0 {
+            self.scale * self.scale * self.df / (self.df - 2.0)
+        } else if self.df > 1.0 {
+            f64::INFINITY
+        } else {
+            f64::NAN
+        }
+    }
+
+    /// Random value by $\mu + \sigma Z / \sqrt{V / \nu}$ of standard normal Z and chi-square distributed V.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let z = Normal::standard().sample(rng);
+        let v = 2.0 * standard_gamma_sample(0.5 * self.df, rng);
+        self.location + self.scale * z / (v / self.df).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::probability_distribution::assert_close;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn student_t_test() {
+        // mpmath.quad of the density and mpmath.findroot
+        let t = StudentT::new(5.0).unwrap();
+        assert_close(t.pdf(1.0), 0.21967979735098057, 1e-13);
+        assert_close(t.inverse_cdf(0.95).unwrap(), 2.0150483733330242, 1e-13);
+        assert_close(t.inverse_cdf(0.05).unwrap(), -2.0150483733330242, 1e-13);
+        assert_close(t.sf(2.0150483733330242), 0.05, 1e-13);
+        assert_eq!(t.cdf(0.0), 0.5);
+        assert_eq!(t.inverse_cdf(0.5), Ok(0.0));
+        assert_eq!(t.inverse_cdf(1.0), Ok(f64::INFINITY));
+        assert_close(t.variance(), 5.0 / 3.0, 1e-15);
+        let t = StudentT::new(2.5).unwrap();
+        assert_close(t.cdf(-3.0), 0.03628804777451592, 1e-13);
+        assert_close(t.sf(3.0), 0.03628804777451592, 1e-13);
+        assert_eq!(StudentT::new(2.0).unwrap().variance(), f64::INFINITY);
+
+        // 1 degree of freedom is standard Cauchy distribution
+        let t = StudentT::new(1.0).unwrap();
+        assert_close(t.cdf(1.0), 0.75, 1e-14);
+        assert!(t.mean().is_nan());
+
+        let t = StudentT::new(10.0).unwrap();
+        let mut rng = StdRng::seed_from_u64(9);
+        let n = 100_000;
+        let samples: Vec<f64> = (0..n).map(|_| t.sample(&mut rng)).collect();
+        let variance = samples.iter().map(|x| x * x).sum::<f64>() / n as f64;
+        assert!((variance - 1.25).abs() < 0.05, "{}", variance);
+
+        assert_eq!(StudentT::new(0.0), Err(DistributionErr::InvalidParameter));
+    }
+
+    #[test]
+    fn student_t_location_scale_test() {
+        let t = StudentT::with_location_scale(5.0, 1.0, 2.0).unwrap();
+        let standard = StudentT::new(5.0).unwrap();
+        assert_close(t.pdf(3.0), 0.5 * standard.pdf(1.0), 1e-14);
+        assert_close(t.cdf(-1.0), standard.cdf(-1.0), 1e-14);
+        assert_close(t.sf(3.0), standard.sf(1.0), 1e-14);
+        assert_close(
+            t.inverse_cdf(0.95).unwrap(),
+            1.0 + 2.0 * 2.0150483733330242,
+            1e-13,
+        );
+        assert_eq!(t.inverse_cdf(0.0), Ok(f64::NEG_INFINITY));
+        assert_eq!((t.mean(), t.variance()), (1.0, 4.0 * 5.0 / 3.0));
+        assert_eq!(
+            StudentT::with_location_scale(5.0, f64::NAN, 1.0),
+            Err(DistributionErr::InvalidParameter)
+        );
+        assert_eq!(
+            StudentT::with_location_scale(5.0, 0.0, 0.0),
+            Err(DistributionErr::InvalidParameter)
+        );
+    }
+
+    #[test]
+    fn student_t_fit_test() {
+        // mpmath.findroot of gradient of log likelihood
+        let data = [
+            0.012, -0.034, 0.005, 0.021, -0.008, 0.047, -0.061, 0.003, 0.015, -0.002, 0.009,
+            -0.019, 0.028, -0.004, 0.001, 0.112, -0.013, 0.006, -0.027, 0.018,
+        ];
+        let fit = StudentT::fit(&data).unwrap();
+        let t = fit.distribution;
+        assert_close(t.df(), 1.9278013674453154, 1e-6);
+        assert_close(t.location(), 0.0032481267918987524, 1e-6);
+        assert_close(t.scale(), 0.01632685685543852, 1e-6);
+        assert_close(fit.log_likelihood, 42.66963874877126, 1e-13);
+        assert_close(fit.bic, 3.0 * 20f64.ln() - 2.0 * 42.66963874877126, 1e-13);
+        // heavy tails fit better than normal distribution despite the extra parameter
+        assert!(fit.aic < Normal::fit(&data).unwrap().aic);
+
+        assert_eq!(StudentT::fit(&[0.0; 5]), Err(DistributionErr::InvalidData));
+    }
+}
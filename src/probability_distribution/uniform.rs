@@ -0,0 +1,98 @@
+/*!
+Continuous uniform distribution on [a, b]
+$$
+f(x) = \frac{1}{b - a}, \quad F(x) = \frac{x - a}{b - a}
+$$
+*/
+use crate::probability_distribution::{check_probability, ContinuousDistribution, DistributionErr};
+use rand::Rng;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Uniform {
+    a: f64,
+    b: f64,
+}
+
+impl Uniform {
+    /// Error unless a and b are finite and a < b.
+    pub fn new(a: f64, b: f64) -> Result<Uniform, DistributionErr> {
+        if !a.is_finite() || !b.is_finite() || a >= b {
+            return Err(DistributionErr::InvalidParameter);
+        }
+        Ok(Uniform { a, b })
+    }
+
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+
+    pub fn b(&self) -> f64 {
+        self.b
+    }
+}
+
+impl ContinuousDistribution for Uniform {
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < self.a || x > self.b {
+            return f64::NEG_INFINITY;
+        }
+        -(self.b - self.a).ln()
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        ((x - self.a) / (self.b - self.a)).clamp(0.0, 1.0)
+    }
+
+    fn inverse_cdf(&self, p: f64) -> Result<f64, DistributionErr> {
+        check_probability(p)?;
+        Ok(self.a + p * (self.b - self.a))
+    }
+
+    fn mean(&self) -> f64 {
+        0.5 * (self.a + self.b)
+    }
+
+    fn variance(&self) -> f64 {
+        (self.b - self.a).powi(2) / 12.0
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        rng.gen_range(self.a, self.b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn uniform_test() {
+        let u = Uniform::new(1.0, 3.0).unwrap();
+        assert_eq!(u.pdf(2.0), 0.5);
+        assert_eq!(u.pdf(3.5), 0.0);
+        assert_eq!(u.cdf(1.5), 0.25);
+        assert_eq!(u.cdf(0.0), 0.0);
+        assert_eq!(u.cdf(4.0), 1.0);
+        assert_eq!(u.sf(1.5), 0.75);
+        assert_eq!(u.inverse_cdf(0.25), Ok(1.5));
+        assert_eq!(
+            u.inverse_cdf(-0.1),
+            Err(DistributionErr::InvalidProbability)
+        );
+        assert_eq!((u.mean(), u.variance()), (2.0, 1.0 / 3.0));
+
+        let mut rng = StdRng::seed_from_u64(3);
+        assert!((0..1000).all(|_| (1.0..3.0).contains(&u.sample(&mut rng))));
+
+        assert_eq!(
+            Uniform::new(1.0, 1.0),
+            Err(DistributionErr::InvalidParameter)
+        );
+        assert_eq!(
+            Uniform::new(0.0, f64::INFINITY),
+            Err(DistributionErr::InvalidParameter)
+        );
+    }
+}
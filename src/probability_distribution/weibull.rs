@@ -0,0 +1,102 @@
+/*!
+Weibull distribution with shape k and scale $\lambda$, on $[0, \infty)$
+$$
+f(x) = \frac{k}{\lambda} \left(\frac{x}{\lambda}\right)^{k-1} e^{-(x/\lambda)^k},
+\quad F(x) = 1 - e^{-(x/\lambda)^k}
+$$
+*/
+use crate::probability_distribution::{
+    check_positive, check_probability, xlnx, ContinuousDistribution, DistributionErr,
+};
+use crate::special_function::ln_gamma;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Weibull {
+    shape: f64,
+    scale: f64,
+}
+
+impl Weibull {
+    /// Error unless shape and scale are positive and finite.
+    pub fn new(shape: f64, scale: f64) -> Result<Weibull, DistributionErr> {
+        check_positive(&[shape, scale])?;
+        Ok(Weibull { shape, scale })
+    }
+
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+impl ContinuousDistribution for Weibull {
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        let z = x / self.scale;
+        (self.shape / self.scale).ln() + xlnx(self.shape - 1.0, z) - z.powf(self.shape)
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        -(-(x / self.scale).powf(self.shape)).exp_m1()
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 1.0;
+        }
+        (-(x / self.scale).powf(self.shape)).exp()
+    }
+
+    fn inverse_cdf(&self, p: f64) -> Result<f64, DistributionErr> {
+        check_probability(p)?;
+        Ok(self.scale * (-(-p).ln_1p()).powf(1.0 / self.shape))
+    }
+
+    /// $\lambda \Gamma(1 + 1/k)$
+    fn mean(&self) -> f64 {
+        self.scale * ln_gamma(1.0 + 1.0 / self.shape).exp()
+    }
+
+    /// $\lambda^2 [\Gamma(1 + 2/k) - \Gamma(1 + 1/k)^2]$
+    fn variance(&self) -> f64 {
+        let g1 = ln_gamma(1.0 + 1.0 / self.shape).exp();
+        let g2 = ln_gamma(1.0 + 2.0 / self.shape).exp();
+        self.scale * self.scale * (g2 - g1 * g1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::probability_distribution::assert_close;
+
+    #[test]
+    fn weibull_test() {
+        // mpmath with the formulas above
+        let w = Weibull::new(1.5, 2.0).unwrap();
+        assert_close(w.pdf(1.0), 0.372391688219422, 1e-14);
+        assert_close(w.cdf(1.0), 0.2978114986734404, 1e-14);
+        assert_close(w.sf(1.0), 1.0 - 0.2978114986734404, 1e-14);
+        assert_close(w.inverse_cdf(0.2978114986734404).unwrap(), 1.0, 1e-14);
+        assert_close(w.mean(), 1.8054905859018672, 1e-13);
+        assert_close(w.variance(), 1.502761139255728, 1e-13);
+        assert_eq!((w.pdf(0.0), w.cdf(0.0)), (0.0, 0.0));
+
+        // shape 1 is exponential distribution
+        let e = Weibull::new(1.0, 0.5).unwrap();
+        assert_close(e.pdf(0.0), 2.0, 1e-15);
+        assert_close(e.variance(), 0.25, 1e-14);
+        assert_eq!(
+            Weibull::new(1.0, 0.0),
+            Err(DistributionErr::InvalidParameter)
+        );
+    }
+}
@@ -0,0 +1,244 @@
+/*!
+Shamir's secret sharing on $GF(2^8)$.
+
+Every byte of the secret is shared independently: byte $s$ becomes the constant term of a random polynomial of
+degree $k - 1$, and share $i$ stores the evaluation of that polynomial at $x_i \neq 0$.
+
+$$
+f(x) = s + a_{1} x + a_{2} x^{2} + \cdots + a_{k-1} x^{k-1}
+$$
+
+Any $k$ shares recover $s$ by Lagrange interpolation at zero, fewer than $k$ shares reveal nothing about it.
+
+$$
+s = f(0) = \sum_{i=1}^{k} y_{i} \prod_{j \neq i} \frac{x_{j}}{x_{j} - x_{i}}
+$$
+*/
+use crate::galois_field::gf_u8::{Gf2p, GF2P8_IRREDUCIBLE_POLYNOMIAL_AES};
+use rand::Rng;
+
+/// One share of a secret: the x coordinate it was evaluated at and one y coordinate per secret byte.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Share {
+    x: u8,
+    y: Vec<u8>,
+}
+
+impl Share {
+    pub fn new(x: u8, y: Vec<u8>) -> Share {
+        Share { x, y }
+    }
+
+    pub fn x(&self) -> u8 {
+        self.x
+    }
+
+    pub fn y(&self) -> &[u8] {
+        &self.y
+    }
+}
+
+pub struct ShamirSecretSharing {
+    threshold: u8,
+    share_count: u8,
+    gf: Gf2p,
+}
+
+impl ShamirSecretSharing {
+    /**
+    Create new secret sharing scheme.
+    threshold: how many shares are needed to recover the secret.
+    share_count: how many shares a secret is split into, share x coordinates are 1..=share_count.
+    */
+    pub fn new(threshold: u8, share_count: u8) -> anyhow::Result<ShamirSecretSharing> {
+        if threshold == 0 {
+            return Err(anyhow::anyhow!("threshold must > 0"));
+        }
+        if share_count < threshold {
+            return Err(anyhow::anyhow!(
+                "share_count {} must >= threshold {}",
+                share_count,
+                threshold
+            ));
+        }
+        let gf = Gf2p::new(8, GF2P8_IRREDUCIBLE_POLYNOMIAL_AES)?;
+        Ok(ShamirSecretSharing {
+            threshold,
+            share_count,
+            gf,
+        })
+    }
+
+    pub fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    pub fn share_count(&self) -> u8 {
+        self.share_count
+    }
+
+    /// Split secret into shares, random coefficients come from thread rng.
+    pub fn split(&self, secret: &[u8]) -> Vec<Share> {
+        self.split_with_rng(secret, &mut rand::thread_rng())
+    }
+
+    /// Split secret into shares, random coefficients come from given rng.
+    pub fn split_with_rng<R: Rng + ?Sized>(&self, secret: &[u8], rng: &mut R) -> Vec<Share> {
+        let mut shares: Vec<Share> = (1..=self.share_count)
+            .map(|x| Share::new(x, Vec::with_capacity(secret.len())))
+            .collect();
+
+        // coefficients[0] is the secret byte, the others are random
+        let mut coefficients = vec![0u8; self.threshold as usize];
+        for s in secret {
+            coefficients[0] = *s;
+            rng.fill(&mut coefficients[1..]);
+            for share in shares.iter_mut() {
+                share.y.push(self.evaluate(&coefficients, share.x));
+            }
+        }
+        shares
+    }
+
+    /**
+    Recover secret from shares, the first `threshold` shares are used.
+    All shares must have distinct non-zero x coordinates and the same length.
+    */
+    pub fn recover(&self, shares: &[Share]) -> anyhow::Result<Vec<u8>> {
+        if shares.len() < self.threshold as usize {
+            return Err(anyhow::anyhow!(
+                "{} shares given, at least {} needed",
+                shares.len(),
+                self.threshold
+            ));
+        }
+        let shares = &shares[..self.threshold as usize];
+        let secret_len = shares[0].y.len();
+        for (i, share) in shares.iter().enumerate() {
+            if share.x == 0 {
+                return Err(anyhow::anyhow!("share x coordinate cannot be 0"));
+            }
+            if share.y.len() != secret_len {
+                return Err(anyhow::anyhow!(
+                    "share length {} != {}",
+                    share.y.len(),
+                    secret_len
+                ));
+            }
+            if shares[..i].iter().any(|other| other.x == share.x) {
+                return Err(anyhow::anyhow!("duplicated share x coordinate {}", share.x));
+            }
+        }
+
+        // Lagrange basis polynomials evaluated at 0, they don't depend on the secret byte.
+        // on GF(2^w), x_j - x_i is x_j xor x_i
+        let basis: Vec<u8> = shares
+            .iter()
+            .map(|share_i| {
+                shares
+                    .iter()
+                    .filter(|share_j| share_j.x != share_i.x)
+                    .fold(1u8, |acc, share_j| {
+                        let term = self
+                            .gf
+                            .div_by_power_log_table(share_j.x, self.gf.sub(share_j.x, share_i.x));
+                        self.gf.mul_by_power_log_table(acc, term)
+                    })
+            })
+            .collect();
+
+        let mut secret = vec![0u8; secret_len];
+        for (k, s) in secret.iter_mut().enumerate() {
+            *s = shares
+                .iter()
+                .zip(basis.iter())
+                .fold(0u8, |acc, (share, l)| {
+                    self.gf
+                        .add(acc, self.gf.mul_by_power_log_table(share.y[k], *l))
+                });
+        }
+        Ok(secret)
+    }
+
+    /// Evaluate polynomial at x by Horner's method, coefficients[i] is the coefficient of x^i.
+    fn evaluate(&self, coefficients: &[u8], x: u8) -> u8 {
+        coefficients.iter().rev().fold(0u8, |acc, c| {
+            self.gf.add(self.gf.mul_by_power_log_table(acc, x), *c)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn shamir_new_test() {
+        assert!(ShamirSecretSharing::new(0, 3).is_err());
+        assert!(ShamirSecretSharing::new(4, 3).is_err());
+        assert!(ShamirSecretSharing::new(3, 3).is_ok());
+        assert!(ShamirSecretSharing::new(1, 255).is_ok());
+    }
+
+    #[test]
+    fn shamir_split_recover_test() {
+        let secret = b"correct horse battery staple".to_vec();
+        let sss = ShamirSecretSharing::new(3, 5).unwrap();
+        let mut rng = StdRng::seed_from_u64(26);
+        let shares = sss.split_with_rng(&secret, &mut rng);
+        assert_eq!(shares.len(), 5);
+
+        // every combination of 3 shares recovers the secret
+        for a in 0..5 {
+            for b in 0..5 {
+                for c in 0..5 {
+                    if a == b || b == c || a == c {
+                        continue;
+                    }
+                    let subset = vec![shares[a].clone(), shares[b].clone(), shares[c].clone()];
+                    assert_eq!(sss.recover(&subset).unwrap(), secret);
+                }
+            }
+        }
+
+        // more shares than threshold also work
+        assert_eq!(sss.recover(&shares).unwrap(), secret);
+    }
+
+    #[test]
+    fn shamir_threshold_one_test() {
+        let secret = vec![0x00, 0x01, 0xFE, 0xFF];
+        let sss = ShamirSecretSharing::new(1, 3).unwrap();
+        let shares = sss.split(&secret);
+        for share in shares.iter() {
+            assert_eq!(share.y(), secret.as_slice());
+            assert_eq!(sss.recover(std::slice::from_ref(share)).unwrap(), secret);
+        }
+    }
+
+    #[test]
+    fn shamir_recover_err_test() {
+        let sss = ShamirSecretSharing::new(2, 3).unwrap();
+        let shares = sss.split(&[1, 2, 3]);
+
+        assert!(sss.recover(&shares[..1]).is_err());
+        assert!(sss
+            .recover(&[shares[0].clone(), shares[0].clone()])
+            .is_err());
+        assert!(sss
+            .recover(&[Share::new(0, vec![1, 2, 3]), shares[1].clone()])
+            .is_err());
+        assert!(sss
+            .recover(&[shares[0].clone(), Share::new(2, vec![1, 2])])
+            .is_err());
+    }
+
+    #[test]
+    fn shamir_empty_secret_test() {
+        let sss = ShamirSecretSharing::new(2, 2).unwrap();
+        let shares = sss.split(&[]);
+        assert_eq!(sss.recover(&shares).unwrap(), Vec::<u8>::new());
+    }
+}
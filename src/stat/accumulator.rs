@@ -0,0 +1,264 @@
+/*!
+Online statistics accumulator, ingests values one at a time in $O(1)$ memory.
+
+Central moment sums $M_k = \sum_{i=1}^{n}(x_i - \bar{x})^k$ are updated by Terriberry's extension of Welford's algorithm,
+which doesn't lose precision like the naive $\sum x^2 - n\bar{x}^2$ when values are far from 0.
+Accumulators of two parts are merged by Pébay's formulas, so parts can be reduced in parallel.
+*/
+use crate::stat::{StatErr, VarianceKind};
+use std::iter::FromIterator;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StatAccumulator {
+    n: u64,
+    mean: f64,
+    m2: f64,
+    m3: f64,
+    m4: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Default for StatAccumulator {
+    fn default() -> StatAccumulator {
+        StatAccumulator::new()
+    }
+}
+
+impl StatAccumulator {
+    pub fn new() -> StatAccumulator {
+        StatAccumulator {
+            n: 0,
+            mean: 0.0,
+            m2: 0.0,
+            m3: 0.0,
+            m4: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// Add a value, NaN makes all moments NaN but is ignored by min and max.
+    pub fn push(&mut self, x: f64) {
+        let n1 = self.n as f64;
+        self.n += 1;
+        let n = self.n as f64;
+        let delta = x - self.mean;
+        let delta_n = delta / n;
+        let delta_n2 = delta_n * delta_n;
+        let term1 = delta * delta_n * n1;
+        self.mean += delta_n;
+        self.m4 += term1 * delta_n2 * (n * n - 3.0 * n + 3.0) + 6.0 * delta_n2 * self.m2
+            - 4.0 * delta_n * self.m3;
+        self.m3 += term1 * delta_n * (n - 2.0) - 3.0 * delta_n * self.m2;
+        self.m2 += term1;
+        self.min = self.min.min(x);
+        self.max = self.max.max(x);
+    }
+
+    /// Merge accumulator of another part of values, result is the same as pushing them all into one.
+    pub fn merge(&mut self, other: &StatAccumulator) {
+        if other.n == 0 {
+            return;
+        }
+        if self.n == 0 {
+            *self = *other;
+            return;
+        }
+        let (na, nb) = (self.n as f64, other.n as f64);
+        let n = na + nb;
+        let delta = other.mean - self.mean;
+        let delta2 = delta * delta;
+        let delta3 = delta2 * delta;
+        let delta4 = delta2 * delta2;
+
+        let m2 = self.m2 + other.m2 + delta2 * na * nb / n;
+        let m3 = self.m3
+            + other.m3
+            + delta3 * na * nb * (na - nb) / (n * n)
+            + 3.0 * delta * (na * other.m2 - nb * self.m2) / n;
+        let m4 = self.m4
+            + other.m4
+            + delta4 * na * nb * (na * na - na * nb + nb * nb) / (n * n * n)
+            + 6.0 * delta2 * (na * na * other.m2 + nb * nb * self.m2) / (n * n)
+            + 4.0 * delta * (na * other.m3 - nb * self.m3) / n;
+
+        self.n += other.n;
+        self.mean += delta * nb / n;
+        self.m2 = m2;
+        self.m3 = m3;
+        self.m4 = m4;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+
+    pub fn count(&self) -> u64 {
+        self.n
+    }
+
+    pub fn mean(&self) -> Result<f64, StatErr> {
+        self.non_empty()?;
+        Ok(self.mean)
+    }
+
+    /// Sample variance with divisor $n - 1$, `StatErr::TooFew` if count is 1.
+    pub fn variance(&self) -> Result<f64, StatErr> {
+        self.variance_by(VarianceKind::Sample)
+    }
+
+    /// Sample standard deviation, `StatErr::TooFew` if count is 1.
+    pub fn stddev(&self) -> Result<f64, StatErr> {
+        self.stddev_by(VarianceKind::Sample)
+    }
+
+    /// Variance with divisor of kind like `StatBasic::u_variance_by`.
+    pub fn variance_by(&self, kind: VarianceKind) -> Result<f64, StatErr> {
+        self.non_empty()?;
+        let divisor = match kind {
+            VarianceKind::Population => self.n,
+            VarianceKind::Sample if self.n > 1 => self.n - 1,
+            VarianceKind::Sample => return Err(StatErr::TooFew),
+        };
+        Ok(self.m2 / divisor as f64)
+    }
+
+    /// Standard deviation with divisor of kind like `StatBasic::u_stddev_by`.
+    pub fn stddev_by(&self, kind: VarianceKind) -> Result<f64, StatErr> {
+        self.variance_by(kind).map(f64::sqrt)
+    }
+
+    /// Skewness of population like `StatBasic::u_skewness`.
+    pub fn skewness(&self) -> Result<f64, StatErr> {
+        self.non_zero_variance()?;
+        let n = self.n as f64;
+        Ok(n.sqrt() * self.m3 / self.m2.powf(1.5))
+    }
+
+    /// Excess kurtosis of population like `StatBasic::u_kurtosis`.
+    pub fn kurtosis(&self) -> Result<f64, StatErr> {
+        self.non_zero_variance()?;
+        let n = self.n as f64;
+        Ok(n * self.m4 / (self.m2 * self.m2) - 3.0)
+    }
+
+    pub fn min(&self) -> Result<f64, StatErr> {
+        self.non_empty()?;
+        Ok(self.min)
+    }
+
+    pub fn max(&self) -> Result<f64, StatErr> {
+        self.non_empty()?;
+        Ok(self.max)
+    }
+
+    fn non_empty(&self) -> Result<(), StatErr> {
+        if self.n == 0 {
+            Err(StatErr::Empty)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn non_zero_variance(&self) -> Result<(), StatErr> {
+        self.non_empty()?;
+        if self.m2 == 0.0 {
+            Err(StatErr::ZeroVariance)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Extend<f64> for StatAccumulator {
+    fn extend<I: IntoIterator<Item = f64>>(&mut self, iter: I) {
+        for x in iter {
+            self.push(x);
+        }
+    }
+}
+
+impl FromIterator<f64> for StatAccumulator {
+    fn from_iter<I: IntoIterator<Item = f64>>(iter: I) -> StatAccumulator {
+        let mut res = StatAccumulator::new();
+        res.extend(iter);
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stat::assert_close;
+    use crate::stat::StatBasic;
+
+    const EPSILON: f64 = 1e-9;
+
+    #[test]
+    fn stat_accumulator_test() {
+        let x = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0, -3.5, 11.25];
+        let acc: StatAccumulator = x.iter().copied().collect();
+        let x = x.as_ref();
+        assert_eq!(acc.count(), 10);
+        assert_close(acc.mean().unwrap(), x.u_checked_mean().unwrap(), EPSILON);
+        assert_close(
+            acc.variance().unwrap(),
+            x.u_variance_by(VarianceKind::Sample).unwrap(),
+            EPSILON,
+        );
+        assert_close(
+            acc.stddev().unwrap(),
+            x.u_stddev_by(VarianceKind::Sample).unwrap(),
+            EPSILON,
+        );
+        assert_close(
+            acc.stddev_by(VarianceKind::Population).unwrap(),
+            x.u_stddev_by(VarianceKind::Population).unwrap(),
+            EPSILON,
+        );
+        assert_close(acc.skewness().unwrap(), x.u_skewness().unwrap(), EPSILON);
+        assert_close(acc.kurtosis().unwrap(), x.u_kurtosis().unwrap(), EPSILON);
+        assert_eq!(acc.min(), Ok(-3.5));
+        assert_eq!(acc.max(), Ok(11.25));
+    }
+
+    #[test]
+    fn stat_accumulator_merge_test() {
+        let x: Vec<f64> = (0..100).map(|i| ((i * 37) % 101) as f64 / 7.0).collect();
+        let all: StatAccumulator = x.iter().copied().collect();
+        for split in [0, 1, 30, 99, 100].iter() {
+            let mut a: StatAccumulator = x[..*split].iter().copied().collect();
+            let b: StatAccumulator = x[*split..].iter().copied().collect();
+            a.merge(&b);
+            assert_eq!(a.count(), all.count());
+            assert_close(a.mean().unwrap(), all.mean().unwrap(), EPSILON);
+            assert_close(a.variance().unwrap(), all.variance().unwrap(), EPSILON);
+            assert_close(a.skewness().unwrap(), all.skewness().unwrap(), EPSILON);
+            assert_close(a.kurtosis().unwrap(), all.kurtosis().unwrap(), EPSILON);
+            assert_eq!(a.min(), all.min());
+            assert_eq!(a.max(), all.max());
+        }
+    }
+
+    #[test]
+    fn stat_accumulator_stability_test() {
+        // naive sum of squares loses all digits of variance 30 at this offset
+        let acc: StatAccumulator = [4.0, 7.0, 13.0, 16.0].iter().map(|x| 1e9 + x).collect();
+        assert_eq!(acc.mean(), Ok(1e9 + 10.0));
+        assert_eq!(acc.variance(), Ok(30.0));
+        assert!(acc.skewness().unwrap().abs() <= EPSILON);
+    }
+
+    #[test]
+    fn stat_accumulator_empty_test() {
+        let mut acc = StatAccumulator::default();
+        assert_eq!(acc.mean(), Err(StatErr::Empty));
+        assert_eq!(acc.variance(), Err(StatErr::Empty));
+        assert_eq!(acc.min(), Err(StatErr::Empty));
+        assert_eq!(acc.kurtosis(), Err(StatErr::Empty));
+        acc.push(1.0);
+        assert_eq!(acc.variance(), Err(StatErr::TooFew));
+        assert_eq!(acc.stddev(), Err(StatErr::TooFew));
+        assert_eq!(acc.variance_by(VarianceKind::Population), Ok(0.0));
+        assert_eq!(acc.skewness(), Err(StatErr::ZeroVariance));
+    }
+}
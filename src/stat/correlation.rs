@@ -0,0 +1,301 @@
+/*!
+Covariance and correlation matrices of several series, element $(i, j)$ is of series i and j.
+
+NaN values are handled pairwise-complete like pandas: every pair of series uses the observations
+where both are not NaN, so different elements may use different observations.
+Elements which don't have enough observations, or whose correlation has 0 variance, are NaN.
+*/
+use crate::matrix::matrix::Matrix;
+use crate::stat::{StatErr, VarianceKind};
+use std::cmp::Ordering;
+
+/// Correlation coefficients.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CorrelationMethod {
+    /// Linear correlation of values.
+    Pearson,
+    /// Pearson correlation of ranks, ties get their average rank.
+    Spearman,
+    /// Kendall's $\tau_b$, which counts concordant and discordant pairs and adjusts for ties.
+    Kendall,
+}
+
+/// Covariance matrix of equal-length series, sample one needs 2 observations for every element.
+pub fn covariance_matrix<S: AsRef<[f64]>>(
+    series: &[S],
+    kind: VarianceKind,
+) -> Result<Matrix<f64>, StatErr> {
+    pairwise(series, |x, y| covariance(x, y, kind))
+}
+
+/// Correlation matrix of equal-length series, diagonal is 1 for series with non-zero variance.
+pub fn correlation_matrix<S: AsRef<[f64]>>(
+    series: &[S],
+    method: CorrelationMethod,
+) -> Result<Matrix<f64>, StatErr> {
+    match method {
+        CorrelationMethod::Pearson => pairwise(series, pearson),
+        CorrelationMethod::Spearman => pairwise(series, |x, y| pearson(&ranks(x), &ranks(y))),
+        CorrelationMethod::Kendall => pairwise(series, kendall),
+    }
+}
+
+/// Covariance matrix of series in columns of m, every row is an observation.
+pub fn covariance_matrix_of_columns(
+    m: &Matrix<f64>,
+    kind: VarianceKind,
+) -> Result<Matrix<f64>, StatErr> {
+    covariance_matrix(&columns(m), kind)
+}
+
+/// Correlation matrix of series in columns of m, every row is an observation.
+pub fn correlation_matrix_of_columns(
+    m: &Matrix<f64>,
+    method: CorrelationMethod,
+) -> Result<Matrix<f64>, StatErr> {
+    correlation_matrix(&columns(m), method)
+}
+
+fn columns(m: &Matrix<f64>) -> Vec<Vec<f64>> {
+    (0..m.col_size()).map(|col| m.column(col)).collect()
+}
+
+/// Symmetric matrix of f on complete observations of every pair of series.
+fn pairwise<S, F>(series: &[S], f: F) -> Result<Matrix<f64>, StatErr>
+where
+    S: AsRef<[f64]>,
+    F: Fn(&[f64], &[f64]) -> f64,
+{
+    if series.is_empty() {
+        return Err(StatErr::Empty);
+    }
+    let len = series[0].as_ref().len();
+    if series.iter().any(|s| s.as_ref().len() != len) {
+        return Err(StatErr::LengthMismatch);
+    }
+
+    let n = series.len();
+    let mut data = vec![vec![0.0; n]; n];
+    for i in 0..n {
+        for j in i..n {
+            let (x, y): (Vec<f64>, Vec<f64>) = series[i]
+                .as_ref()
+                .iter()
+                .zip(series[j].as_ref())
+                .filter(|(a, b)| !a.is_nan() && !b.is_nan())
+                .unzip();
+            data[i][j] = f(&x, &y);
+            data[j][i] = data[i][j];
+        }
+    }
+    Ok(Matrix::new_from_vec(data))
+}
+
+fn covariance(x: &[f64], y: &[f64], kind: VarianceKind) -> f64 {
+    let n = x.len();
+    let divisor = match kind {
+        VarianceKind::Population if n > 0 => n,
+        VarianceKind::Sample if n > 1 => n - 1,
+        _ => return f64::NAN,
+    };
+    let mx = x.iter().sum::<f64>() / n as f64;
+    let my = y.iter().sum::<f64>() / n as f64;
+    x.iter()
+        .zip(y)
+        .map(|(a, b)| (a - mx) * (b - my))
+        .sum::<f64>()
+        / divisor as f64
+}
+
+fn pearson(x: &[f64], y: &[f64]) -> f64 {
+    let (sxy, sxx, syy) = (
+        covariance(x, y, VarianceKind::Population),
+        covariance(x, x, VarianceKind::Population),
+        covariance(y, y, VarianceKind::Population),
+    );
+    if x.len() < 2 || sxx == 0.0 || syy == 0.0 {
+        return f64::NAN;
+    }
+    // rounding may push it out of [-1, 1] slightly
+    (sxy / (sxx * syy).sqrt()).clamp(-1.0, 1.0)
+}
+
+/// Ranks from 1, ties get the average of their ranks.
+pub(crate) fn ranks(values: &[f64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|a, b| {
+        values[*a]
+            .partial_cmp(&values[*b])
+            .unwrap_or(Ordering::Equal)
+    });
+    let mut res = vec![0.0; values.len()];
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start + 1;
+        while end < order.len() && values[order[end]] == values[order[start]] {
+            end += 1;
+        }
+        // ranks start + 1 ..= end
+        let rank = (start + 1 + end) as f64 / 2.0;
+        for i in order[start..end].iter() {
+            res[*i] = rank;
+        }
+        start = end;
+    }
+    res
+}
+
+/**
+Kendall's $\tau_b$ in $O(n^2)$
+$$
+\tau_b = \frac{n_c - n_d}{\sqrt{(n_0 - n_x)(n_0 - n_y)}}
+$$
+where $n_0 = n(n-1)/2$ and $n_x, n_y$ are pairs tied in x or y.
+*/
+fn kendall(x: &[f64], y: &[f64]) -> f64 {
+    let n = x.len();
+    let (mut concordant, mut discordant, mut tied_x, mut tied_y) = (0i64, 0i64, 0i64, 0i64);
+    for i in 0..n {
+        for j in i + 1..n {
+            let dx = x[i] - x[j];
+            let dy = y[i] - y[j];
+            if dx == 0.0 {
+                tied_x += 1;
+            }
+            if dy == 0.0 {
+                tied_y += 1;
+            }
+            if dx * dy > 0.0 {
+                concordant += 1;
+            } else if dx * dy < 0.0 {
+                discordant += 1;
+            }
+        }
+    }
+    let n0 = (n * n.saturating_sub(1) / 2) as i64;
+    let denom = (((n0 - tied_x) * (n0 - tied_y)) as f64).sqrt();
+    if denom == 0.0 {
+        return f64::NAN;
+    }
+    (concordant - discordant) as f64 / denom
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stat::assert_close;
+    use crate::stat::StatBasic;
+
+    const EPSILON: f64 = 1e-12;
+
+    #[test]
+    fn covariance_matrix_test() {
+        let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let y = [2.0, 4.0, 5.0, 4.0, 5.0];
+        let cov = covariance_matrix(&[x, y], VarianceKind::Sample).unwrap();
+        assert_eq!((cov.row_size(), cov.col_size()), (2, 2));
+        assert_close(
+            cov.get(0, 0),
+            x.as_ref().u_variance_by(VarianceKind::Sample).unwrap(),
+            EPSILON,
+        );
+        assert_close(
+            cov.get(1, 1),
+            y.as_ref().u_variance_by(VarianceKind::Sample).unwrap(),
+            EPSILON,
+        );
+        assert_close(cov.get(0, 1), 6.0 / 4.0, EPSILON);
+        assert_eq!(cov.get(0, 1), cov.get(1, 0));
+
+        let cov = covariance_matrix(&[x, y], VarianceKind::Population).unwrap();
+        assert_close(cov.get(0, 1), 6.0 / 5.0, EPSILON);
+    }
+
+    #[test]
+    fn correlation_matrix_test() {
+        let x = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        let y = vec![2.0, 4.0, 5.0, 4.0, 5.0];
+        let z: Vec<f64> = x.iter().map(|v| -v * v).collect();
+        let series = [x, y, z];
+        let cases = [
+            (CorrelationMethod::Pearson, 6.0 / 60f64.sqrt()),
+            // ranks of y are 1, 2.5, 4.5, 2.5, 4.5
+            (CorrelationMethod::Spearman, 7.0 / 90f64.sqrt()),
+            // 7 concordant, 1 discordant, 2 pairs tied in y
+            (CorrelationMethod::Kendall, 6.0 / 80f64.sqrt()),
+        ];
+        for (method, want) in cases.iter() {
+            let corr = correlation_matrix(&series, *method).unwrap();
+            assert_close(corr.get(0, 1), *want, EPSILON);
+            assert_close(corr.get(1, 0), *want, EPSILON);
+            assert_close(corr.get(0, 0), 1.0, EPSILON);
+            assert_close(corr.get(2, 2), 1.0, EPSILON);
+        }
+        // monotonic but not linear
+        let pearson = correlation_matrix(&series, CorrelationMethod::Pearson).unwrap();
+        assert!(pearson.get(0, 2) > -1.0 + 1e-3);
+        let spearman = correlation_matrix(&series, CorrelationMethod::Spearman).unwrap();
+        assert_close(spearman.get(0, 2), -1.0, EPSILON);
+        let kendall = correlation_matrix(&series, CorrelationMethod::Kendall).unwrap();
+        assert_close(kendall.get(0, 2), -1.0, EPSILON);
+    }
+
+    #[test]
+    fn correlation_matrix_nan_test() {
+        let nan = f64::NAN;
+        let x = vec![1.0, 2.0, nan, 3.0, 4.0, 5.0];
+        let y = vec![2.0, 4.0, 1.0, 5.0, 4.0, nan];
+        let w = vec![nan, nan, nan, nan, nan, 1.0];
+        let corr = correlation_matrix(&[&x, &y, &w], CorrelationMethod::Pearson).unwrap();
+        // observations 0, 1, 3 and 4 of both
+        let want = correlation_matrix(
+            &[[1.0, 2.0, 3.0, 4.0], [2.0, 4.0, 5.0, 4.0]],
+            CorrelationMethod::Pearson,
+        )
+        .unwrap();
+        assert_close(corr.get(0, 1), want.get(0, 1), EPSILON);
+        assert!(corr.get(0, 2).is_nan());
+        assert!(corr.get(2, 2).is_nan());
+
+        let cov = covariance_matrix(&[&x, &w], VarianceKind::Sample).unwrap();
+        assert_close(
+            cov.get(0, 0),
+            [1.0, 2.0, 3.0, 4.0, 5.0]
+                .as_ref()
+                .u_variance_by(VarianceKind::Sample)
+                .unwrap(),
+            EPSILON,
+        );
+        assert!(cov.get(1, 1).is_nan());
+        let cov = covariance_matrix(&[&x, &w], VarianceKind::Population).unwrap();
+        assert_eq!(cov.get(1, 1), 0.0);
+    }
+
+    #[test]
+    fn correlation_matrix_of_columns_test() {
+        let m = Matrix::new_from_vec(vec![
+            vec![1.0, 2.0],
+            vec![2.0, 4.0],
+            vec![3.0, 5.0],
+            vec![4.0, 4.0],
+            vec![5.0, 5.0],
+        ]);
+        let corr = correlation_matrix_of_columns(&m, CorrelationMethod::Pearson).unwrap();
+        assert_close(corr.get(0, 1), 6.0 / 60f64.sqrt(), EPSILON);
+        let cov = covariance_matrix_of_columns(&m, VarianceKind::Sample).unwrap();
+        assert_close(cov.get(0, 1), 1.5, EPSILON);
+    }
+
+    #[test]
+    fn correlation_matrix_err_test() {
+        let empty: [Vec<f64>; 0] = [];
+        assert_eq!(
+            covariance_matrix(&empty, VarianceKind::Sample).err(),
+            Some(StatErr::Empty)
+        );
+        assert_eq!(
+            correlation_matrix(&[vec![1.0, 2.0], vec![1.0]], CorrelationMethod::Pearson).err(),
+            Some(StatErr::LengthMismatch)
+        );
+    }
+}
@@ -0,0 +1,370 @@
+/*!
+Histograms and kernel density estimation of empirical distributions.
+
+Both reject NaN values with `StatErr::NaN` and infinite values with `StatErr::InvalidParameter`,
+because the range of data must be finite.
+*/
+use crate::probability_distribution::normal_distribution;
+use crate::stat::{sorted, sorted_quantile, QuantileMethod, StatErr};
+
+/// Most bins a histogram may have, more are rejected like numpy's "too many bins".
+pub const MAX_BINS: usize = 1 << 20;
+
+/// Rules of histogram bin width h for n values.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BinRule {
+    /// Fixed width h > 0, bins start at the minimum.
+    Width(f64),
+    /// $\lceil \log_2 n \rceil + 1$ bins of equal width, assumes roughly normal data.
+    Sturges,
+    /// $h = 2 \, IQR / \sqrt[3]{n}$, robust to outliers, falls back to Sturges' rule if IQR is 0.
+    FreedmanDiaconis,
+}
+
+/// Histogram with bin i of $[e_i, e_{i+1})$, the last bin includes its upper edge.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    /// Bin edges, one more than counts.
+    pub edges: Vec<f64>,
+    pub counts: Vec<usize>,
+}
+
+impl Histogram {
+    /// Count of bin divided by number of values and bin width, so the area is 1.
+    pub fn density(&self) -> Vec<f64> {
+        let n = self.counts.iter().sum::<usize>() as f64;
+        self.counts
+            .iter()
+            .zip(self.edges.windows(2))
+            .map(|(c, e)| *c as f64 / (n * (e[1] - e[0])))
+            .collect()
+    }
+}
+
+/**
+Histogram of values binned by rule, a single distinct value gets one bin of width 1 around it like numpy.
+
+Error `StatErr::InvalidParameter` if the width is not positive, or gives more than `MAX_BINS` bins.
+*/
+pub fn histogram(x: &[f64], rule: BinRule) -> Result<Histogram, StatErr> {
+    if let BinRule::Width(width) = rule {
+        if !(width.is_finite() && width > 0.0) {
+            return Err(StatErr::InvalidParameter);
+        }
+    }
+    let sorted = finite_sorted(x)?;
+    let (min, max) = (sorted[0], sorted[sorted.len() - 1]);
+    if min == max {
+        return Ok(Histogram {
+            edges: vec![min - 0.5, max + 0.5],
+            counts: vec![sorted.len()],
+        });
+    }
+    let edges = match rule {
+        BinRule::Width(width) => {
+            let bins = width_bins(min, max, width)?;
+            (0..=bins).map(|i| min + i as f64 * width).collect()
+        }
+        BinRule::Sturges => equal_edges(min, max, sturges_bins(sorted.len())),
+        BinRule::FreedmanDiaconis => {
+            let width = 2.0 * iqr(&sorted) / (sorted.len() as f64).cbrt();
+            let bins = if width > 0.0 {
+                width_bins(min, max, width)?
+            } else {
+                sturges_bins(sorted.len())
+            };
+            equal_edges(min, max, bins)
+        }
+    };
+
+    let mut counts = vec![0; edges.len() - 1];
+    let last = counts.len() - 1;
+    let width = (edges[last + 1] - edges[0]) / counts.len() as f64;
+    for v in sorted.iter() {
+        // estimate by width, then correct rounding against the edges
+        let mut i = (((v - edges[0]) / width) as usize).min(last);
+        if i > 0 && *v < edges[i] {
+            i -= 1;
+        } else if i < last && *v >= edges[i + 1] {
+            i += 1;
+        }
+        counts[i] += 1;
+    }
+    Ok(Histogram { edges, counts })
+}
+
+/// Kernel functions K(u) of density estimation, scaled to standard deviation 1.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Kernel {
+    /// Standard normal density.
+    Gaussian,
+    /// $\frac{3}{4\sqrt{5}} (1 - u^2 / 5)$ on $|u| \leq \sqrt{5}$, optimal in mean integrated squared error.
+    Epanechnikov,
+}
+
+/**
+Bandwidth h, the standard deviation of kernel, for n values with sample standard deviation s.
+
+The rules are optimal for normal data and oversmooth multimodal data.
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Bandwidth {
+    /// Fixed h > 0.
+    Fixed(f64),
+    /// Silverman's rule of thumb $h = 0.9 \min(s, IQR / 1.34) n^{-1/5}$, R's `bw.nrd0`.
+    Silverman,
+    /// Scott's rule $h = 1.06 \, s \, n^{-1/5}$.
+    Scott,
+}
+
+/**
+Kernel density estimate of values $x_i$ with kernel K and bandwidth h
+$$
+\hat{f}(x) = \frac{1}{nh} \sum_{i=1}^n K\left(\frac{x - x_i}{h}\right)
+$$
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct KernelDensity {
+    data: Vec<f64>,
+    kernel: Kernel,
+    bandwidth: f64,
+}
+
+impl KernelDensity {
+    /// Error if there are fewer than 2 values, or the rule of bandwidth gets 0 for equal values.
+    pub fn new(x: &[f64], kernel: Kernel, bandwidth: Bandwidth) -> Result<KernelDensity, StatErr> {
+        let data = finite_sorted(x)?;
+        if data.len() < 2 {
+            return Err(StatErr::TooFew);
+        }
+        let n = data.len() as f64;
+        let mean = data.iter().sum::<f64>() / n;
+        let s = (data.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / (n - 1.0)).sqrt();
+        let bandwidth = match bandwidth {
+            Bandwidth::Fixed(h) => {
+                if !(h.is_finite() && h > 0.0) {
+                    return Err(StatErr::InvalidParameter);
+                }
+                h
+            }
+            Bandwidth::Silverman => {
+                let spread = iqr(&data) / 1.34;
+                // IQR is 0 for data concentrated on a few values
+                let spread = if spread > 0.0 { s.min(spread) } else { s };
+                0.9 * spread * n.powf(-0.2)
+            }
+            Bandwidth::Scott => 1.06 * s * n.powf(-0.2),
+        };
+        if bandwidth == 0.0 {
+            return Err(StatErr::ZeroVariance);
+        }
+        Ok(KernelDensity {
+            data,
+            kernel,
+            bandwidth,
+        })
+    }
+
+    pub fn kernel(&self) -> Kernel {
+        self.kernel
+    }
+
+    pub fn bandwidth(&self) -> f64 {
+        self.bandwidth
+    }
+
+    /// Estimated density at x.
+    pub fn density(&self, x: f64) -> f64 {
+        let h = self.bandwidth;
+        let sum = match self.kernel {
+            Kernel::Gaussian => self
+                .data
+                .iter()
+                .map(|v| normal_distribution(x, *v, h))
+                .sum::<f64>(),
+            Kernel::Epanechnikov => {
+                // support of every kernel is $x_i \pm \sqrt{5} h$
+                let a = 5f64.sqrt() * h;
+                self.data
+                    .iter()
+                    .map(|v| {
+                        let u = (x - v) / a;
+                        if u.abs() < 1.0 {
+                            0.75 * (1.0 - u * u) / a
+                        } else {
+                            0.0
+                        }
+                    })
+                    .sum::<f64>()
+            }
+        };
+        sum / self.data.len() as f64
+    }
+
+    /// Density at `points` evenly spaced x from 3 bandwidths below minimum to 3 above maximum, for plotting.
+    pub fn grid(&self, points: usize) -> Vec<(f64, f64)> {
+        let lo = self.data[0] - 3.0 * self.bandwidth;
+        let hi = self.data[self.data.len() - 1] + 3.0 * self.bandwidth;
+        let step = if points > 1 {
+            (hi - lo) / (points - 1) as f64
+        } else {
+            0.0
+        };
+        (0..points)
+            .map(|i| {
+                let x = lo + i as f64 * step;
+                (x, self.density(x))
+            })
+            .collect()
+    }
+}
+
+/// Sorted values, error if empty, any is NaN or infinite.
+fn finite_sorted(x: &[f64]) -> Result<Vec<f64>, StatErr> {
+    let sorted = sorted(x.to_vec())?;
+    if sorted.iter().any(|v| v.is_infinite()) {
+        return Err(StatErr::InvalidParameter);
+    }
+    Ok(sorted)
+}
+
+fn iqr(sorted: &[f64]) -> f64 {
+    sorted_quantile(sorted, 0.75, QuantileMethod::Linear)
+        - sorted_quantile(sorted, 0.25, QuantileMethod::Linear)
+}
+
+/// Count of bins of width to cover [min, max], error if more than `MAX_BINS`.
+fn width_bins(min: f64, max: f64, width: f64) -> Result<usize, StatErr> {
+    let bins = ((max - min) / width).ceil().max(1.0);
+    if bins > MAX_BINS as f64 {
+        return Err(StatErr::InvalidParameter);
+    }
+    Ok(bins as usize)
+}
+
+fn sturges_bins(n: usize) -> usize {
+    (n as f64).log2().ceil() as usize + 1
+}
+
+/// Edges of bins of equal width, the last one is exactly max.
+fn equal_edges(min: f64, max: f64, bins: usize) -> Vec<f64> {
+    let mut edges: Vec<f64> = (0..=bins)
+        .map(|i| min + (max - min) * i as f64 / bins as f64)
+        .collect();
+    edges[bins] = max;
+    edges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stat::assert_close;
+
+    const EPSILON: f64 = 1e-12;
+
+    const DATA: [f64; 10] = [5.0, 1.2, 2.3, 30.0, 2.9, 3.1, 3.4, 4.8, 5.5, 7.9];
+
+    #[test]
+    fn histogram_test() {
+        // ceil(log2 10) + 1 = 5 bins of [1.2, 30]
+        let h = histogram(&DATA, BinRule::Sturges).unwrap();
+        assert_eq!(h.counts, vec![8, 1, 0, 0, 1]);
+        assert_eq!((h.edges.len(), h.edges[0], h.edges[5]), (6, 1.2, 30.0));
+        assert_close(h.edges[1], 6.96, EPSILON);
+
+        // IQR is 2.425, width 2 IQR / cbrt(10) gives 13 bins
+        let h = histogram(&DATA, BinRule::FreedmanDiaconis).unwrap();
+        assert_eq!(h.counts, vec![5, 3, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+        let density = h.density();
+        assert_close(density[0], 5.0 / (10.0 * 28.8 / 13.0), EPSILON);
+        let area = density
+            .iter()
+            .zip(h.edges.windows(2))
+            .map(|(d, e)| d * (e[1] - e[0]))
+            .sum::<f64>();
+        assert_close(area, 1.0, EPSILON);
+
+        // values on edges go to the upper bin, except the maximum
+        let h = histogram(&[0.0, 1.0, 2.0, 3.0, 4.0], BinRule::Width(1.0)).unwrap();
+        assert_eq!(h.edges, vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(h.counts, vec![1, 1, 1, 2]);
+        let h = histogram(&[0.0, 0.5, 1.0, 2.2], BinRule::Width(0.5)).unwrap();
+        assert_eq!(h.edges, vec![0.0, 0.5, 1.0, 1.5, 2.0, 2.5]);
+        assert_eq!(h.counts, vec![1, 1, 1, 0, 1]);
+
+        for rule in [BinRule::Sturges, BinRule::Width(0.1)].iter() {
+            let h = histogram(&[2.0, 2.0, 2.0], *rule).unwrap();
+            assert_eq!(h.edges, vec![1.5, 2.5]);
+            assert_eq!(h.counts, vec![3]);
+        }
+        for width in [-1.0, 0.0, f64::NAN].iter() {
+            assert_eq!(
+                histogram(&[2.0, 2.0], BinRule::Width(*width)),
+                Err(StatErr::InvalidParameter)
+            );
+        }
+
+        assert_eq!(histogram(&[], BinRule::Sturges), Err(StatErr::Empty));
+        assert_eq!(
+            histogram(&[1.0, f64::NAN], BinRule::Sturges),
+            Err(StatErr::NaN)
+        );
+        assert_eq!(
+            histogram(&[1.0, f64::INFINITY], BinRule::Sturges),
+            Err(StatErr::InvalidParameter)
+        );
+        assert_eq!(
+            histogram(&DATA, BinRule::Width(0.0)),
+            Err(StatErr::InvalidParameter)
+        );
+        assert_eq!(
+            histogram(&[0.0, 1.0], BinRule::Width(1e-300)),
+            Err(StatErr::InvalidParameter)
+        );
+        let h = histogram(&[0.0, 1.0], BinRule::Width(1.0 / MAX_BINS as f64)).unwrap();
+        assert_eq!(h.counts.len(), MAX_BINS);
+    }
+
+    #[test]
+    fn kernel_density_test() {
+        // mpmath: mean of npdf(x, x_i, h)
+        let kde = KernelDensity::new(&DATA, Kernel::Gaussian, Bandwidth::Silverman).unwrap();
+        assert_close(kde.bandwidth(), 1.027660003229867, EPSILON);
+        assert_close(kde.density(4.0), 0.1581735319242297, EPSILON);
+        let kde = KernelDensity::new(&DATA, Kernel::Gaussian, Bandwidth::Scott).unwrap();
+        assert_close(kde.bandwidth(), 5.640333615527053, EPSILON);
+        assert_close(kde.density(4.0), 0.06033000592355204, EPSILON);
+
+        let kde = KernelDensity::new(&DATA, Kernel::Epanechnikov, Bandwidth::Fixed(1.0)).unwrap();
+        assert_close(kde.density(4.0), 0.1733399896157837, EPSILON);
+        // support ends sqrt(5) bandwidths beyond the data
+        assert_eq!(kde.density(30.0 + 2.25), 0.0);
+        assert!(kde.density(30.0 + 2.2) > 0.0);
+
+        // grid covers the whole mass
+        for kernel in [Kernel::Gaussian, Kernel::Epanechnikov].iter() {
+            let kde = KernelDensity::new(&DATA, *kernel, Bandwidth::Silverman).unwrap();
+            let grid = kde.grid(2001);
+            assert_eq!(grid.len(), 2001);
+            assert_close(grid[0].0, 1.2 - 3.0 * kde.bandwidth(), EPSILON);
+            let area = grid
+                .windows(2)
+                .map(|p| 0.5 * (p[0].1 + p[1].1) * (p[1].0 - p[0].0))
+                .sum::<f64>();
+            assert!((area - 1.0).abs() < 1e-3, "{}", area);
+        }
+
+        assert_eq!(
+            KernelDensity::new(&[1.0], Kernel::Gaussian, Bandwidth::Scott),
+            Err(StatErr::TooFew)
+        );
+        assert_eq!(
+            KernelDensity::new(&[1.0, 1.0], Kernel::Gaussian, Bandwidth::Silverman),
+            Err(StatErr::ZeroVariance)
+        );
+        assert_eq!(
+            KernelDensity::new(&DATA, Kernel::Gaussian, Bandwidth::Fixed(-1.0)),
+            Err(StatErr::InvalidParameter)
+        );
+    }
+}
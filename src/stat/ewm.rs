@@ -0,0 +1,284 @@
+/*!
+Exponentially weighted moving statistics, recent values weigh more.
+
+Every new value $x_t$ gets weight $\alpha$ and older ones decay by $1 - \alpha$, recursively
+$$
+\mu_t = (1 - \alpha)\mu_{t-1} + \alpha x_t, \quad
+\sigma^2_t = (1 - \alpha)(\sigma^2_{t-1} + \alpha (x_t - \mu_{t-1})^2)
+$$
+starting from $\mu_1 = x_1, \sigma^2_1 = 0$.
+It's the same as pandas' `ewm(adjust=False)` with `bias=True`, and RiskMetrics' volatility model.
+*/
+use crate::stat::StatErr;
+
+/// Parameterizations of decay factor $\alpha$, like pandas' `ewm`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EwmDecay {
+    /// $\alpha$ in (0, 1].
+    Alpha(f64),
+    /// Span $s \geq 1$, $\alpha = 2 / (s + 1)$, weights of the last s values sum to about 86%.
+    Span(f64),
+    /// Half-life $h > 0$, $\alpha = 1 - 0.5^{1/h}$, weights halve every h values.
+    HalfLife(f64),
+    /// Center of mass $c \geq 0$, $\alpha = 1 / (1 + c)$.
+    CenterOfMass(f64),
+}
+
+impl EwmDecay {
+    /// Decay factor $\alpha$, error if parameter is out of its domain.
+    pub fn alpha(self) -> Result<f64, StatErr> {
+        let alpha = match self {
+            EwmDecay::Alpha(a) => a,
+            EwmDecay::Span(s) if s >= 1.0 => 2.0 / (s + 1.0),
+            EwmDecay::HalfLife(h) if h > 0.0 => 1.0 - 0.5f64.powf(1.0 / h),
+            EwmDecay::CenterOfMass(c) if c >= 0.0 => 1.0 / (1.0 + c),
+            _ => return Err(StatErr::InvalidParameter),
+        };
+        if alpha > 0.0 && alpha <= 1.0 {
+            Ok(alpha)
+        } else {
+            Err(StatErr::InvalidParameter)
+        }
+    }
+}
+
+/// Online exponentially weighted moving mean and variance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EwmAccumulator {
+    alpha: f64,
+    n: u64,
+    mean: f64,
+    variance: f64,
+}
+
+impl EwmAccumulator {
+    pub fn new(decay: EwmDecay) -> Result<EwmAccumulator, StatErr> {
+        Ok(EwmAccumulator {
+            alpha: decay.alpha()?,
+            n: 0,
+            mean: 0.0,
+            variance: 0.0,
+        })
+    }
+
+    pub fn push(&mut self, x: f64) {
+        if self.n == 0 {
+            self.mean = x;
+        } else {
+            let diff = x - self.mean;
+            let incr = self.alpha * diff;
+            self.mean += incr;
+            self.variance = (1.0 - self.alpha) * (self.variance + diff * incr);
+        }
+        self.n += 1;
+    }
+
+    pub fn count(&self) -> u64 {
+        self.n
+    }
+
+    pub fn mean(&self) -> Result<f64, StatErr> {
+        self.non_empty()?;
+        Ok(self.mean)
+    }
+
+    pub fn variance(&self) -> Result<f64, StatErr> {
+        self.non_empty()?;
+        Ok(self.variance)
+    }
+
+    pub fn stddev(&self) -> Result<f64, StatErr> {
+        self.variance().map(f64::sqrt)
+    }
+
+    fn non_empty(&self) -> Result<(), StatErr> {
+        if self.n == 0 {
+            Err(StatErr::Empty)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/**
+Online exponentially weighted moving covariance of two series
+$$
+c_t = (1 - \alpha)(c_{t-1} + \alpha (x_t - \mu^x_{t-1})(y_t - \mu^y_{t-1}))
+$$
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EwmCovAccumulator {
+    x: EwmAccumulator,
+    y: EwmAccumulator,
+    covariance: f64,
+}
+
+impl EwmCovAccumulator {
+    pub fn new(decay: EwmDecay) -> Result<EwmCovAccumulator, StatErr> {
+        Ok(EwmCovAccumulator {
+            x: EwmAccumulator::new(decay)?,
+            y: EwmAccumulator::new(decay)?,
+            covariance: 0.0,
+        })
+    }
+
+    pub fn push(&mut self, x: f64, y: f64) {
+        if self.x.n > 0 {
+            let alpha = self.x.alpha;
+            self.covariance =
+                (1.0 - alpha) * (self.covariance + alpha * (x - self.x.mean) * (y - self.y.mean));
+        }
+        self.x.push(x);
+        self.y.push(y);
+    }
+
+    pub fn count(&self) -> u64 {
+        self.x.n
+    }
+
+    /// Accumulator of the first series.
+    pub fn x(&self) -> &EwmAccumulator {
+        &self.x
+    }
+
+    /// Accumulator of the second series.
+    pub fn y(&self) -> &EwmAccumulator {
+        &self.y
+    }
+
+    pub fn covariance(&self) -> Result<f64, StatErr> {
+        self.x.non_empty()?;
+        Ok(self.covariance)
+    }
+
+    /// Correlation, `StatErr::ZeroVariance` if either series has 0 variance.
+    pub fn correlation(&self) -> Result<f64, StatErr> {
+        let denom = (self.x.variance()? * self.y.variance()?).sqrt();
+        if denom == 0.0 {
+            return Err(StatErr::ZeroVariance);
+        }
+        Ok(self.covariance / denom)
+    }
+}
+
+/// Exponentially weighted moving mean after every value.
+pub fn ewm_mean(values: &[f64], decay: EwmDecay) -> Result<Vec<f64>, StatErr> {
+    let mut acc = EwmAccumulator::new(decay)?;
+    Ok(values
+        .iter()
+        .map(|x| {
+            acc.push(*x);
+            acc.mean
+        })
+        .collect())
+}
+
+/// Exponentially weighted moving variance after every value.
+pub fn ewm_variance(values: &[f64], decay: EwmDecay) -> Result<Vec<f64>, StatErr> {
+    let mut acc = EwmAccumulator::new(decay)?;
+    Ok(values
+        .iter()
+        .map(|x| {
+            acc.push(*x);
+            acc.variance
+        })
+        .collect())
+}
+
+/// Exponentially weighted moving covariance after every pair of values.
+pub fn ewm_covariance(x: &[f64], y: &[f64], decay: EwmDecay) -> Result<Vec<f64>, StatErr> {
+    if x.len() != y.len() {
+        return Err(StatErr::LengthMismatch);
+    }
+    let mut acc = EwmCovAccumulator::new(decay)?;
+    Ok(x.iter()
+        .zip(y)
+        .map(|(x, y)| {
+            acc.push(*x, *y);
+            acc.covariance
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f64 = 1e-12;
+
+    #[test]
+    fn ewm_decay_test() {
+        assert_eq!(EwmDecay::Alpha(0.5).alpha(), Ok(0.5));
+        assert_eq!(EwmDecay::Span(3.0).alpha(), Ok(0.5));
+        assert_eq!(EwmDecay::HalfLife(1.0).alpha(), Ok(0.5));
+        assert_eq!(EwmDecay::CenterOfMass(1.0).alpha(), Ok(0.5));
+        assert_eq!(EwmDecay::CenterOfMass(0.0).alpha(), Ok(1.0));
+        for decay in [
+            EwmDecay::Alpha(0.0),
+            EwmDecay::Alpha(1.5),
+            EwmDecay::Alpha(f64::NAN),
+            EwmDecay::Span(0.5),
+            EwmDecay::HalfLife(0.0),
+            EwmDecay::CenterOfMass(-1.0),
+        ]
+        .iter()
+        {
+            assert_eq!(decay.alpha(), Err(StatErr::InvalidParameter), "{:?}", decay);
+        }
+    }
+
+    #[test]
+    fn ewm_mean_variance_test() {
+        // weights of the last step are 0.25, 0.25, 0.5, same as pandas
+        let x = [1.0, 2.0, 3.0];
+        let decay = EwmDecay::Alpha(0.5);
+        assert_eq!(ewm_mean(&x, decay), Ok(vec![1.0, 1.5, 2.25]));
+        assert_eq!(ewm_variance(&x, decay), Ok(vec![0.0, 0.25, 0.6875]));
+
+        let mut acc = EwmAccumulator::new(EwmDecay::Span(3.0)).unwrap();
+        assert_eq!(acc.mean(), Err(StatErr::Empty));
+        x.iter().for_each(|v| acc.push(*v));
+        assert_eq!(acc.count(), 3);
+        assert_eq!(acc.stddev(), Ok(0.6875f64.sqrt()));
+
+        // alpha 1 keeps only the last value
+        assert_eq!(ewm_mean(&x, EwmDecay::Alpha(1.0)), Ok(x.to_vec()));
+    }
+
+    #[test]
+    fn ewm_covariance_test() {
+        let x = [1.0, 4.0, 2.0, 8.0, 5.0];
+        let y = [2.0, 1.0, 7.0, 3.0, 3.0];
+        let decay = EwmDecay::HalfLife(2.0);
+        // covariance with itself is variance
+        let cov = ewm_covariance(&x, &x, decay).unwrap();
+        let var = ewm_variance(&x, decay).unwrap();
+        for (c, v) in cov.iter().zip(var.iter()) {
+            assert!((c - v).abs() <= EPSILON);
+        }
+
+        let mut acc = EwmCovAccumulator::new(decay).unwrap();
+        x.iter().zip(y.iter()).for_each(|(a, b)| acc.push(*a, *b));
+        let cov = ewm_covariance(&x, &y, decay).unwrap();
+        assert_eq!(acc.covariance(), Ok(cov[4]));
+        // covariance of exponential weights w: sum w (x - mean_x)(y - mean_y)
+        let alpha = decay.alpha().unwrap();
+        let mut w: Vec<f64> = (0..5).map(|i| alpha * (1.0 - alpha).powi(4 - i)).collect();
+        w[0] = (1.0 - alpha).powi(4);
+        let mx: f64 = x.iter().zip(&w).map(|(a, b)| a * b).sum();
+        let my: f64 = y.iter().zip(&w).map(|(a, b)| a * b).sum();
+        let want: f64 = (0..5).map(|i| w[i] * (x[i] - mx) * (y[i] - my)).sum();
+        assert!((cov[4] - want).abs() <= EPSILON);
+        let corr = acc.correlation().unwrap();
+        assert!(
+            (corr - want / (acc.x().variance().unwrap() * acc.y().variance().unwrap()).sqrt())
+                .abs()
+                <= EPSILON
+        );
+
+        assert_eq!(
+            ewm_covariance(&x, &y[1..], decay),
+            Err(StatErr::LengthMismatch)
+        );
+    }
+}
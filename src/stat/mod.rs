@@ -0,0 +1,714 @@
+pub mod accumulator;
+pub mod correlation;
+pub mod density;
+pub mod ewm;
+pub mod tests;
+pub mod weighted;
+
+use crate::number::decimal::Xdecimal;
+use crate::number::number::{XFloat, XNum};
+use core::fmt;
+use num::Float;
+use std::cmp::Ordering;
+use std::prelude::v1::Iterator;
+
+/// Statistics error type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StatErr {
+    /// Input has no values.
+    Empty,
+
+    /// Input has NaN values which can't be ordered.
+    NaN,
+
+    /// Parameter is out of its domain, such as quantile out of [0, 1].
+    InvalidParameter,
+
+    /// Input has zero or negative values, which geometric and harmonic means are not defined for.
+    NonPositive,
+
+    /// All values are equal, so moments normalized by variance are not defined.
+    ZeroVariance,
+
+    /// Input has too few values, such as sample variance of one value.
+    TooFew,
+
+    /// Series, such as values and weights, have different lengths.
+    LengthMismatch,
+}
+
+impl std::error::Error for StatErr {}
+
+impl fmt::Display for StatErr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            StatErr::Empty => write!(f, "Empty input"),
+            StatErr::NaN => write!(f, "NaN in input"),
+            StatErr::InvalidParameter => write!(f, "Invalid parameter"),
+            StatErr::NonPositive => write!(f, "Non-positive value in input"),
+            StatErr::ZeroVariance => write!(f, "Zero variance"),
+            StatErr::TooFew => write!(f, "Too few values"),
+            StatErr::LengthMismatch => write!(f, "Length mismatch"),
+        }
+    }
+}
+
+/**
+Interpolation methods of quantile between the two sorted values $x_i$ and $x_{i+1}$
+around position $h = (n - 1) q$, same as numpy's.
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QuantileMethod {
+    /// $x_i + (h - i)(x_{i+1} - x_i)$, the default of numpy and R type 7.
+    Linear,
+    /// $x_i$
+    Lower,
+    /// $x_{i+1}$
+    Higher,
+    /// The nearer one of $x_i$ and $x_{i+1}$, ties to the even index.
+    Nearest,
+    /// $(x_i + x_{i+1}) / 2$
+    Midpoint,
+}
+
+/// Summary of a sample, like pandas' `describe()`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Describe<T> {
+    pub count: usize,
+    pub mean: T,
+    /// Sample standard deviation, None if count is 1.
+    pub stddev: Option<T>,
+    pub min: T,
+    /// 25% quantile
+    pub q1: T,
+    pub median: T,
+    /// 75% quantile
+    pub q3: T,
+    pub max: T,
+}
+
+/// Divisor of variance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VarianceKind {
+    /// Divided by $n$, variance of the values themselves.
+    Population,
+    /// Divided by $n - 1$, unbiased estimate of the variance of population they are sampled from.
+    Sample,
+}
+
+/**
+Values statistics can be computed on, floats and `Xdecimal` are computed as themselves,
+integers are computed as f64.
+*/
+pub trait StatValue: Copy {
+    type Float: XFloat;
+
+    fn to_float(self) -> Self::Float;
+}
+
+macro_rules! impl_stat_value {
+    ($float:ty; $($t:ty),*) => {$(
+        impl StatValue for $t {
+            type Float = $float;
+
+            fn to_float(self) -> $float {
+                self as $float
+            }
+        }
+    )*};
+}
+
+impl_stat_value!(f64; f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_stat_value!(f32; f32);
+
+impl StatValue for Xdecimal {
+    type Float = Xdecimal;
+
+    fn to_float(self) -> Xdecimal {
+        self
+    }
+}
+
+impl<T: StatValue> StatValue for &T {
+    type Float = T::Float;
+
+    fn to_float(self) -> T::Float {
+        (*self).to_float()
+    }
+}
+
+/**
+Basic statistics functions, implemented for anything iterable of `StatValue`, like `&[f64]`, `Vec<i32>`
+or `Iterator<Item = Xdecimal>`.
+
+All of them return `StatErr::Empty` for empty input, and `StatErr::NaN` if they need to order the values.
+The deprecated `u_mean`, `u_stddev` and `u_variance` return NaN instead, and only exist for floats,
+use `u_checked_mean`, `u_stddev_by` and `u_variance_by`.
+*/
+pub trait StatBasic {
+    /// Result type
+    type Result;
+
+    /// Compute arithmetic mean
+    /// (reference)[http://en.wikipedia.org/wiki/Arithmetic_mean]
+    #[deprecated(note = "returns NaN for empty input, use u_checked_mean")]
+    fn u_mean(self) -> Self::Result
+    where
+        Self::Result: Float;
+
+    /// Compute standard deviation
+    #[deprecated(note = "returns NaN for fewer than 2 values, use u_stddev_by")]
+    fn u_stddev(self) -> Self::Result
+    where
+        Self::Result: Float;
+
+    /// Compute statistical variance
+    #[deprecated(note = "returns NaN for fewer than 2 values, use u_variance_by")]
+    fn u_variance(self) -> Self::Result
+    where
+        Self::Result: Float;
+
+    /// Compute arithmetic mean
+    /// (reference)[http://en.wikipedia.org/wiki/Arithmetic_mean]
+    fn u_checked_mean(self) -> Result<Self::Result, StatErr>;
+
+    /// Compute standard deviation with divisor of kind, sample one needs at least 2 values
+    fn u_stddev_by(self, kind: VarianceKind) -> Result<Self::Result, StatErr>;
+
+    /// Compute variance with divisor of kind, sample one needs at least 2 values
+    fn u_variance_by(self, kind: VarianceKind) -> Result<Self::Result, StatErr>;
+
+    /// Compute median, the mean of the two middle values for even count
+    fn u_median(self) -> Result<Self::Result, StatErr>;
+
+    /// Compute q quantile for q in [0, 1]
+    fn u_quantile(self, q: f64, method: QuantileMethod) -> Result<Self::Result, StatErr>;
+
+    /// Compute mode, the most frequent value, the smallest one if there are several
+    fn u_mode(self) -> Result<Self::Result, StatErr>;
+
+    /// Compute minimum value
+    fn u_min(self) -> Result<Self::Result, StatErr>;
+
+    /// Compute maximum value
+    fn u_max(self) -> Result<Self::Result, StatErr>;
+
+    /// Compute max - min
+    fn u_range(self) -> Result<Self::Result, StatErr>;
+
+    /**
+    Compute skewness of population
+    $$
+    g_1 = \frac{m_3}{m_2^{3/2}}, \quad m_k = \frac{1}{n}\sum_{i=1}^{n}(x_i - \bar{x})^k
+    $$
+    */
+    fn u_skewness(self) -> Result<Self::Result, StatErr>;
+
+    /**
+    Compute excess kurtosis of population, which is 0 for normal distribution
+    $$
+    g_2 = \frac{m_4}{m_2^2} - 3
+    $$
+    */
+    fn u_kurtosis(self) -> Result<Self::Result, StatErr>;
+
+    /// Compute median absolute deviation $median(|x_i - median(x)|)$, not scaled
+    fn u_mad(self) -> Result<Self::Result, StatErr>;
+
+    /// Compute geometric mean $(\prod x_i)^{1/n}$ of positive values
+    fn u_geometric_mean(self) -> Result<Self::Result, StatErr>;
+
+    /// Compute harmonic mean $n / \sum{1/x_i}$ of positive values
+    fn u_harmonic_mean(self) -> Result<Self::Result, StatErr>;
+
+    /// Compute count, mean, stddev, min, quartiles and max at once
+    fn u_describe(self) -> Result<Describe<Self::Result>, StatErr>;
+}
+
+impl<I> StatBasic for I
+where
+    I: IntoIterator,
+    I::Item: StatValue,
+{
+    type Result = <I::Item as StatValue>::Float;
+
+    fn u_mean(self) -> Self::Result
+    where
+        Self::Result: Float,
+    {
+        self.u_checked_mean().unwrap_or_else(|_| Float::nan())
+    }
+
+    fn u_stddev(self) -> Self::Result
+    where
+        Self::Result: Float,
+    {
+        Float::sqrt(variance(&floats(self), VarianceKind::Sample).unwrap_or_else(|_| Float::nan()))
+    }
+
+    fn u_variance(self) -> Self::Result
+    where
+        Self::Result: Float,
+    {
+        variance(&floats(self), VarianceKind::Sample).unwrap_or_else(|_| Float::nan())
+    }
+
+    fn u_checked_mean(self) -> Result<Self::Result, StatErr> {
+        let values = floats(self);
+        if values.is_empty() {
+            return Err(StatErr::Empty);
+        }
+        Ok(mean(&values))
+    }
+
+    fn u_stddev_by(self, kind: VarianceKind) -> Result<Self::Result, StatErr> {
+        self.u_variance_by(kind).map(XFloat::sqrt)
+    }
+
+    fn u_variance_by(self, kind: VarianceKind) -> Result<Self::Result, StatErr> {
+        variance(&floats(self), kind)
+    }
+
+    fn u_median(self) -> Result<Self::Result, StatErr> {
+        median(floats(self))
+    }
+
+    fn u_quantile(self, q: f64, method: QuantileMethod) -> Result<Self::Result, StatErr> {
+        if !(0.0..=1.0).contains(&q) {
+            return Err(StatErr::InvalidParameter);
+        }
+        Ok(sorted_quantile(&sorted(floats(self))?, q, method))
+    }
+
+    fn u_mode(self) -> Result<Self::Result, StatErr> {
+        let sorted = sorted(floats(self))?;
+        // longest run of equal values, the first one wins ties
+        let (mut best, mut best_len, mut run_len) = (sorted[0], 0, 0);
+        for (i, v) in sorted.iter().enumerate() {
+            run_len = if i > 0 && sorted[i - 1] == *v {
+                run_len + 1
+            } else {
+                1
+            };
+            if run_len > best_len {
+                best = *v;
+                best_len = run_len;
+            }
+        }
+        Ok(best)
+    }
+
+    fn u_min(self) -> Result<Self::Result, StatErr> {
+        min_max(&floats(self)).map(|(min, _)| min)
+    }
+
+    fn u_max(self) -> Result<Self::Result, StatErr> {
+        min_max(&floats(self)).map(|(_, max)| max)
+    }
+
+    fn u_range(self) -> Result<Self::Result, StatErr> {
+        min_max(&floats(self)).map(|(min, max)| max - min)
+    }
+
+    fn u_skewness(self) -> Result<Self::Result, StatErr> {
+        let (m2, m3, _) = central_moments(&floats(self))?;
+        Ok(m3 / (m2 * m2.sqrt()))
+    }
+
+    fn u_kurtosis(self) -> Result<Self::Result, StatErr> {
+        let (m2, _, m4) = central_moments(&floats(self))?;
+        Ok(m4 / (m2 * m2) - XNum::n_u8(3))
+    }
+
+    fn u_mad(self) -> Result<Self::Result, StatErr> {
+        let values = floats(self);
+        let median = median(values.clone())?;
+        self::median(values.iter().map(|x| (*x - median).abs()).collect())
+    }
+
+    fn u_geometric_mean(self) -> Result<Self::Result, StatErr> {
+        // mean of logarithms, which doesn't overflow as the product does
+        let values = floats(self);
+        positive(&values)?;
+        let logs: Vec<Self::Result> = values.iter().map(|x| x.ln()).collect();
+        Ok(mean(&logs).exp())
+    }
+
+    fn u_harmonic_mean(self) -> Result<Self::Result, StatErr> {
+        let values = floats(self);
+        positive(&values)?;
+        let (n, one): (Self::Result, Self::Result) = (count(values.len()), XNum::n_1());
+        Ok(n / values.iter().fold(XNum::n_0(), |acc, x| acc + one / *x))
+    }
+
+    fn u_describe(self) -> Result<Describe<Self::Result>, StatErr> {
+        let values = floats(self);
+        let sorted = sorted(values.clone())?;
+        let quantile = |q| sorted_quantile(&sorted, q, QuantileMethod::Linear);
+        Ok(Describe {
+            count: sorted.len(),
+            mean: mean(&values),
+            stddev: variance(&values, VarianceKind::Sample)
+                .ok()
+                .map(XFloat::sqrt),
+            min: sorted[0],
+            q1: quantile(0.25),
+            median: quantile(0.5),
+            q3: quantile(0.75),
+            max: sorted[sorted.len() - 1],
+        })
+    }
+}
+
+fn floats<I>(values: I) -> Vec<<I::Item as StatValue>::Float>
+where
+    I: IntoIterator,
+    I::Item: StatValue,
+{
+    values.into_iter().map(StatValue::to_float).collect()
+}
+
+/// n as float, exact for any count fitting in memory.
+fn count<F: XFloat>(n: usize) -> F {
+    F::from_f64(n as f64).unwrap()
+}
+
+#[inline]
+fn is_nan<F: XFloat>(x: &F) -> bool {
+    x.partial_cmp(x).is_none()
+}
+
+fn mean<F: XFloat>(values: &[F]) -> F {
+    values.iter().fold(F::n_0(), |acc, x| acc + *x) / count(values.len())
+}
+
+/// Sum of squared deviations from mean.
+fn sum_squares<F: XFloat>(values: &[F]) -> F {
+    let mean = mean(values);
+    values.iter().fold(F::n_0(), |acc, x| {
+        let d = *x - mean;
+        acc + d * d
+    })
+}
+
+fn variance<F: XFloat>(values: &[F], kind: VarianceKind) -> Result<F, StatErr> {
+    let divisor = match kind {
+        VarianceKind::Population if !values.is_empty() => values.len(),
+        VarianceKind::Sample if values.len() > 1 => values.len() - 1,
+        _ if values.is_empty() => return Err(StatErr::Empty),
+        _ => return Err(StatErr::TooFew),
+    };
+    Ok(sum_squares(values) / count(divisor))
+}
+
+/// Sorted values, error if empty or any is NaN.
+fn sorted<F: XFloat>(mut values: Vec<F>) -> Result<Vec<F>, StatErr> {
+    if values.is_empty() {
+        return Err(StatErr::Empty);
+    }
+    if values.iter().any(is_nan) {
+        return Err(StatErr::NaN);
+    }
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    Ok(values)
+}
+
+fn median<F: XFloat>(values: Vec<F>) -> Result<F, StatErr> {
+    Ok(sorted_quantile(
+        &sorted(values)?,
+        0.5,
+        QuantileMethod::Midpoint,
+    ))
+}
+
+/// Quantile of non-empty sorted values, q must be in [0, 1].
+fn sorted_quantile<F: XFloat>(sorted: &[F], q: f64, method: QuantileMethod) -> F {
+    let h = (sorted.len() - 1) as f64 * q;
+    let i = h.floor() as usize;
+    let j = (i + 1).min(sorted.len() - 1);
+    let (lo, hi) = (sorted[i], sorted[j]);
+    let frac = h - i as f64;
+    match method {
+        QuantileMethod::Linear => {
+            if frac == 0.0 {
+                lo
+            } else {
+                lo + F::from_f64(frac).unwrap() * (hi - lo)
+            }
+        }
+        QuantileMethod::Lower => lo,
+        QuantileMethod::Higher => {
+            if frac == 0.0 {
+                lo
+            } else {
+                hi
+            }
+        }
+        QuantileMethod::Nearest => {
+            if frac > 0.5 || (frac == 0.5 && i % 2 == 1) {
+                hi
+            } else {
+                lo
+            }
+        }
+        QuantileMethod::Midpoint => {
+            if frac == 0.0 {
+                lo
+            } else {
+                (lo + hi) / F::n_u8(2)
+            }
+        }
+    }
+}
+
+fn min_max<F: XFloat>(values: &[F]) -> Result<(F, F), StatErr> {
+    if values.is_empty() {
+        return Err(StatErr::Empty);
+    }
+    values
+        .iter()
+        .try_fold((values[0], values[0]), |(min, max), x| {
+            if is_nan(x) {
+                Err(StatErr::NaN)
+            } else if *x < min {
+                Ok((*x, max))
+            } else if *x > max {
+                Ok((min, *x))
+            } else {
+                Ok((min, max))
+            }
+        })
+}
+
+/// Central moments m2, m3 and m4 of population, error if empty or m2 is 0.
+fn central_moments<F: XFloat>(values: &[F]) -> Result<(F, F, F), StatErr> {
+    if values.is_empty() {
+        return Err(StatErr::Empty);
+    }
+    let n: F = count(values.len());
+    let mean = mean(values);
+    let (mut m2, mut m3, mut m4) = (F::n_0(), F::n_0(), F::n_0());
+    for x in values.iter() {
+        let d = *x - mean;
+        let d2 = d * d;
+        m2 = m2 + d2;
+        m3 = m3 + d2 * d;
+        m4 = m4 + d2 * d2;
+    }
+    if m2.is_zero() {
+        return Err(StatErr::ZeroVariance);
+    }
+    Ok((m2 / n, m3 / n, m4 / n))
+}
+
+/// Error if empty or any value isn't positive.
+fn positive<F: XFloat>(values: &[F]) -> Result<(), StatErr> {
+    if values.is_empty() {
+        return Err(StatErr::Empty);
+    }
+    if values.iter().any(is_nan) {
+        return Err(StatErr::NaN);
+    }
+    if values.iter().any(|x| *x <= F::n_0()) {
+        return Err(StatErr::NonPositive);
+    }
+    Ok(())
+}
+
+/// Assert a is within relative tolerance of b, for tests.
+#[cfg(test)]
+pub(crate) fn assert_close(a: f64, b: f64, tolerance: f64) {
+    assert!((a - b).abs() <= tolerance * b.abs(), "{} != {}", a, b);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use num::abs;
+
+    const EPSILON: f64 = 1e-6;
+
+    #[test]
+    #[allow(deprecated)]
+    fn u_mean_test() {
+        assert_eq!([1.0, 3.0, 5.0].as_ref().u_mean(), 3.0);
+
+        let diff = abs([0.0, 0.25, 0.25, 1.25, 1.5, 1.75, 2.75, 3.25]
+            .as_ref()
+            .u_mean()
+            - 1.375);
+        assert!(diff <= EPSILON);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn u_variance_test() {
+        assert_eq!([1.0, 3.0, 5.0].as_ref().u_variance(), 4.0);
+
+        // deprecated for this NaN
+        let empty: &[f64] = &[];
+        assert!(empty.u_variance().is_nan());
+        assert!([1.0f64].as_ref().u_variance().is_nan());
+        assert!(empty.u_mean().is_nan());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn u_stddev_test() {
+        assert_eq!([1.0, 3.0, 5.0].as_ref().u_stddev(), 2.0);
+    }
+
+    #[test]
+    fn u_quantile_test() {
+        let x = [7.0, 1.0, 3.0, 5.0].as_ref();
+        assert_eq!(x.u_median(), Ok(4.0));
+        assert_eq!([3.0, 1.0, 2.0].as_ref().u_median(), Ok(2.0));
+        // h = 3 * 0.4 = 1.2, between 3.0 and 5.0
+        let cases = [
+            (QuantileMethod::Linear, 3.4),
+            (QuantileMethod::Lower, 3.0),
+            (QuantileMethod::Higher, 5.0),
+            (QuantileMethod::Nearest, 3.0),
+            (QuantileMethod::Midpoint, 4.0),
+        ];
+        for (method, want) in cases.iter() {
+            let q = x.u_quantile(0.4, *method).unwrap();
+            assert!(abs(q - want) <= EPSILON, "{:?} {}", method, q);
+            assert_eq!(x.u_quantile(0.0, *method), Ok(1.0));
+            assert_eq!(x.u_quantile(1.0, *method), Ok(7.0));
+        }
+        // h = 1.5, ties to even index 2
+        assert_eq!(x.u_quantile(0.5, QuantileMethod::Nearest), Ok(5.0));
+        assert_eq!(
+            x.u_quantile(1.5, QuantileMethod::Linear),
+            Err(StatErr::InvalidParameter)
+        );
+        assert_eq!([1.0, f64::NAN].as_ref().u_median(), Err(StatErr::NaN));
+    }
+
+    #[test]
+    fn u_descriptive_test() {
+        let x = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0].as_ref();
+        assert_eq!(x.u_mode(), Ok(4.0));
+        assert_eq!([3.0, 1.0, 3.0, 1.0].as_ref().u_mode(), Ok(1.0));
+        assert_eq!(x.u_min(), Ok(2.0));
+        assert_eq!(x.u_max(), Ok(9.0));
+        assert_eq!(x.u_range(), Ok(7.0));
+        assert_eq!(x.u_mad(), Ok(0.5));
+
+        // population moments: m2 = 4, m3 = 5.25, m4 = 44.5
+        assert!(abs(x.u_skewness().unwrap() - 0.65625) <= EPSILON);
+        assert!(abs(x.u_kurtosis().unwrap() - (44.5 / 16.0 - 3.0)) <= EPSILON);
+        assert_eq!([1.0, 1.0].as_ref().u_skewness(), Err(StatErr::ZeroVariance));
+
+        assert!(abs([1.0, 2.0, 4.0].as_ref().u_geometric_mean().unwrap() - 2.0) <= EPSILON);
+        assert!(abs([1.0, 2.0, 4.0].as_ref().u_harmonic_mean().unwrap() - 12.0 / 7.0) <= EPSILON);
+        assert_eq!(
+            [1.0, 0.0].as_ref().u_geometric_mean(),
+            Err(StatErr::NonPositive)
+        );
+        assert_eq!(
+            [1.0, -1.0].as_ref().u_harmonic_mean(),
+            Err(StatErr::NonPositive)
+        );
+    }
+
+    #[test]
+    fn u_generic_test() {
+        assert_eq!(vec![1, 3, 5].u_checked_mean(), Ok(3.0));
+        assert_eq!(
+            [1u8, 3, 5].iter().u_variance_by(VarianceKind::Sample),
+            Ok(4.0)
+        );
+        assert_eq!((1..=5).map(|x| x * x).u_median(), Ok(9.0));
+        assert_eq!(
+            [1.0f32, 3.0, 5.0]
+                .as_ref()
+                .u_stddev_by(VarianceKind::Sample),
+            Ok(2.0f32)
+        );
+        assert_eq!(
+            [2.5f32, 0.5].iter().u_quantile(0.5, QuantileMethod::Linear),
+            Ok(1.5f32)
+        );
+
+        // exact without binary floating point errors
+        let x: Vec<Xdecimal> = ["0.1", "0.2", "0.3", "0.4"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        assert_eq!(x.iter().u_checked_mean(), Ok(Xdecimal::new(25, 2)));
+        assert_eq!(
+            x.iter().u_variance_by(VarianceKind::Population),
+            Ok(Xdecimal::new(125, 4))
+        );
+        assert_eq!(x.iter().u_median(), Ok(Xdecimal::new(25, 2)));
+        assert_eq!(x.iter().u_range(), Ok(Xdecimal::new(3, 1)));
+        assert_eq!(
+            x.iter().u_quantile(0.5, QuantileMethod::Linear),
+            Ok(Xdecimal::new(25, 2))
+        );
+    }
+
+    #[test]
+    fn u_variance_by_test() {
+        let x = [1.0, 3.0, 5.0].as_ref();
+        assert_eq!(x.u_variance_by(VarianceKind::Sample), Ok(4.0));
+        assert_eq!(x.u_variance_by(VarianceKind::Population), Ok(8.0 / 3.0));
+        assert_eq!(x.u_stddev_by(VarianceKind::Sample), Ok(2.0));
+        assert_eq!(
+            [1.0].as_ref().u_variance_by(VarianceKind::Population),
+            Ok(0.0)
+        );
+        assert_eq!(
+            [1.0].as_ref().u_variance_by(VarianceKind::Sample),
+            Err(StatErr::TooFew)
+        );
+        let empty: &[f64] = &[];
+        assert_eq!(
+            empty.u_variance_by(VarianceKind::Population),
+            Err(StatErr::Empty)
+        );
+        assert_eq!(empty.u_checked_mean(), Err(StatErr::Empty));
+
+        // no NaN for decimals, errors instead of panics
+        let decimals: Vec<Xdecimal> = vec![];
+        assert_eq!(decimals.iter().u_checked_mean(), Err(StatErr::Empty));
+        assert_eq!(
+            [Xdecimal::n_1()].iter().u_variance_by(VarianceKind::Sample),
+            Err(StatErr::TooFew)
+        );
+    }
+
+    #[test]
+    fn u_describe_test() {
+        let d = [1.0, 2.0, 3.0, 4.0, 5.0].as_ref().u_describe().unwrap();
+        assert_eq!(d.count, 5);
+        assert_eq!(d.mean, 3.0);
+        assert!(abs(d.stddev.unwrap() - 2.5f64.sqrt()) <= EPSILON);
+        assert_eq!(
+            (d.min, d.q1, d.median, d.q3, d.max),
+            (1.0, 2.0, 3.0, 4.0, 5.0)
+        );
+        assert!([7.0].as_ref().u_describe().unwrap().stddev.is_none());
+    }
+
+    #[test]
+    fn u_empty_test() {
+        let x: &[f64] = &[];
+        assert_eq!(x.u_median(), Err(StatErr::Empty));
+        assert_eq!(
+            x.u_quantile(0.5, QuantileMethod::Linear),
+            Err(StatErr::Empty)
+        );
+        assert_eq!(x.u_mode(), Err(StatErr::Empty));
+        assert_eq!(x.u_min(), Err(StatErr::Empty));
+        assert_eq!(x.u_range(), Err(StatErr::Empty));
+        assert_eq!(x.u_skewness(), Err(StatErr::Empty));
+        assert_eq!(x.u_kurtosis(), Err(StatErr::Empty));
+        assert_eq!(x.u_mad(), Err(StatErr::Empty));
+        assert_eq!(x.u_geometric_mean(), Err(StatErr::Empty));
+        assert_eq!(x.u_harmonic_mean(), Err(StatErr::Empty));
+        assert_eq!(x.u_describe(), Err(StatErr::Empty));
+    }
+}
@@ -0,0 +1,479 @@
+/*!
+Hypothesis tests, all of them reject NaN values with `StatErr::NaN`.
+
+p-value is the probability of a statistic at least as extreme as the observed one under null hypothesis,
+reject null hypothesis at significance level $\alpha$ if p-value < $\alpha$.
+*/
+use crate::probability_distribution::normal::Normal;
+use crate::probability_distribution::ContinuousDistribution;
+use crate::special_function::{regularized_beta, regularized_gamma_q};
+use crate::stat::correlation::ranks;
+use crate::stat::{StatBasic, StatErr, VarianceKind};
+use std::cmp::Ordering;
+
+/// Alternative hypothesis, the side of distribution counted by p-value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Alternative {
+    /// Parameter differs from null hypothesis in either direction.
+    TwoSided,
+    /// Parameter of first sample is less.
+    Less,
+    /// Parameter of first sample is greater.
+    Greater,
+}
+
+/// Two-sample t-test variants.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TTestKind {
+    /// Student's t-test, assumes equal variances and pools them.
+    Student,
+    /// Welch's t-test, doesn't assume equal variances, degrees of freedom by Welch–Satterthwaite equation.
+    Welch,
+}
+
+/// Result of a hypothesis test.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TestResult {
+    pub statistic: f64,
+    /// Degrees of freedom of statistic's distribution, None for tests without it.
+    pub df: Option<f64>,
+    pub p_value: f64,
+}
+
+/**
+One-sample t-test of null hypothesis that population mean is mu
+$$
+t = \frac{\bar{x} - \mu}{s / \sqrt{n}}, \quad df = n - 1
+$$
+*/
+pub fn t_test_one_sample(
+    x: &[f64],
+    mu: f64,
+    alternative: Alternative,
+) -> Result<TestResult, StatErr> {
+    let (mean, variance) = mean_variance(x)?;
+    t_result(
+        mean - mu,
+        (variance / x.len() as f64).sqrt(),
+        (x.len() - 1) as f64,
+        alternative,
+    )
+}
+
+/// Two-sample t-test of null hypothesis that two populations have equal means.
+pub fn t_test_two_sample(
+    x: &[f64],
+    y: &[f64],
+    kind: TTestKind,
+    alternative: Alternative,
+) -> Result<TestResult, StatErr> {
+    let (mean_x, var_x) = mean_variance(x)?;
+    let (mean_y, var_y) = mean_variance(y)?;
+    let (nx, ny) = (x.len() as f64, y.len() as f64);
+    let (se, df) = match kind {
+        TTestKind::Student => {
+            let df = nx + ny - 2.0;
+            let pooled = ((nx - 1.0) * var_x + (ny - 1.0) * var_y) / df;
+            ((pooled * (1.0 / nx + 1.0 / ny)).sqrt(), df)
+        }
+        TTestKind::Welch => {
+            let (a, b) = (var_x / nx, var_y / ny);
+            let df = (a + b) * (a + b) / (a * a / (nx - 1.0) + b * b / (ny - 1.0));
+            ((a + b).sqrt(), df)
+        }
+    };
+    t_result(mean_x - mean_y, se, df, alternative)
+}
+
+/// Paired t-test of null hypothesis that mean of differences x - y is 0.
+pub fn t_test_paired(
+    x: &[f64],
+    y: &[f64],
+    alternative: Alternative,
+) -> Result<TestResult, StatErr> {
+    if x.len() != y.len() {
+        return Err(StatErr::LengthMismatch);
+    }
+    let diff: Vec<f64> = x.iter().zip(y).map(|(a, b)| a - b).collect();
+    t_test_one_sample(&diff, 0.0, alternative)
+}
+
+/**
+Pearson's chi-square goodness of fit test of null hypothesis that observed counts follow expected frequencies
+$$
+\chi^2 = \sum_{i=1}^{k} \frac{(O_i - E_i)^2}{E_i}, \quad df = k - 1
+$$
+expected frequencies are rescaled to the total of observed counts, so they can be probabilities.
+*/
+pub fn chi_square_goodness_of_fit(
+    observed: &[f64],
+    expected: &[f64],
+) -> Result<TestResult, StatErr> {
+    if observed.len() != expected.len() {
+        return Err(StatErr::LengthMismatch);
+    }
+    check_nan(observed)?;
+    check_nan(expected)?;
+    if observed.len() < 2 {
+        return Err(StatErr::TooFew);
+    }
+    if observed.iter().any(|o| *o < 0.0) || expected.iter().any(|e| *e <= 0.0) {
+        return Err(StatErr::InvalidParameter);
+    }
+    let scale = observed.iter().sum::<f64>() / expected.iter().sum::<f64>();
+    let statistic = observed
+        .iter()
+        .zip(expected)
+        .map(|(o, e)| {
+            let e = e * scale;
+            (o - e) * (o - e) / e
+        })
+        .sum();
+    chi_square_result(statistic, (observed.len() - 1) as f64)
+}
+
+/**
+Pearson's chi-square test of independence of rows and columns of a contingency table,
+expected counts are $E_{ij} = R_i C_j / N$ from row and column totals, $df = (r - 1)(c - 1)$.
+*/
+pub fn chi_square_independence<S: AsRef<[f64]>>(table: &[S]) -> Result<TestResult, StatErr> {
+    if table.is_empty() {
+        return Err(StatErr::Empty);
+    }
+    let cols = table[0].as_ref().len();
+    if table.iter().any(|row| row.as_ref().len() != cols) {
+        return Err(StatErr::LengthMismatch);
+    }
+    for row in table.iter() {
+        check_nan(row.as_ref())?;
+    }
+    if table.len() < 2 || cols < 2 {
+        return Err(StatErr::TooFew);
+    }
+    if table
+        .iter()
+        .any(|row| row.as_ref().iter().any(|v| *v < 0.0))
+    {
+        return Err(StatErr::InvalidParameter);
+    }
+
+    let row_totals: Vec<f64> = table.iter().map(|row| row.as_ref().iter().sum()).collect();
+    let col_totals: Vec<f64> = (0..cols)
+        .map(|j| table.iter().map(|row| row.as_ref()[j]).sum())
+        .collect();
+    if row_totals
+        .iter()
+        .chain(col_totals.iter())
+        .any(|t| *t == 0.0)
+    {
+        return Err(StatErr::InvalidParameter);
+    }
+    let total: f64 = row_totals.iter().sum();
+    let mut statistic = 0.0;
+    for (row, row_total) in table.iter().zip(row_totals.iter()) {
+        for (o, col_total) in row.as_ref().iter().zip(col_totals.iter()) {
+            let e = row_total * col_total / total;
+            statistic += (o - e) * (o - e) / e;
+        }
+    }
+    chi_square_result(statistic, ((table.len() - 1) * (cols - 1)) as f64)
+}
+
+/**
+Two-sided two-sample Kolmogorov-Smirnov test of null hypothesis that x and y are from the same distribution,
+statistic is the max distance between their empirical CDFs
+$$
+D = \sup_t |F_x(t) - F_y(t)|
+$$
+p-value is from asymptotic Kolmogorov distribution with Stephens' correction for small samples
+$$
+p = Q_{KS}((\sqrt{n_e} + 0.12 + 0.11 / \sqrt{n_e}) D), \quad n_e = \frac{n_x n_y}{n_x + n_y}
+$$
+*/
+pub fn ks_two_sample(x: &[f64], y: &[f64]) -> Result<TestResult, StatErr> {
+    check_nan(x)?;
+    check_nan(y)?;
+    if x.is_empty() || y.is_empty() {
+        return Err(StatErr::Empty);
+    }
+    let x = sorted(x);
+    let y = sorted(y);
+    let (nx, ny) = (x.len() as f64, y.len() as f64);
+    let (mut i, mut j, mut d) = (0, 0, 0.0f64);
+    while i < x.len() && j < y.len() {
+        // step over all values equal to the smaller one in both samples
+        let t = x[i].min(y[j]);
+        while i < x.len() && x[i] == t {
+            i += 1;
+        }
+        while j < y.len() && y[j] == t {
+            j += 1;
+        }
+        d = d.max((i as f64 / nx - j as f64 / ny).abs());
+    }
+
+    let en = (nx * ny / (nx + ny)).sqrt();
+    Ok(TestResult {
+        statistic: d,
+        df: None,
+        p_value: kolmogorov_sf((en + 0.12 + 0.11 / en) * d),
+    })
+}
+
+/**
+Mann-Whitney U test, or Wilcoxon rank-sum test, of null hypothesis that x values are
+equally likely to be greater or less than y values.
+
+Statistic is U of x, $U_x = R_x - n_x(n_x + 1)/2$ where $R_x$ is the rank sum of x in both samples.
+p-value is from normal approximation with tie and continuity corrections, like scipy's default for large samples.
+*/
+pub fn mann_whitney_u(
+    x: &[f64],
+    y: &[f64],
+    alternative: Alternative,
+) -> Result<TestResult, StatErr> {
+    check_nan(x)?;
+    check_nan(y)?;
+    if x.is_empty() || y.is_empty() {
+        return Err(StatErr::Empty);
+    }
+    let all: Vec<f64> = x.iter().chain(y.iter()).copied().collect();
+    let rank_sum: f64 = ranks(&all)[..x.len()].iter().sum();
+    let (nx, ny, n) = (x.len() as f64, y.len() as f64, all.len() as f64);
+    let u_x = rank_sum - nx * (nx + 1.0) / 2.0;
+    let u_y = nx * ny - u_x;
+
+    // sum of t^3 - t of groups of t tied values
+    let sorted_all = sorted(&all);
+    let mut ties = 0.0;
+    let mut start = 0;
+    while start < sorted_all.len() {
+        let end = start
+            + sorted_all[start..]
+                .iter()
+                .take_while(|v| **v == sorted_all[start])
+                .count();
+        let t = (end - start) as f64;
+        ties += t * t * t - t;
+        start = end;
+    }
+    let mu = nx * ny / 2.0;
+    let sigma = (nx * ny / 12.0 * ((n + 1.0) - ties / (n * (n - 1.0)))).sqrt();
+    if sigma.is_nan() || sigma <= 0.0 {
+        return Err(StatErr::ZeroVariance);
+    }
+
+    let normal = Normal::standard();
+    let sf = |u: f64| normal.sf((u - mu - 0.5) / sigma);
+    let p_value = match alternative {
+        Alternative::TwoSided => (2.0 * sf(u_x.max(u_y))).min(1.0),
+        Alternative::Greater => sf(u_x),
+        Alternative::Less => sf(u_y),
+    };
+    Ok(TestResult {
+        statistic: u_x,
+        df: None,
+        p_value,
+    })
+}
+
+fn check_nan(values: &[f64]) -> Result<(), StatErr> {
+    if values.iter().any(|v| v.is_nan()) {
+        Err(StatErr::NaN)
+    } else {
+        Ok(())
+    }
+}
+
+fn sorted(values: &[f64]) -> Vec<f64> {
+    let mut res = values.to_vec();
+    res.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    res
+}
+
+/// Mean and sample variance, needs at least 2 values.
+fn mean_variance(values: &[f64]) -> Result<(f64, f64), StatErr> {
+    check_nan(values)?;
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let variance = values.u_variance_by(VarianceKind::Sample)?;
+    Ok((mean, variance))
+}
+
+fn t_result(diff: f64, se: f64, df: f64, alternative: Alternative) -> Result<TestResult, StatErr> {
+    if se == 0.0 {
+        return Err(StatErr::ZeroVariance);
+    }
+    let t = diff / se;
+    // probability of |T| > |t| on one side
+    let tail = 0.5 * regularized_beta(df / 2.0, 0.5, df / (df + t * t));
+    let (lower, upper) = if t < 0.0 {
+        (tail, 1.0 - tail)
+    } else {
+        (1.0 - tail, tail)
+    };
+    let p_value = match alternative {
+        Alternative::TwoSided => 2.0 * tail,
+        Alternative::Less => lower,
+        Alternative::Greater => upper,
+    };
+    Ok(TestResult {
+        statistic: t,
+        df: Some(df),
+        p_value,
+    })
+}
+
+fn chi_square_result(statistic: f64, df: f64) -> Result<TestResult, StatErr> {
+    Ok(TestResult {
+        statistic,
+        df: Some(df),
+        p_value: regularized_gamma_q(df / 2.0, statistic / 2.0),
+    })
+}
+
+/// Survival function of Kolmogorov distribution $Q_{KS}(\lambda) = 2\sum_{j=1}^\infty (-1)^{j-1} e^{-2j^2\lambda^2}$.
+fn kolmogorov_sf(lambda: f64) -> f64 {
+    // series converges too slow for small lambda, where Q is 1 within f64 precision
+    if lambda < 0.2 {
+        return 1.0;
+    }
+    let mut sum = 0.0;
+    let mut sign = 1.0;
+    for j in 1..=100 {
+        let j = j as f64;
+        let term = sign * (-2.0 * j * j * lambda * lambda).exp();
+        sum += term;
+        if term.abs() < 1e-16 * sum.abs() {
+            break;
+        }
+        sign = -sign;
+    }
+    (2.0 * sum).clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::stat::assert_close;
+
+    const X: [f64; 8] = [5.1, 4.9, 5.6, 5.8, 6.0, 5.5, 5.3, 6.2];
+    const Y: [f64; 7] = [4.8, 5.0, 4.6, 5.2, 4.7, 5.1, 4.9];
+
+    fn assert_result(r: TestResult, statistic: f64, df: Option<f64>, p_value: f64) {
+        assert_close(r.statistic, statistic, 1e-10);
+        match (r.df, df) {
+            (Some(a), Some(b)) => assert_close(a, b, 1e-10),
+            (a, b) => assert_eq!(a, b),
+        }
+        assert_close(r.p_value, p_value, 1e-10);
+    }
+
+    // reference values are from the same formulas in mpmath with 30 digits
+
+    #[test]
+    fn t_test_test() {
+        let t = 3.503621314988333;
+        let r = t_test_one_sample(&X, 5.0, Alternative::TwoSided).unwrap();
+        assert_result(r, t, Some(7.0), 0.009944414335751586);
+        let r = t_test_one_sample(&X, 5.0, Alternative::Greater).unwrap();
+        assert_result(r, t, Some(7.0), 0.004972207167875793);
+        let r = t_test_one_sample(&X, 5.0, Alternative::Less).unwrap();
+        assert_result(r, t, Some(7.0), 0.9950277928321242);
+
+        let r = t_test_two_sample(&X, &Y, TTestKind::Student, Alternative::TwoSided).unwrap();
+        assert_result(r, 3.5146280659220636, Some(13.0), 0.0038058012141897344);
+        let r = t_test_two_sample(&X, &Y, TTestKind::Welch, Alternative::TwoSided).unwrap();
+        assert_result(
+            r,
+            3.673458394748301,
+            Some(10.41082115493503),
+            0.004014745377632391,
+        );
+        let r = t_test_two_sample(&X, &Y, TTestKind::Welch, Alternative::Less).unwrap();
+        assert_close(r.p_value, 0.9979926273111838, 1e-10);
+
+        let a = [12.1, 11.4, 13.2, 10.8, 12.9, 11.7];
+        let b = [11.8, 11.5, 12.6, 10.1, 12.2, 11.9];
+        let r = t_test_paired(&a, &b, Alternative::TwoSided).unwrap();
+        assert_result(r, 2.024440825447287, Some(5.0), 0.09880906324153152);
+    }
+
+    #[test]
+    fn t_test_err_test() {
+        let two_sided = Alternative::TwoSided;
+        assert_eq!(
+            t_test_one_sample(&[1.0], 0.0, two_sided),
+            Err(StatErr::TooFew)
+        );
+        assert_eq!(
+            t_test_one_sample(&[1.0, 1.0], 0.0, two_sided),
+            Err(StatErr::ZeroVariance)
+        );
+        assert_eq!(
+            t_test_one_sample(&[1.0, f64::NAN], 0.0, two_sided),
+            Err(StatErr::NaN)
+        );
+        assert_eq!(
+            t_test_paired(&X, &Y, two_sided),
+            Err(StatErr::LengthMismatch)
+        );
+    }
+
+    #[test]
+    fn chi_square_test() {
+        let observed = [16.0, 18.0, 16.0, 14.0, 12.0, 12.0];
+        let r = chi_square_goodness_of_fit(&observed, &[1.0; 6]).unwrap();
+        assert_result(r, 2.0, Some(5.0), 0.8491450360846096);
+        let r = chi_square_goodness_of_fit(&observed, &[1.0, 2.0, 1.0, 1.0, 1.0, 1.0]).unwrap();
+        assert_result(r, 4.113636363636363, Some(5.0), 0.5331739237313631);
+
+        let table = [[10.0, 20.0, 30.0], [6.0, 9.0, 17.0]];
+        let r = chi_square_independence(&table).unwrap();
+        assert_result(r, 0.2715746515040352, Some(2.0), 0.8730282833800729);
+
+        assert_eq!(
+            chi_square_goodness_of_fit(&observed, &[1.0; 5]),
+            Err(StatErr::LengthMismatch)
+        );
+        assert_eq!(
+            chi_square_goodness_of_fit(&[1.0, 2.0], &[1.0, 0.0]),
+            Err(StatErr::InvalidParameter)
+        );
+        assert_eq!(chi_square_independence(&[[1.0, 2.0]]), Err(StatErr::TooFew));
+        assert_eq!(
+            chi_square_independence(&[[1.0, 0.0], [2.0, 0.0]]),
+            Err(StatErr::InvalidParameter)
+        );
+    }
+
+    #[test]
+    fn ks_two_sample_test() {
+        let r = ks_two_sample(&X, &Y).unwrap();
+        assert_result(r, 0.75, None, 0.013416632640790534);
+        // same samples
+        let r = ks_two_sample(&X, &X).unwrap();
+        assert_eq!((r.statistic, r.p_value), (0.0, 1.0));
+        // ties across samples
+        let r = ks_two_sample(&[1.0, 2.0, 2.0, 3.0], &[2.0, 2.0, 3.0, 3.0]).unwrap();
+        assert_close(r.statistic, 0.25, 1e-10);
+        assert_eq!(ks_two_sample(&X, &[]), Err(StatErr::Empty));
+    }
+
+    #[test]
+    fn mann_whitney_u_test() {
+        let r = mann_whitney_u(&X, &Y, Alternative::TwoSided).unwrap();
+        assert_result(r, 51.0, None, 0.009093169829990705);
+        let r = mann_whitney_u(&X, &Y, Alternative::Greater).unwrap();
+        assert_close(r.p_value, 0.004546584914995352, 1e-10);
+        let r = mann_whitney_u(&X, &Y, Alternative::Less).unwrap();
+        assert_close(r.p_value, 0.9967797220563285, 1e-10);
+        // U of y is n_x n_y - U of x
+        let r = mann_whitney_u(&Y, &X, Alternative::TwoSided).unwrap();
+        assert_result(r, 5.0, None, 0.009093169829990705);
+        assert_eq!(
+            mann_whitney_u(&[1.0], &[1.0], Alternative::TwoSided),
+            Err(StatErr::ZeroVariance)
+        );
+    }
+}
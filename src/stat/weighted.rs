@@ -0,0 +1,203 @@
+/*!
+Weighted statistics, weights must be non-negative and not all 0, they don't need to sum to 1.
+
+NaN values propagate to mean and variance, and are errors for quantile.
+*/
+use crate::stat::{StatErr, VarianceKind};
+use std::cmp::Ordering;
+
+/**
+Weighted arithmetic mean
+$$
+\bar{x} = \frac{\sum_{i=1}^{n} w_i x_i}{\sum_{i=1}^{n} w_i}
+$$
+*/
+pub fn weighted_mean(values: &[f64], weights: &[f64]) -> Result<f64, StatErr> {
+    let total = check_weights(values, weights)?;
+    Ok(values.iter().zip(weights).map(|(x, w)| x * w).sum::<f64>() / total)
+}
+
+/**
+Weighted variance, sample one treats weights as reliability weights and is unbiased
+$$
+\sigma^2 = \frac{\sum w_i (x_i - \bar{x})^2}{V_1}, \quad
+s^2 = \frac{\sum w_i (x_i - \bar{x})^2}{V_1 - V_2 / V_1}, \quad
+V_1 = \sum w_i, V_2 = \sum w_i^2
+$$
+Sample one is `StatErr::TooFew` if only one weight is non-zero.
+*/
+pub fn weighted_variance(
+    values: &[f64],
+    weights: &[f64],
+    kind: VarianceKind,
+) -> Result<f64, StatErr> {
+    let v1 = check_weights(values, weights)?;
+    let mean = values.iter().zip(weights).map(|(x, w)| x * w).sum::<f64>() / v1;
+    let sum_squares: f64 = values
+        .iter()
+        .zip(weights)
+        .map(|(x, w)| w * (x - mean) * (x - mean))
+        .sum();
+    match kind {
+        VarianceKind::Population => Ok(sum_squares / v1),
+        VarianceKind::Sample => {
+            if weights.iter().filter(|w| **w > 0.0).count() < 2 {
+                return Err(StatErr::TooFew);
+            }
+            let v2: f64 = weights.iter().map(|w| w * w).sum();
+            Ok(sum_squares / (v1 - v2 / v1))
+        }
+    }
+}
+
+/// Weighted standard deviation, square root of `weighted_variance`.
+pub fn weighted_stddev(
+    values: &[f64],
+    weights: &[f64],
+    kind: VarianceKind,
+) -> Result<f64, StatErr> {
+    weighted_variance(values, weights, kind).map(f64::sqrt)
+}
+
+/**
+Weighted q quantile for q in [0, 1].
+
+Sorted value $x_i$ with cumulative weight $S_i$ is placed at $p_i = (S_i - w_i / 2) / S_n$,
+the center of its weight, and quantile is linearly interpolated between them,
+clamped to the min and max value outside $[p_1, p_n]$.
+For equal weights it is R's type 5 quantile, and values with 0 weight are ignored.
+*/
+pub fn weighted_quantile(values: &[f64], weights: &[f64], q: f64) -> Result<f64, StatErr> {
+    let total = check_weights(values, weights)?;
+    if !(0.0..=1.0).contains(&q) {
+        return Err(StatErr::InvalidParameter);
+    }
+    if values.iter().any(|x| x.is_nan()) {
+        return Err(StatErr::NaN);
+    }
+    let mut pairs: Vec<(f64, f64)> = values
+        .iter()
+        .copied()
+        .zip(weights.iter().copied())
+        .filter(|(_, w)| *w > 0.0)
+        .collect();
+    pairs.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+
+    let mut cumulative = 0.0;
+    let mut prev: Option<(f64, f64)> = None;
+    for (x, w) in pairs.iter() {
+        let p = (cumulative + w / 2.0) / total;
+        cumulative += w;
+        if q <= p {
+            return Ok(match prev {
+                Some((prev_p, prev_x)) => prev_x + (q - prev_p) / (p - prev_p) * (x - prev_x),
+                None => *x,
+            });
+        }
+        prev = Some((p, *x));
+    }
+    Ok(pairs[pairs.len() - 1].0)
+}
+
+/// Weighted median, the 0.5 quantile of `weighted_quantile`.
+pub fn weighted_median(values: &[f64], weights: &[f64]) -> Result<f64, StatErr> {
+    weighted_quantile(values, weights, 0.5)
+}
+
+/// Check lengths and weights, returns sum of weights.
+fn check_weights(values: &[f64], weights: &[f64]) -> Result<f64, StatErr> {
+    if values.len() != weights.len() {
+        return Err(StatErr::LengthMismatch);
+    }
+    if values.is_empty() {
+        return Err(StatErr::Empty);
+    }
+    if weights.iter().any(|w| !w.is_finite() || *w < 0.0) {
+        return Err(StatErr::InvalidParameter);
+    }
+    let total: f64 = weights.iter().sum();
+    if total == 0.0 {
+        return Err(StatErr::InvalidParameter);
+    }
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stat::{QuantileMethod, StatBasic};
+
+    const EPSILON: f64 = 1e-12;
+
+    #[test]
+    fn weighted_mean_variance_test() {
+        let x = [1.0, 2.0, 4.0];
+        assert_eq!(weighted_mean(&x, &[1.0, 1.0, 2.0]), Ok(2.75));
+        // integer weights are frequencies for population variance: same as [1, 2, 4, 4]
+        let repeated = [1.0, 2.0, 4.0, 4.0];
+        assert_eq!(
+            weighted_variance(&x, &[1.0, 1.0, 2.0], VarianceKind::Population),
+            repeated.as_ref().u_variance_by(VarianceKind::Population)
+        );
+        // equal weights are the plain sample variance
+        let v = weighted_variance(&x, &[0.2, 0.2, 0.2], VarianceKind::Sample).unwrap();
+        assert!((v - x.as_ref().u_variance_by(VarianceKind::Sample).unwrap()).abs() <= EPSILON);
+        assert_eq!(
+            weighted_stddev(&[1.0, 3.0], &[1.0, 1.0], VarianceKind::Population),
+            Ok(1.0)
+        );
+        assert_eq!(
+            weighted_variance(&x, &[0.0, 0.0, 3.0], VarianceKind::Sample),
+            Err(StatErr::TooFew)
+        );
+    }
+
+    #[test]
+    fn weighted_quantile_test() {
+        let x = [3.0, 1.0, 4.0, 2.0, 5.0];
+        let equal = [1.0; 5];
+        assert_eq!(weighted_median(&x, &equal), Ok(3.0));
+        assert_eq!(weighted_quantile(&x, &equal, 0.0), Ok(1.0));
+        assert_eq!(weighted_quantile(&x, &equal, 1.0), Ok(5.0));
+        // R type 5: p_i = (i - 0.5) / n, so 0.2 is midway between 1.0 and 2.0
+        assert!((weighted_quantile(&x, &equal, 0.2).unwrap() - 1.5).abs() <= EPSILON);
+        assert_eq!(
+            x.as_ref().u_quantile(0.5, QuantileMethod::Linear),
+            weighted_median(&x, &equal)
+        );
+
+        // a heavy value pulls the median: p = 1.5 / 12 and 7 / 12 around 0.5
+        let m = weighted_median(&[1.0, 2.0, 10.0], &[1.0, 1.0, 10.0]).unwrap();
+        assert!((m - 94.0 / 11.0).abs() <= EPSILON);
+        // zero weights are ignored
+        assert_eq!(
+            weighted_median(&[1.0, 2.0, 10.0], &[0.0, 1.0, 0.0]),
+            Ok(2.0)
+        );
+        assert_eq!(
+            weighted_median(&[1.0, f64::NAN], &[1.0, 1.0]),
+            Err(StatErr::NaN)
+        );
+    }
+
+    #[test]
+    fn weighted_err_test() {
+        assert_eq!(weighted_mean(&[], &[]), Err(StatErr::Empty));
+        assert_eq!(
+            weighted_mean(&[1.0], &[1.0, 2.0]),
+            Err(StatErr::LengthMismatch)
+        );
+        assert_eq!(
+            weighted_mean(&[1.0, 2.0], &[1.0, -1.0]),
+            Err(StatErr::InvalidParameter)
+        );
+        assert_eq!(
+            weighted_mean(&[1.0, 2.0], &[0.0, 0.0]),
+            Err(StatErr::InvalidParameter)
+        );
+        assert_eq!(
+            weighted_quantile(&[1.0], &[1.0], -0.1),
+            Err(StatErr::InvalidParameter)
+        );
+    }
+}